@@ -0,0 +1,277 @@
+use bencher_json::{
+    project::report::{JsonAdapterConfig, JsonAdapterConfigMetric},
+    BenchmarkName, JsonMetric,
+};
+use ordered_float::OrderedFloat;
+use regex::{Captures, Regex};
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::{
+        adapter_results::{AdapterMetricKind, AdapterResults},
+        MetricKind, LATENCY_RESOURCE_ID,
+    },
+    Adapter, AdapterError, Settings,
+};
+
+pub struct AdapterCustom {
+    metrics: Vec<CustomMetric>,
+}
+
+struct CustomMetric {
+    regex: Regex,
+    name: CaptureGroup,
+    value: CaptureGroup,
+    lower_bound: Option<CaptureGroup>,
+    upper_bound: Option<CaptureGroup>,
+    units: Option<Units>,
+    metric_kind: MetricKind,
+}
+
+enum CaptureGroup {
+    Index(usize),
+    Name(String),
+}
+
+impl TryFrom<&JsonAdapterConfig> for AdapterCustom {
+    type Error = AdapterError;
+
+    fn try_from(adapter_config: &JsonAdapterConfig) -> Result<Self, Self::Error> {
+        if adapter_config.metrics.is_empty() {
+            return Err(AdapterError::AdapterConfig(
+                "At least one metric is required".into(),
+            ));
+        }
+        let metrics = adapter_config
+            .metrics
+            .iter()
+            .map(CustomMetric::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { metrics })
+    }
+}
+
+impl TryFrom<&JsonAdapterConfigMetric> for CustomMetric {
+    type Error = AdapterError;
+
+    fn try_from(metric: &JsonAdapterConfigMetric) -> Result<Self, Self::Error> {
+        let JsonAdapterConfigMetric {
+            regex,
+            name,
+            value,
+            lower_bound,
+            upper_bound,
+            units,
+            metric_kind,
+        } = metric;
+        let regex = Regex::new(regex).map_err(|e| AdapterError::AdapterConfig(e.to_string()))?;
+        let capture_group = |group: &str| CaptureGroup::new(&regex, group);
+        Ok(Self {
+            name: capture_group(name)?,
+            value: capture_group(value)?,
+            lower_bound: lower_bound.as_deref().map(capture_group).transpose()?,
+            upper_bound: upper_bound.as_deref().map(capture_group).transpose()?,
+            units: units.as_deref().map(str::parse).transpose()?,
+            metric_kind: metric_kind
+                .clone()
+                .unwrap_or_else(|| LATENCY_RESOURCE_ID.clone()),
+            regex,
+        })
+    }
+}
+
+impl CaptureGroup {
+    fn new(regex: &Regex, group: &str) -> Result<Self, AdapterError> {
+        let capture_group = if let Ok(index) = group.parse() {
+            Self::Index(index)
+        } else {
+            Self::Name(group.into())
+        };
+        let exists = match &capture_group {
+            Self::Index(index) => *index < regex.captures_len(),
+            Self::Name(name) => regex.capture_names().flatten().any(|n| n == name),
+        };
+        if exists {
+            Ok(capture_group)
+        } else {
+            Err(AdapterError::AdapterConfig(format!(
+                "Capture group `{group}` does not exist in regex: {regex}"
+            )))
+        }
+    }
+
+    fn get<'c>(&self, captures: &'c Captures) -> Option<&'c str> {
+        match self {
+            Self::Index(index) => captures.get(*index),
+            Self::Name(name) => captures.name(name),
+        }
+        .map(|m| m.as_str().trim())
+    }
+}
+
+impl Adapter for AdapterCustom {
+    fn convert(&self, input: &str, _settings: Settings) -> Option<AdapterResults> {
+        let mut benchmark_metrics = Vec::new();
+
+        for line in input.lines() {
+            for metric in &self.metrics {
+                if let Some(benchmark_metric) = metric.parse(line) {
+                    benchmark_metrics.push(benchmark_metric);
+                }
+            }
+        }
+
+        AdapterResults::new(benchmark_metrics)
+    }
+
+    // A custom adapter is unable to parse anything without its configuration
+    fn parse(_input: &str, _settings: Settings) -> Option<AdapterResults> {
+        None
+    }
+}
+
+impl CustomMetric {
+    fn parse(&self, line: &str) -> Option<(BenchmarkName, AdapterMetricKind)> {
+        let captures = self.regex.captures(line)?;
+        let benchmark_name = self.name.get(&captures)?.parse().ok()?;
+        let json_metric = JsonMetric {
+            value: self.number(&captures, &self.value)?,
+            lower_bound: self.bound(&captures, self.lower_bound.as_ref())?,
+            upper_bound: self.bound(&captures, self.upper_bound.as_ref())?,
+        };
+        Some((
+            benchmark_name,
+            AdapterMetricKind::Custom(self.metric_kind.clone(), json_metric),
+        ))
+    }
+
+    fn number(&self, captures: &Captures, group: &CaptureGroup) -> Option<OrderedFloat<f64>> {
+        let number = group.get(captures)?.replace(',', "").parse::<f64>().ok()?;
+        Some(if let Some(units) = self.units {
+            latency_as_nanos(number, units)
+        } else {
+            number.into()
+        })
+    }
+
+    // An optional bound that is configured but fails to match is treated as an invalid line
+    #[allow(clippy::option_option)]
+    fn bound(
+        &self,
+        captures: &Captures,
+        group: Option<&CaptureGroup>,
+    ) -> Option<Option<OrderedFloat<f64>>> {
+        match group {
+            Some(group) => self.number(captures, group).map(Some),
+            None => Some(None),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_custom {
+    use bencher_json::project::{
+        metric_kind::BYTES_SLUG_STR,
+        report::{JsonAdapterConfig, JsonAdapterConfigMetric},
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{validate_latency, validate_metric, validate_metric_kind},
+        Adapter, AdapterResults, Settings,
+    };
+
+    use super::AdapterCustom;
+
+    fn config_metric(regex: &str) -> JsonAdapterConfigMetric {
+        JsonAdapterConfigMetric {
+            regex: regex.into(),
+            name: "name".into(),
+            value: "value".into(),
+            lower_bound: None,
+            upper_bound: None,
+            units: None,
+            metric_kind: None,
+        }
+    }
+
+    fn convert_custom(adapter_config: &JsonAdapterConfig) -> AdapterResults {
+        let file_path = "./tool_output/custom/two.txt";
+        let contents = std::fs::read_to_string(file_path)
+            .unwrap_or_else(|e| panic!("Failed to read test file {file_path}: {e}"));
+        AdapterCustom::try_from(adapter_config)
+            .unwrap()
+            .convert(&contents, Settings::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_adapter_custom_config() {
+        assert!(AdapterCustom::try_from(&JsonAdapterConfig { metrics: vec![] }).is_err());
+        assert!(AdapterCustom::try_from(&JsonAdapterConfig {
+            metrics: vec![config_metric("(")]
+        })
+        .is_err());
+        assert!(AdapterCustom::try_from(&JsonAdapterConfig {
+            metrics: vec![config_metric(r"^(?P<name>\S+) (\d+)$")]
+        })
+        .is_err());
+
+        let mut metric = config_metric(r"^(\S+) (\d+)$");
+        metric.name = "1".into();
+        metric.value = "2".into();
+        assert!(AdapterCustom::try_from(&JsonAdapterConfig {
+            metrics: vec![metric.clone()]
+        })
+        .is_ok());
+        metric.units = Some("parsecs".into());
+        assert!(AdapterCustom::try_from(&JsonAdapterConfig {
+            metrics: vec![metric]
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_adapter_custom_latency() {
+        let mut metric = config_metric(
+            r"^bench (?P<name>\S+): (?P<value>[\d.]+) ms \[(?P<low>[\d.]+), (?P<high>[\d.]+)\]$",
+        );
+        metric.lower_bound = Some("low".into());
+        metric.upper_bound = Some("high".into());
+        metric.units = Some("ms".into());
+        let results = convert_custom(&JsonAdapterConfig {
+            metrics: vec![metric],
+        });
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("parse_small").unwrap();
+        validate_latency(metrics, 1_500_000.0, Some(1_000_000.0), Some(2_000_000.0));
+
+        let metrics = results.get("parse_large").unwrap();
+        validate_latency(
+            metrics,
+            25_000_000.0,
+            Some(24_000_000.0),
+            Some(26_000_000.0),
+        );
+    }
+
+    #[test]
+    fn test_adapter_custom_metric_kinds() {
+        let latency = config_metric(r"^bench (?P<name>\S+): (?P<value>[\d.]+) ms");
+        let mut bytes = config_metric(r"^size (?P<name>\S+): (?P<value>[\d,]+) bytes$");
+        bytes.metric_kind = Some(BYTES_SLUG_STR.parse().unwrap());
+        let results = convert_custom(&JsonAdapterConfig {
+            metrics: vec![latency, bytes],
+        });
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("parse_small").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_metric_kind(metrics, "latency", 1.5, None, None);
+        validate_metric_kind(metrics, BYTES_SLUG_STR, 1_024.0, None, None);
+
+        let metrics = results.get("parse_large").unwrap();
+        validate_metric(metrics, "latency", 25.0, None, None);
+    }
+}
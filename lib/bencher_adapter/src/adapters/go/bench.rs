@@ -2,19 +2,26 @@ use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
 use nom::{
     bytes::complete::{tag, take_till1},
     character::complete::space1,
-    combinator::{eof, map_res},
+    combinator::{eof, map, map_res},
+    multi::many0,
     sequence::tuple,
     IResult,
 };
 
 use crate::{
     adapters::util::{
-        latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units, NomError,
+        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_u64, parse_units,
+        NomError,
     },
-    results::adapter_results::AdapterResults,
+    results::adapter_results::{AdapterMetricKind, AdapterResults},
     Adapter, Settings,
 };
 
+const NANOS_PER_OP: &str = "ns/op";
+const BYTES_PER_OP: &str = "B/op";
+const ALLOCS_PER_OP: &str = "allocs/op";
+const PER_OP: &str = "/op";
+
 pub struct AdapterGoBench;
 
 impl Adapter for AdapterGoBench {
@@ -27,41 +34,44 @@ impl Adapter for AdapterGoBench {
         let mut benchmark_metrics = Vec::new();
 
         for line in input.lines() {
-            if let Ok((remainder, benchmark_metric)) = parse_go(line) {
+            if let Ok((remainder, (benchmark_name, metric_kinds))) = parse_go(line) {
                 if remainder.is_empty() {
-                    benchmark_metrics.push(benchmark_metric);
+                    for metric_kind in metric_kinds {
+                        benchmark_metrics.push((benchmark_name.clone(), metric_kind));
+                    }
                 }
             }
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        AdapterResults::new(benchmark_metrics)
     }
 }
 
-fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
+fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, Vec<AdapterMetricKind>)> {
     map_res(
         tuple((
             take_till1(|c| c == ' ' || c == '\t'),
             space1,
             parse_u64,
-            space1,
-            parse_go_bench,
+            parse_go_latency,
+            many0(parse_go_metric),
             eof,
         )),
-        |(name, _, _iter, _, json_metric, _)| -> Result<(BenchmarkName, JsonMetric), NomError> {
+        |(name, _, _iter, latency, mut metric_kinds, _)| -> Result<(BenchmarkName, Vec<AdapterMetricKind>), NomError> {
             let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
+            metric_kinds.insert(0, latency);
+            Ok((benchmark_name, metric_kinds))
         },
     )(input)
 }
 
-fn parse_go_bench(input: &str) -> IResult<&str, JsonMetric> {
-    map_res(
-        tuple((parse_f64, space1, parse_units, tag("/op"))),
-        |(duration, _, units, _)| -> Result<JsonMetric, NomError> {
-            let value = latency_as_nanos(duration, units);
-            Ok(JsonMetric {
-                value,
+// The first column is always the time per operation, for example `325 ns/op`.
+fn parse_go_latency(input: &str) -> IResult<&str, AdapterMetricKind> {
+    map(
+        tuple((space1, parse_f64, space1, parse_units, tag(PER_OP))),
+        |(_, duration, _, units, _)| {
+            AdapterMetricKind::Latency(JsonMetric {
+                value: latency_as_nanos(duration, units),
                 lower_bound: None,
                 upper_bound: None,
             })
@@ -69,13 +79,56 @@ fn parse_go_bench(input: &str) -> IResult<&str, JsonMetric> {
     )(input)
 }
 
+// Any following columns are a value and its unit, for example `16 B/op`, `1 allocs/op`,
+// or a custom unit reported with `b.ReportMetric`.
+fn parse_go_metric(input: &str) -> IResult<&str, AdapterMetricKind> {
+    map_res(
+        tuple((
+            space1,
+            parse_f64,
+            space1,
+            take_till1(|c: char| c.is_whitespace()),
+        )),
+        |(_, value, _, units)| -> Result<AdapterMetricKind, NomError> {
+            go_metric_kind(value, units)
+        },
+    )(input)
+}
+
+fn go_metric_kind(value: f64, units: &str) -> Result<AdapterMetricKind, NomError> {
+    let json_metric = |value| JsonMetric {
+        value,
+        lower_bound: None,
+        upper_bound: None,
+    };
+
+    Ok(match units {
+        BYTES_PER_OP => AdapterMetricKind::Bytes(json_metric(value.into())),
+        ALLOCS_PER_OP => AdapterMetricKind::Allocations(json_metric(value.into())),
+        NANOS_PER_OP => AdapterMetricKind::Latency(json_metric(value.into())),
+        // Other time units, such as `ms/op`, are custom metrics and not the time per operation
+        _ => {
+            let metric_kind = slug::slugify(units)
+                .parse()
+                .map_err(|_e| nom_error(units))?;
+            AdapterMetricKind::Custom(metric_kind, json_metric(value.into()))
+        },
+    })
+}
+
 #[cfg(test)]
 pub(crate) mod test_go_bench {
     use bencher_json::{project::report::JsonAverage, JsonMetric};
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, opt_convert_file_path, validate_latency, validate_metric_kind,
+        },
+        results::{
+            adapter_results::AdapterMetricKind, ALLOCATIONS_RESOURCE_ID, BYTES_RESOURCE_ID,
+            LATENCY_RESOURCE_ID,
+        },
         AdapterResults, Settings,
     };
 
@@ -94,11 +147,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib10-8".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMetricKind::Latency(JsonMetric {
                             value: 325.0.into(),
                             lower_bound: None,
                             upper_bound: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib10-8   		 					5000000		325 ns/op",
@@ -108,11 +161,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMetricKind::Latency(JsonMetric {
                             value: 40_537.123.into(),
                             lower_bound: None,
                             upper_bound: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib20  	 	   					30000		40537.123 ns/op",
@@ -122,11 +175,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_10-8".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMetricKind::Latency(JsonMetric {
                             value: 325.0.into(),
                             lower_bound: None,
                             upper_bound: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_10-8    	5000000		325 ns/op",
@@ -136,11 +189,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_20".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMetricKind::Latency(JsonMetric {
                             value: 40_537.123.into(),
                             lower_bound: None,
                             upper_bound: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_20		30000		40537.123 ns/op",
@@ -150,15 +203,89 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my/tabled/benchmark_-_20".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMetricKind::Latency(JsonMetric {
                             value: 40_537.456.into(),
                             lower_bound: None,
                             upper_bound: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my/tabled/benchmark_-_20		30001		40537.456 ns/op",
             ),
+            (
+                Ok((
+                    "",
+                    (
+                        "BenchmarkAlloc-8".parse().unwrap(),
+                        vec![
+                            AdapterMetricKind::Latency(JsonMetric {
+                                value: 1_038.0.into(),
+                                lower_bound: None,
+                                upper_bound: None,
+                            }),
+                            AdapterMetricKind::Bytes(JsonMetric {
+                                value: 1_024.0.into(),
+                                lower_bound: None,
+                                upper_bound: None,
+                            }),
+                            AdapterMetricKind::Allocations(JsonMetric {
+                                value: 2.0.into(),
+                                lower_bound: None,
+                                upper_bound: None,
+                            }),
+                        ],
+                    ),
+                )),
+                "BenchmarkAlloc-8         	 1000000	      1038 ns/op	    1024 B/op	       2 allocs/op",
+            ),
+            (
+                Ok((
+                    "",
+                    (
+                        "BenchmarkCustom-8".parse().unwrap(),
+                        vec![
+                            AdapterMetricKind::Latency(JsonMetric {
+                                value: 1_043.0.into(),
+                                lower_bound: None,
+                                upper_bound: None,
+                            }),
+                            AdapterMetricKind::Custom(
+                                "widgets-op".parse().unwrap(),
+                                JsonMetric {
+                                    value: 12.0.into(),
+                                    lower_bound: None,
+                                    upper_bound: None,
+                                },
+                            ),
+                        ],
+                    ),
+                )),
+                "BenchmarkCustom-8        	 1000000	      1043 ns/op	        12.00 widgets/op",
+            ),
+            (
+                Ok((
+                    "",
+                    (
+                        "BenchmarkQuery-8".parse().unwrap(),
+                        vec![
+                            AdapterMetricKind::Latency(JsonMetric {
+                                value: 2_000_000.0.into(),
+                                lower_bound: None,
+                                upper_bound: None,
+                            }),
+                            AdapterMetricKind::Custom(
+                                "ms-op".parse().unwrap(),
+                                JsonMetric {
+                                    value: 1.5.into(),
+                                    lower_bound: None,
+                                    upper_bound: None,
+                                },
+                            ),
+                        ],
+                    ),
+                )),
+                "BenchmarkQuery-8        	     500	   2000000 ns/op	         1.500 ms/op",
+            ),
         ]
         .into_iter()
         .enumerate()
@@ -196,6 +323,38 @@ pub(crate) mod test_go_bench {
         validate_adapter_go_bench(results);
     }
 
+    #[test]
+    fn test_adapter_go_bench_mem() {
+        let results = convert_go_bench("mem");
+        assert_eq!(results.inner.len(), 4);
+
+        let metrics = results.get("BenchmarkFib10-8").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_metric_kind(metrics, LATENCY_RESOURCE_ID.as_ref(), 299.6, None, None);
+        validate_metric_kind(metrics, BYTES_RESOURCE_ID.as_ref(), 0.0, None, None);
+        validate_metric_kind(metrics, ALLOCATIONS_RESOURCE_ID.as_ref(), 0.0, None, None);
+
+        let metrics = results.get("BenchmarkFib20-8").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_metric_kind(metrics, LATENCY_RESOURCE_ID.as_ref(), 37_148.0, None, None);
+        validate_metric_kind(metrics, BYTES_RESOURCE_ID.as_ref(), 0.0, None, None);
+        validate_metric_kind(metrics, ALLOCATIONS_RESOURCE_ID.as_ref(), 0.0, None, None);
+
+        let metrics = results.get("BenchmarkAlloc-8").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_metric_kind(metrics, LATENCY_RESOURCE_ID.as_ref(), 1_038.0, None, None);
+        validate_metric_kind(metrics, BYTES_RESOURCE_ID.as_ref(), 1_024.0, None, None);
+        validate_metric_kind(metrics, ALLOCATIONS_RESOURCE_ID.as_ref(), 2.0, None, None);
+
+        let metrics = results.get("BenchmarkCustom-8").unwrap();
+        assert_eq!(metrics.inner.len(), 5);
+        validate_metric_kind(metrics, LATENCY_RESOURCE_ID.as_ref(), 1_043.0, None, None);
+        validate_metric_kind(metrics, "widgets-op", 12.0, None, None);
+        validate_metric_kind(metrics, "mb-s", 98.06, None, None);
+        validate_metric_kind(metrics, BYTES_RESOURCE_ID.as_ref(), 1_024.0, None, None);
+        validate_metric_kind(metrics, ALLOCATIONS_RESOURCE_ID.as_ref(), 2.0, None, None);
+    }
+
     pub fn validate_adapter_go_bench(results: AdapterResults) {
         assert_eq!(results.inner.len(), 5);
 
@@ -1,6 +1,7 @@
 use crate::{
     results::adapter_results::AdapterResults, Adapter, AdapterCSharp, AdapterCpp, AdapterGo,
-    AdapterJava, AdapterJs, AdapterJson, AdapterPython, AdapterRuby, AdapterRust, Settings,
+    AdapterJava, AdapterJs, AdapterJson, AdapterPython, AdapterRuby, AdapterRust, AdapterShell,
+    Settings,
 };
 
 pub struct AdapterMagic;
@@ -16,6 +17,7 @@ impl Adapter for AdapterMagic {
             .or_else(|| AdapterPython::parse(input, settings))
             .or_else(|| AdapterRuby::parse(input, settings))
             .or_else(|| AdapterRust::parse(input, settings))
+            .or_else(|| AdapterShell::parse(input, settings))
     }
 }
 
@@ -31,7 +33,11 @@ mod test_magic {
         json::test_json,
         python::{asv::test_python_asv, pytest::test_python_pytest},
         ruby::benchmark::test_ruby_benchmark,
-        rust::{bench::test_rust_bench, criterion::test_rust_criterion},
+        rust::{
+            bench::test_rust_bench, criterion::test_rust_criterion,
+            criterion_json::test_rust_criterion_json, iai::test_rust_iai,
+        },
+        shell::hyperfine::test_shell_hyperfine,
         test_util::convert_file_path,
     };
 
@@ -112,4 +118,23 @@ mod test_magic {
         let results = convert_file_path::<AdapterMagic>("./tool_output/rust/criterion/many.txt");
         test_rust_criterion::validate_adapter_rust_criterion(results);
     }
+
+    #[test]
+    fn test_adapter_magic_rust_criterion_json() {
+        let results =
+            convert_file_path::<AdapterMagic>("./tool_output/rust/criterion_json/three.json");
+        test_rust_criterion_json::validate_adapter_rust_criterion_json(results);
+    }
+
+    #[test]
+    fn test_adapter_magic_rust_iai() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/rust/iai/two.txt");
+        test_rust_iai::validate_adapter_rust_iai(results);
+    }
+
+    #[test]
+    fn test_adapter_magic_shell_hyperfine() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/hyperfine/two.json");
+        test_shell_hyperfine::validate_adapter_shell_hyperfine(results);
+    }
 }
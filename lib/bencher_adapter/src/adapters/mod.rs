@@ -2,6 +2,7 @@ use nom::IResult;
 
 pub mod c_sharp;
 pub mod cpp;
+pub mod custom;
 pub mod go;
 pub mod java;
 pub mod js;
@@ -10,6 +11,7 @@ pub mod magic;
 pub mod python;
 pub mod ruby;
 pub mod rust;
+pub mod shell;
 mod util;
 
 #[allow(dead_code, clippy::print_stdout, clippy::unnecessary_wraps)]
@@ -94,6 +96,16 @@ pub(crate) mod test_util {
         upper_bound: Option<f64>,
     ) {
         assert_eq!(metrics.inner.len(), 1);
+        validate_metric_kind(metrics, key, value, lower_bound, upper_bound);
+    }
+
+    pub fn validate_metric_kind(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_bound: Option<f64>,
+        upper_bound: Option<f64>,
+    ) {
         let metric = metrics.get(key).unwrap();
         assert_eq!(metric.value, OrderedFloat::from(value));
         assert_eq!(metric.lower_bound, lower_bound.map(OrderedFloat::from));
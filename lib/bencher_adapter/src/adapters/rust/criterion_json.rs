@@ -0,0 +1,239 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use ordered_float::OrderedFloat;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::adapter_results::{AdapterMetricKind, AdapterResults},
+    Adapter, AdapterError, Settings,
+};
+
+const NANOS_PER_SECOND: f64 = 1_000_000_000.0;
+
+pub struct AdapterRustCriterionJson;
+
+impl Adapter for AdapterRustCriterionJson {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<CriterionJson>(input)
+            .ok()?
+            .convert(settings)
+            .ok()?
+    }
+}
+
+/// The contents of each `target/criterion/**/new/benchmark.json` and `estimates.json` pair.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CriterionJson(pub Vec<CriterionBenchmark>);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CriterionBenchmark {
+    pub benchmark: Benchmark,
+    pub estimates: Estimates,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Benchmark {
+    pub full_id: BenchmarkName,
+    pub throughput: Option<Throughput>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum Throughput {
+    Bytes(u64),
+    BytesDecimal(u64),
+    Elements(u64),
+}
+
+impl Throughput {
+    fn per_iteration(self) -> u64 {
+        match self {
+            Self::Bytes(count) | Self::BytesDecimal(count) | Self::Elements(count) => count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Estimates {
+    pub mean: Estimate,
+    pub median: Estimate,
+    /// Only present when Criterion uses linear sampling
+    pub slope: Option<Estimate>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Estimate {
+    pub confidence_interval: ConfidenceInterval,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub point_estimate: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfidenceInterval {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub lower_bound: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub upper_bound: Decimal,
+}
+
+impl CriterionJson {
+    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::with_capacity(self.0.len());
+        for criterion_benchmark in self.0 {
+            let CriterionBenchmark {
+                benchmark,
+                estimates,
+            } = criterion_benchmark;
+            let Benchmark {
+                full_id: benchmark_name,
+                throughput,
+            } = benchmark;
+
+            // Estimates are always in nanoseconds per iteration
+            let units = Units::Nano;
+            // Like Criterion itself, prefer the slope estimate by default when there is one
+            let estimate = match settings.average {
+                None => estimates.slope.unwrap_or(estimates.mean),
+                Some(JsonAverage::Mean) => estimates.mean,
+                Some(JsonAverage::Median) => estimates.median,
+            };
+            let Estimate {
+                confidence_interval,
+                point_estimate,
+            } = estimate;
+            let latency = JsonMetric {
+                value: latency_as_nanos(point_estimate, units),
+                lower_bound: Some(latency_as_nanos(confidence_interval.lower_bound, units)),
+                upper_bound: Some(latency_as_nanos(confidence_interval.upper_bound, units)),
+            };
+
+            if let Some(throughput) = throughput {
+                let count = throughput.per_iteration();
+                // The fastest time bound is the highest throughput bound and vice versa
+                let throughput = JsonMetric {
+                    value: throughput_per_second(count, latency.value)?,
+                    lower_bound: latency
+                        .upper_bound
+                        .map(|bound| throughput_per_second(count, bound))
+                        .transpose()?,
+                    upper_bound: latency
+                        .lower_bound
+                        .map(|bound| throughput_per_second(count, bound))
+                        .transpose()?,
+                };
+                benchmark_metrics.push((
+                    benchmark_name.clone(),
+                    AdapterMetricKind::Throughput(throughput),
+                ));
+            }
+
+            benchmark_metrics.push((benchmark_name, AdapterMetricKind::Latency(latency)));
+        }
+
+        Ok(AdapterResults::new(benchmark_metrics))
+    }
+}
+
+#[allow(clippy::cast_precision_loss, clippy::float_arithmetic)]
+fn throughput_per_second(
+    count: u64,
+    nanos: OrderedFloat<f64>,
+) -> Result<OrderedFloat<f64>, AdapterError> {
+    if nanos.0 > 0.0 {
+        Ok((count as f64 * NANOS_PER_SECOND / nanos.0).into())
+    } else {
+        Err(AdapterError::Convert(format!(
+            "Invalid duration for throughput: {nanos}"
+        )))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_rust_criterion_json {
+    use bencher_json::project::{
+        metric_kind::{LATENCY_SLUG_STR, THROUGHPUT_SLUG_STR},
+        report::JsonAverage,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            validate_metric_kind,
+        },
+        AdapterResults, Settings,
+    };
+
+    use super::AdapterRustCriterionJson;
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/rust/criterion_json/{suffix}.json")
+    }
+
+    fn convert_rust_criterion_json(suffix: &str) -> AdapterResults {
+        convert_file_path::<AdapterRustCriterionJson>(&file_path(suffix))
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json() {
+        let results = convert_rust_criterion_json("three");
+        validate_adapter_rust_criterion_json(results);
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json_median() {
+        let results = convert_file_path_median::<AdapterRustCriterionJson>(&file_path("three"));
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/fib 10").unwrap();
+        validate_latency(metrics, 212.5, Some(211.0), Some(214.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json_slope() {
+        let results = convert_rust_criterion_json("slope");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fib/fib 10").unwrap();
+        validate_latency(metrics, 205.0, Some(200.0), Some(208.0));
+
+        // Without a slope estimate, fall back to the mean
+        let metrics = results.get("fib/fib 20").unwrap();
+        validate_latency(metrics, 27_000.0, Some(26_000.0), Some(28_000.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json_slope_mean() {
+        let settings = Settings {
+            average: Some(JsonAverage::Mean),
+        };
+        let results =
+            opt_convert_file_path::<AdapterRustCriterionJson>(&file_path("slope"), settings)
+                .unwrap();
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fib/fib 10").unwrap();
+        validate_latency(metrics, 215.0, Some(210.0), Some(220.0));
+    }
+
+    pub fn validate_adapter_rust_criterion_json(results: AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("fib/fib 10").unwrap();
+        validate_latency(metrics, 215.0, Some(210.0), Some(220.0));
+
+        let metrics = results.get("fib/fib 20").unwrap();
+        validate_latency(metrics, 27_000.0, Some(26_000.0), Some(28_000.0));
+
+        let metrics = results.get("parse/bytes").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_metric_kind(metrics, LATENCY_SLUG_STR, 500.0, Some(400.0), Some(1_000.0));
+        validate_metric_kind(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            2_048_000_000.0,
+            Some(1_024_000_000.0),
+            Some(2_560_000_000.0),
+        );
+    }
+}
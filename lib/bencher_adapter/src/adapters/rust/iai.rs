@@ -0,0 +1,172 @@
+use bencher_json::{BenchmarkName, JsonMetric};
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::{space0, space1},
+    combinator::{eof, map, opt},
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+use crate::{
+    adapters::util::{parse_benchmark_name, parse_u64},
+    results::adapter_results::{AdapterMetricKind, AdapterResults},
+    Adapter, Settings,
+};
+
+pub struct AdapterRustIai;
+
+const IAI_METRICS_LINE_COUNT: usize = 6;
+
+impl Adapter for AdapterRustIai {
+    // Instruction counts are deterministic,
+    // so the suggested central tendency does not apply.
+    fn parse(input: &str, _settings: Settings) -> Option<AdapterResults> {
+        let mut benchmark_metrics = Vec::new();
+
+        let lines = input.lines().collect::<Vec<_>>();
+        for lines in lines.windows(IAI_METRICS_LINE_COUNT) {
+            if let Some(metrics) = parse_iai_lines(lines) {
+                benchmark_metrics.extend(metrics);
+            }
+        }
+
+        AdapterResults::new(benchmark_metrics)
+    }
+}
+
+fn parse_iai_lines(lines: &[&str]) -> Option<Vec<(BenchmarkName, AdapterMetricKind)>> {
+    let [benchmark_name_line, instructions_line, l1_accesses_line, l2_accesses_line, ram_accesses_line, estimated_cycles_line] =
+        lines
+    else {
+        return None;
+    };
+
+    // The benchmark name is the only line without any indentation
+    if benchmark_name_line.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let benchmark_name = parse_benchmark_name(benchmark_name_line.trim_end()).ok()?;
+
+    [
+        (
+            "Instructions",
+            instructions_line,
+            AdapterMetricKind::Instructions as fn(JsonMetric) -> AdapterMetricKind,
+        ),
+        (
+            "L1 Accesses",
+            l1_accesses_line,
+            AdapterMetricKind::L1Accesses,
+        ),
+        (
+            "L2 Accesses",
+            l2_accesses_line,
+            AdapterMetricKind::L2Accesses,
+        ),
+        (
+            "RAM Accesses",
+            ram_accesses_line,
+            AdapterMetricKind::RamAccesses,
+        ),
+        (
+            "Estimated Cycles",
+            estimated_cycles_line,
+            AdapterMetricKind::EstimatedCycles,
+        ),
+    ]
+    .into_iter()
+    .map(|(label, line, into_metric_kind)| {
+        parse_iai_metric(line, label)
+            .ok()
+            .map(|(_, json_metric)| (benchmark_name.clone(), into_metric_kind(json_metric)))
+    })
+    .collect()
+}
+
+// Instructions:                1735 (+0.000000%)
+#[allow(clippy::cast_precision_loss)]
+fn parse_iai_metric<'i>(input: &'i str, label: &'static str) -> IResult<&'i str, JsonMetric> {
+    map(
+        tuple((
+            space1,
+            tag(label),
+            tag(":"),
+            space1,
+            parse_u64,
+            opt(tuple((
+                space1,
+                delimited(tag("("), take_until(")"), tag(")")),
+            ))),
+            space0,
+            eof,
+        )),
+        |(_, _, _, _, count, _, _, _)| JsonMetric {
+            value: (count as f64).into(),
+            lower_bound: None,
+            upper_bound: None,
+        },
+    )(input)
+}
+
+#[cfg(test)]
+pub(crate) mod test_rust_iai {
+    use bencher_json::project::metric_kind::{
+        ESTIMATED_CYCLES_SLUG_STR, INSTRUCTIONS_SLUG_STR, L1_ACCESSES_SLUG_STR,
+        L2_ACCESSES_SLUG_STR, RAM_ACCESSES_SLUG_STR,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, validate_metric_kind},
+        results::adapter_metrics::AdapterMetrics,
+        AdapterResults,
+    };
+
+    use super::AdapterRustIai;
+
+    fn convert_rust_iai(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/rust/iai/{suffix}.txt");
+        convert_file_path::<AdapterRustIai>(&file_path)
+    }
+
+    #[test]
+    fn test_adapter_rust_iai() {
+        let results = convert_rust_iai("two");
+        validate_adapter_rust_iai(results);
+    }
+
+    #[test]
+    fn test_adapter_rust_iai_change() {
+        let results = convert_rust_iai("change");
+        validate_adapter_rust_iai(results);
+    }
+
+    pub fn validate_adapter_rust_iai(results: AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("bench_fibonacci_short").unwrap();
+        validate_iai(metrics, [1_735.0, 2_364.0, 1.0, 1.0, 2_404.0]);
+
+        let metrics = results.get("bench_fibonacci_long").unwrap();
+        validate_iai(
+            metrics,
+            [26_214_735.0, 35_638_623.0, 2.0, 3.0, 35_638_738.0],
+        );
+    }
+
+    fn validate_iai(metrics: &AdapterMetrics, values: [f64; 5]) {
+        assert_eq!(metrics.inner.len(), 5);
+        let [instructions, l1_accesses, l2_accesses, ram_accesses, estimated_cycles] = values;
+        validate_metric_kind(metrics, INSTRUCTIONS_SLUG_STR, instructions, None, None);
+        validate_metric_kind(metrics, L1_ACCESSES_SLUG_STR, l1_accesses, None, None);
+        validate_metric_kind(metrics, L2_ACCESSES_SLUG_STR, l2_accesses, None, None);
+        validate_metric_kind(metrics, RAM_ACCESSES_SLUG_STR, ram_accesses, None, None);
+        validate_metric_kind(
+            metrics,
+            ESTIMATED_CYCLES_SLUG_STR,
+            estimated_cycles,
+            None,
+            None,
+        );
+    }
+}
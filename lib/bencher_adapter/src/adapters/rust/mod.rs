@@ -1,7 +1,11 @@
 pub mod bench;
 pub mod criterion;
+pub mod criterion_json;
+pub mod iai;
 
-use self::criterion::AdapterRustCriterion;
+use self::{
+    criterion::AdapterRustCriterion, criterion_json::AdapterRustCriterionJson, iai::AdapterRustIai,
+};
 use crate::{Adapter, AdapterResults, Settings};
 use bench::AdapterRustBench;
 
@@ -11,6 +15,8 @@ impl Adapter for AdapterRust {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterRustBench::parse(input, settings)
             .or_else(|| AdapterRustCriterion::parse(input, settings))
+            .or_else(|| AdapterRustCriterionJson::parse(input, settings))
+            .or_else(|| AdapterRustIai::parse(input, settings))
     }
 }
 
@@ -18,7 +24,10 @@ impl Adapter for AdapterRust {
 mod test_rust {
     use super::AdapterRust;
     use crate::adapters::{
-        rust::{bench::test_rust_bench, criterion::test_rust_criterion},
+        rust::{
+            bench::test_rust_bench, criterion::test_rust_criterion,
+            criterion_json::test_rust_criterion_json, iai::test_rust_iai,
+        },
         test_util::convert_file_path,
     };
 
@@ -33,4 +42,17 @@ mod test_rust {
         let results = convert_file_path::<AdapterRust>("./tool_output/rust/criterion/many.txt");
         test_rust_criterion::validate_adapter_rust_criterion(results);
     }
+
+    #[test]
+    fn test_adapter_magic_rust_criterion_json() {
+        let results =
+            convert_file_path::<AdapterRust>("./tool_output/rust/criterion_json/three.json");
+        test_rust_criterion_json::validate_adapter_rust_criterion_json(results);
+    }
+
+    #[test]
+    fn test_adapter_magic_rust_iai() {
+        let results = convert_file_path::<AdapterRust>("./tool_output/rust/iai/two.txt");
+        test_rust_iai::validate_adapter_rust_iai(results);
+    }
 }
@@ -0,0 +1,173 @@
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::{
+    adapters::util::{latency_as_nanos, Units},
+    results::adapter_results::AdapterResults,
+    Adapter, AdapterError, Settings,
+};
+
+pub struct AdapterShellHyperfine;
+
+impl Adapter for AdapterShellHyperfine {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<Hyperfine>(input)
+            .ok()?
+            .convert(settings)
+            .ok()?
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Hyperfine {
+    pub results: Vec<HyperfineResult>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HyperfineResult {
+    pub command: BenchmarkName,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub mean: Decimal,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub stddev: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub median: Decimal,
+    pub times: Vec<Decimal>,
+}
+
+impl Hyperfine {
+    fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
+        let mut benchmark_metrics = Vec::with_capacity(self.results.len());
+        for result in self.results {
+            let HyperfineResult {
+                command: benchmark_name,
+                mean,
+                stddev,
+                median,
+                times,
+            } = result;
+
+            // JSON output is always in seconds
+            let units = Units::Sec;
+            // The mean is bounded by one standard deviation and the median by the quartiles
+            let (average, bounds) = match settings.average.unwrap_or_default() {
+                JsonAverage::Mean => (
+                    mean,
+                    stddev.and_then(|stddev| {
+                        Some((mean.checked_sub(stddev)?, mean.checked_add(stddev)?))
+                    }),
+                ),
+                JsonAverage::Median => (median, quartiles(times)),
+            };
+            // A latency can never be negative
+            let bound = |bound: Decimal| latency_as_nanos(bound.max(Decimal::ZERO), units);
+            let json_metric = JsonMetric {
+                value: latency_as_nanos(average, units),
+                lower_bound: bounds.map(|(lower, _)| bound(lower)),
+                upper_bound: bounds.map(|(_, upper)| bound(upper)),
+            };
+
+            benchmark_metrics.push((benchmark_name, json_metric));
+        }
+
+        Ok(AdapterResults::new_latency(benchmark_metrics))
+    }
+}
+
+// The lower and upper quartiles using the medians of the lower and upper halves of the times
+fn quartiles(mut times: Vec<Decimal>) -> Option<(Decimal, Decimal)> {
+    if times.len() < 2 {
+        return None;
+    }
+    times.sort();
+    let half = times.len() / 2;
+    let lower_quartile = median(times.get(..half)?)?;
+    let upper_quartile = median(times.get(times.len() - half..)?)?;
+    Some((lower_quartile, upper_quartile))
+}
+
+fn median(sorted: &[Decimal]) -> Option<Decimal> {
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        sorted
+            .get(middle.checked_sub(1)?)?
+            .checked_add(*sorted.get(middle)?)?
+            .checked_div(2.into())
+    } else {
+        sorted.get(middle).copied()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_shell_hyperfine {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_latency},
+        AdapterResults,
+    };
+
+    use super::AdapterShellHyperfine;
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/shell/hyperfine/{suffix}.json")
+    }
+
+    #[test]
+    fn test_adapter_shell_hyperfine_two() {
+        let results = convert_file_path::<AdapterShellHyperfine>(&file_path("two"));
+        validate_adapter_shell_hyperfine(results);
+    }
+
+    #[test]
+    fn test_adapter_shell_hyperfine_two_median() {
+        let results = convert_file_path_median::<AdapterShellHyperfine>(&file_path("two"));
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("sleep 0.1").unwrap();
+        validate_latency(
+            metrics,
+            102_600_000.0,
+            Some(102_150_000.0),
+            Some(103_250_000.0),
+        );
+
+        let metrics = results.get("fib 20").unwrap();
+        validate_latency(metrics, 2_100_000.0, None, None);
+    }
+
+    #[test]
+    fn test_adapter_shell_hyperfine_noisy() {
+        let results = convert_file_path::<AdapterShellHyperfine>(&file_path("noisy"));
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("noisy").unwrap();
+        validate_latency(metrics, 2_000_000.0, Some(0.0), Some(5_000_000.0));
+    }
+
+    #[test]
+    fn test_adapter_shell_hyperfine_noisy_median() {
+        let results = convert_file_path_median::<AdapterShellHyperfine>(&file_path("noisy"));
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("noisy").unwrap();
+        validate_latency(metrics, 1_000_000.0, Some(1_000_000.0), Some(4_500_000.0));
+    }
+
+    pub fn validate_adapter_shell_hyperfine(results: AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("sleep 0.1").unwrap();
+        validate_latency(
+            metrics,
+            102_630_000.0,
+            Some(101_620_000.0),
+            Some(103_640_000.0),
+        );
+
+        let metrics = results.get("fib 20").unwrap();
+        validate_latency(metrics, 2_100_000.0, None, None);
+    }
+}
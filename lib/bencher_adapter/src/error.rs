@@ -8,4 +8,6 @@ pub enum AdapterError {
     BenchmarkUnits(String),
     #[error("Failed to convert results: {0}")]
     Convert(String),
+    #[error("Invalid adapter config: {0}")]
+    AdapterConfig(String),
 }
@@ -2,6 +2,7 @@ pub mod adapters;
 pub mod error;
 pub mod results;
 
+pub use adapters::custom::AdapterCustom;
 use adapters::{
     c_sharp::{dot_net::AdapterCSharpDotNet, AdapterCSharp},
     cpp::{catch2::AdapterCppCatch2, google::AdapterCppGoogle, AdapterCpp},
@@ -12,7 +13,11 @@ use adapters::{
     magic::AdapterMagic,
     python::{asv::AdapterPythonAsv, pytest::AdapterPythonPytest, AdapterPython},
     ruby::{benchmark::AdapterRubyBenchmark, AdapterRuby},
-    rust::{bench::AdapterRustBench, criterion::AdapterRustCriterion, AdapterRust},
+    rust::{
+        bench::AdapterRustBench, criterion::AdapterRustCriterion,
+        criterion_json::AdapterRustCriterionJson, iai::AdapterRustIai, AdapterRust,
+    },
+    shell::{hyperfine::AdapterShellHyperfine, AdapterShell},
 };
 use bencher_json::project::report::{JsonAdapter, JsonAverage};
 pub use error::AdapterError;
@@ -31,6 +36,8 @@ impl Adapter for JsonAdapter {
         match self {
             JsonAdapter::Magic => AdapterMagic::parse(input, settings),
             JsonAdapter::Json => AdapterJson::parse(input, settings),
+            // A custom adapter requires an adapter config
+            JsonAdapter::Custom => None,
             JsonAdapter::CSharp => AdapterCSharp::parse(input, settings),
             JsonAdapter::CSharpDotNet => AdapterCSharpDotNet::parse(input, settings),
             JsonAdapter::Cpp => AdapterCpp::parse(input, settings),
@@ -51,6 +58,10 @@ impl Adapter for JsonAdapter {
             JsonAdapter::Rust => AdapterRust::parse(input, settings),
             JsonAdapter::RustBench => AdapterRustBench::parse(input, settings),
             JsonAdapter::RustCriterion => AdapterRustCriterion::parse(input, settings),
+            JsonAdapter::RustCriterionJson => AdapterRustCriterionJson::parse(input, settings),
+            JsonAdapter::RustIai => AdapterRustIai::parse(input, settings),
+            JsonAdapter::Shell => AdapterShell::parse(input, settings),
+            JsonAdapter::ShellHyperfine => AdapterShellHyperfine::parse(input, settings),
         }
     }
 
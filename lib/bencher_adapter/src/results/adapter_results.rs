@@ -1,11 +1,13 @@
 use std::{collections::HashMap, str::FromStr};
 
 use bencher_json::{project::metric::Mean, BenchmarkName, JsonMetric};
-use literally::hmap;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    adapter_metrics::AdapterMetrics, CombinedKind, LATENCY_RESOURCE_ID, THROUGHPUT_RESOURCE_ID,
+    adapter_metrics::AdapterMetrics, CombinedKind, MetricKind, ALLOCATIONS_RESOURCE_ID,
+    BYTES_RESOURCE_ID, ESTIMATED_CYCLES_RESOURCE_ID, INSTRUCTIONS_RESOURCE_ID,
+    L1_ACCESSES_RESOURCE_ID, L2_ACCESSES_RESOURCE_ID, LATENCY_RESOURCE_ID,
+    RAM_ACCESSES_RESOURCE_ID, THROUGHPUT_RESOURCE_ID,
 };
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -22,10 +24,49 @@ impl From<ResultsMap> for AdapterResults {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AdapterMetricKind {
     Latency(JsonMetric),
     Throughput(JsonMetric),
+    Bytes(JsonMetric),
+    Allocations(JsonMetric),
+    Instructions(JsonMetric),
+    L1Accesses(JsonMetric),
+    L2Accesses(JsonMetric),
+    RamAccesses(JsonMetric),
+    EstimatedCycles(JsonMetric),
+    Custom(MetricKind, JsonMetric),
+}
+
+impl From<AdapterMetricKind> for (MetricKind, JsonMetric) {
+    fn from(metric_kind: AdapterMetricKind) -> Self {
+        match metric_kind {
+            AdapterMetricKind::Latency(json_metric) => (LATENCY_RESOURCE_ID.clone(), json_metric),
+            AdapterMetricKind::Throughput(json_metric) => {
+                (THROUGHPUT_RESOURCE_ID.clone(), json_metric)
+            },
+            AdapterMetricKind::Bytes(json_metric) => (BYTES_RESOURCE_ID.clone(), json_metric),
+            AdapterMetricKind::Allocations(json_metric) => {
+                (ALLOCATIONS_RESOURCE_ID.clone(), json_metric)
+            },
+            AdapterMetricKind::Instructions(json_metric) => {
+                (INSTRUCTIONS_RESOURCE_ID.clone(), json_metric)
+            },
+            AdapterMetricKind::L1Accesses(json_metric) => {
+                (L1_ACCESSES_RESOURCE_ID.clone(), json_metric)
+            },
+            AdapterMetricKind::L2Accesses(json_metric) => {
+                (L2_ACCESSES_RESOURCE_ID.clone(), json_metric)
+            },
+            AdapterMetricKind::RamAccesses(json_metric) => {
+                (RAM_ACCESSES_RESOURCE_ID.clone(), json_metric)
+            },
+            AdapterMetricKind::EstimatedCycles(json_metric) => {
+                (ESTIMATED_CYCLES_RESOURCE_ID.clone(), json_metric)
+            },
+            AdapterMetricKind::Custom(metric_kind, json_metric) => (metric_kind, json_metric),
+        }
+    }
 }
 
 impl AdapterResults {
@@ -34,23 +75,14 @@ impl AdapterResults {
             return None;
         }
 
-        let mut results_map = HashMap::new();
+        let mut results_map: ResultsMap = HashMap::new();
         for (benchmark_name, metric_kind) in benchmark_metrics {
-            let adapter_metrics = AdapterMetrics {
-                inner: match metric_kind {
-                    AdapterMetricKind::Latency(json_metric) => {
-                        hmap! {
-                            LATENCY_RESOURCE_ID.clone() => json_metric
-                        }
-                    },
-                    AdapterMetricKind::Throughput(json_metric) => {
-                        hmap! {
-                            THROUGHPUT_RESOURCE_ID.clone() => json_metric
-                        }
-                    },
-                },
-            };
-            results_map.insert(benchmark_name, adapter_metrics);
+            let (metric_kind, json_metric) = metric_kind.into();
+            results_map
+                .entry(benchmark_name)
+                .or_default()
+                .inner
+                .insert(metric_kind, json_metric);
         }
 
         Some(results_map.into())
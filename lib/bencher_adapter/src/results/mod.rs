@@ -3,8 +3,12 @@ use std::collections::HashMap;
 use bencher_json::{
     project::{
         metric::Mean,
-        metric_kind::{LATENCY_SLUG_STR, THROUGHPUT_SLUG_STR},
-        report::{JsonAdapter, JsonFold},
+        metric_kind::{
+            ALLOCATIONS_SLUG_STR, BYTES_SLUG_STR, ESTIMATED_CYCLES_SLUG_STR, INSTRUCTIONS_SLUG_STR,
+            L1_ACCESSES_SLUG_STR, L2_ACCESSES_SLUG_STR, LATENCY_SLUG_STR, RAM_ACCESSES_SLUG_STR,
+            THROUGHPUT_SLUG_STR,
+        },
+        report::JsonFold,
     },
     ResourceId,
 };
@@ -33,6 +37,55 @@ pub static THROUGHPUT_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
         .expect("Failed to parse metric kind slug.")
 });
 
+#[allow(clippy::expect_used)]
+pub static BYTES_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
+    BYTES_SLUG_STR
+        .parse()
+        .expect("Failed to parse metric kind slug.")
+});
+
+#[allow(clippy::expect_used)]
+pub static ALLOCATIONS_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
+    ALLOCATIONS_SLUG_STR
+        .parse()
+        .expect("Failed to parse metric kind slug.")
+});
+
+#[allow(clippy::expect_used)]
+pub static INSTRUCTIONS_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
+    INSTRUCTIONS_SLUG_STR
+        .parse()
+        .expect("Failed to parse metric kind slug.")
+});
+
+#[allow(clippy::expect_used)]
+pub static L1_ACCESSES_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
+    L1_ACCESSES_SLUG_STR
+        .parse()
+        .expect("Failed to parse metric kind slug.")
+});
+
+#[allow(clippy::expect_used)]
+pub static L2_ACCESSES_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
+    L2_ACCESSES_SLUG_STR
+        .parse()
+        .expect("Failed to parse metric kind slug.")
+});
+
+#[allow(clippy::expect_used)]
+pub static RAM_ACCESSES_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
+    RAM_ACCESSES_SLUG_STR
+        .parse()
+        .expect("Failed to parse metric kind slug.")
+});
+
+#[allow(clippy::expect_used)]
+pub static ESTIMATED_CYCLES_RESOURCE_ID: Lazy<ResourceId> = Lazy::new(|| {
+    ESTIMATED_CYCLES_SLUG_STR
+        .parse()
+        .expect("Failed to parse metric kind slug.")
+});
+
 pub type MetricKind = ResourceId;
 
 #[derive(Debug, Clone)]
@@ -49,11 +102,14 @@ impl From<ResultsArray> for AdapterResultsArray {
 }
 
 impl AdapterResultsArray {
-    pub fn new(
+    pub fn new<A>(
         results_array: &[&str],
-        adapter: JsonAdapter,
+        adapter: &A,
         settings: Settings,
-    ) -> Result<Self, AdapterError> {
+    ) -> Result<Self, AdapterError>
+    where
+        A: Adapter,
+    {
         let mut parsed_results_array = Vec::new();
         for results in results_array {
             let parsed_results = adapter
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BoundaryError {
+    #[error("Failed to create normal distribution: {0}")]
+    Normal(statrs::StatsError),
+    #[error("Failed to create Student's t distribution: {0}")]
+    StudentsT(statrs::StatsError),
+}
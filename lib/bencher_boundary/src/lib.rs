@@ -0,0 +1,384 @@
+use bencher_json::project::{
+    alert::JsonSide,
+    report::JsonAverage,
+    threshold::{JsonNewStatistic, JsonStatisticKind},
+};
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+mod error;
+pub mod stats;
+
+pub use error::BoundaryError;
+use stats::{
+    mean, median, median_absolute_deviation, percent_change, quartiles, std_deviation,
+    MAD_SCALE_FACTOR,
+};
+
+/// The outcome of checking a datum against the boundary for a statistic
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Detection {
+    /// The datum was not checked, because there was not enough data
+    /// or there is no limit on the side of the baseline that it is on
+    Unchecked,
+    /// The datum was checked and it is inside of the boundary
+    Inside,
+    /// The datum was checked and it is outside of the boundary
+    Outlier(Boundary),
+}
+
+impl Detection {
+    fn outlier(is_outlier: bool, boundary: Boundary) -> Self {
+        if is_outlier {
+            Self::Outlier(boundary)
+        } else {
+            Self::Inside
+        }
+    }
+}
+
+/// A datum that is outside of the boundary for a statistic
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Boundary {
+    pub side: JsonSide,
+    pub limit: f32,
+    pub outlier: f64,
+}
+
+impl Boundary {
+    /// Check a datum against the historical data for a statistic.
+    /// The data is expected to be ordered from most to least recent,
+    /// and it should not include the datum itself.
+    pub fn detect(
+        statistic: &JsonNewStatistic,
+        data: &[f64],
+        datum: f64,
+    ) -> Result<Detection, BoundaryError> {
+        // If there is a min sample size, then check to see if it is met.
+        // Otherwise, the datum is not checked.
+        if let Some(min_sample_size) = statistic.min_sample_size {
+            if data.len() < min_sample_size as usize {
+                return Ok(Detection::Unchecked);
+            }
+        }
+
+        match statistic.test {
+            JsonStatisticKind::Z | JsonStatisticKind::T => {
+                detect_percentile(statistic, data, datum)
+            },
+            JsonStatisticKind::Percentage | JsonStatisticKind::Absolute => {
+                Ok(detect_limit(statistic, data, datum))
+            },
+            JsonStatisticKind::Mad | JsonStatisticKind::Iqr => {
+                Ok(detect_robust(statistic, data, datum))
+            },
+        }
+    }
+}
+
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::float_arithmetic,
+    clippy::integer_arithmetic
+)]
+fn detect_percentile(
+    statistic: &JsonNewStatistic,
+    data: &[f64],
+    datum: f64,
+) -> Result<Detection, BoundaryError> {
+    let Some(mean) = mean(data) else {
+        return Ok(Detection::Unchecked);
+    };
+    let Some(std_dev) = std_deviation(mean, data) else {
+        return Ok(Detection::Unchecked);
+    };
+
+    let (abs_datum, side, limit) = if datum < mean {
+        if let Some(left_side) = statistic.left_side {
+            (mean * 2.0 - datum, JsonSide::Left, left_side.0)
+        } else {
+            return Ok(Detection::Unchecked);
+        }
+    } else if let Some(right_side) = statistic.right_side {
+        (datum, JsonSide::Right, right_side.0)
+    } else {
+        return Ok(Detection::Unchecked);
+    };
+
+    let percentile = match statistic.test {
+        JsonStatisticKind::Z => {
+            let normal = Normal::new(mean, std_dev).map_err(BoundaryError::Normal)?;
+            normal.cdf(abs_datum)
+        },
+        JsonStatisticKind::T => {
+            let students_t = StudentsT::new(mean, std_dev, (data.len() - 1) as f64)
+                .map_err(BoundaryError::StudentsT)?;
+            students_t.cdf(abs_datum)
+        },
+        JsonStatisticKind::Percentage
+        | JsonStatisticKind::Absolute
+        | JsonStatisticKind::Mad
+        | JsonStatisticKind::Iqr => return Ok(Detection::Unchecked),
+    };
+
+    Ok(Detection::outlier(
+        percentile > f64::from(limit),
+        Boundary {
+            side,
+            limit,
+            outlier: percentile,
+        },
+    ))
+}
+
+// Compare the change from the baseline against the lower and upper limits.
+// Percentage changes are relative to the baseline, absolute changes are in the metric units.
+#[allow(clippy::cast_possible_truncation, clippy::float_arithmetic)]
+fn detect_limit(statistic: &JsonNewStatistic, data: &[f64], datum: f64) -> Detection {
+    let baseline = match statistic.baseline.unwrap_or(JsonAverage::Mean) {
+        JsonAverage::Mean => mean(data),
+        JsonAverage::Median => median(data),
+    };
+    let Some(baseline) = baseline else {
+        return Detection::Unchecked;
+    };
+
+    let change = match statistic.test {
+        JsonStatisticKind::Percentage => percent_change(baseline, datum),
+        JsonStatisticKind::Absolute => Some(datum - baseline),
+        JsonStatisticKind::Z
+        | JsonStatisticKind::T
+        | JsonStatisticKind::Mad
+        | JsonStatisticKind::Iqr => None,
+    };
+    let Some(change) = change else {
+        return Detection::Unchecked;
+    };
+
+    let (outlier, side, limit) = if change < 0.0 {
+        (-change, JsonSide::Left, statistic.lower_limit)
+    } else {
+        (change, JsonSide::Right, statistic.upper_limit)
+    };
+    let Some(limit) = limit else {
+        return Detection::Unchecked;
+    };
+
+    Detection::outlier(
+        outlier > limit.0,
+        Boundary {
+            side,
+            limit: limit.0 as f32,
+            outlier,
+        },
+    )
+}
+
+// Compare how far outside of the fences the datum is, in multiples of the spread,
+// against the lower and upper multipliers.
+// The MAD test fences at the median and its spread is the scaled median absolute deviation,
+// so that a multiplier of `3` is about three standard deviations for normally distributed data.
+// The IQR test fences at the quartiles and its spread is the interquartile range.
+#[allow(clippy::cast_possible_truncation, clippy::float_arithmetic)]
+fn detect_robust(statistic: &JsonNewStatistic, data: &[f64], datum: f64) -> Detection {
+    let fences = match statistic.test {
+        JsonStatisticKind::Mad => median(data).and_then(|median| {
+            median_absolute_deviation(median, data)
+                .map(|mad| (median, median, mad * MAD_SCALE_FACTOR))
+        }),
+        JsonStatisticKind::Iqr => quartiles(data).map(|(q1, q3)| (q1, q3, q3 - q1)),
+        JsonStatisticKind::Z
+        | JsonStatisticKind::T
+        | JsonStatisticKind::Percentage
+        | JsonStatisticKind::Absolute => None,
+    };
+    let Some((lower_fence, upper_fence, spread)) = fences else {
+        return Detection::Unchecked;
+    };
+    // A spread of zero means that there is no variation to compare against
+    if spread <= 0.0 {
+        return Detection::Unchecked;
+    }
+
+    let (outlier, side, limit) = if datum < lower_fence {
+        (
+            (lower_fence - datum) / spread,
+            JsonSide::Left,
+            statistic.lower_limit,
+        )
+    } else if datum > upper_fence {
+        (
+            (datum - upper_fence) / spread,
+            JsonSide::Right,
+            statistic.upper_limit,
+        )
+    } else {
+        return Detection::Inside;
+    };
+    let Some(limit) = limit else {
+        return Detection::Unchecked;
+    };
+
+    Detection::outlier(
+        outlier > limit.0,
+        Boundary {
+            side,
+            limit: limit.0 as f32,
+            outlier,
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::project::{
+        alert::JsonSide,
+        threshold::{JsonNewStatistic, JsonStatisticKind},
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::{Boundary, Detection};
+
+    fn outlier(detection: Detection) -> Boundary {
+        match detection {
+            Detection::Outlier(boundary) => boundary,
+            Detection::Unchecked | Detection::Inside => {
+                panic!("Expected an outlier: {detection:?}")
+            },
+        }
+    }
+
+    fn statistic(test: JsonStatisticKind) -> JsonNewStatistic {
+        JsonNewStatistic {
+            test,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            left_side: None,
+            right_side: None,
+            baseline: None,
+            lower_limit: None,
+            upper_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_min_sample_size() {
+        let statistic = JsonNewStatistic {
+            min_sample_size: Some(4),
+            upper_limit: Some(10.0.into()),
+            ..statistic(JsonStatisticKind::Percentage)
+        };
+        assert_eq!(
+            Boundary::detect(&statistic, &[1.0, 1.0, 2.0], 2.0).unwrap(),
+            Detection::Unchecked
+        );
+        assert_eq!(
+            Boundary::detect(&statistic, &[1.0, 1.0, 1.0, 1.0], 2.0).unwrap(),
+            Detection::Outlier(Boundary {
+                side: JsonSide::Right,
+                limit: 10.0,
+                outlier: 100.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_z() {
+        let statistic = JsonNewStatistic {
+            right_side: Some(0.99.into()),
+            ..statistic(JsonStatisticKind::Z)
+        };
+        let data = [1.0, 2.0, 1.0, 2.0, 1.0, 2.0];
+        assert_eq!(
+            Boundary::detect(&statistic, &data, 1.5).unwrap(),
+            Detection::Inside
+        );
+        let boundary = outlier(Boundary::detect(&statistic, &data, 10.0).unwrap());
+        assert_eq!(boundary.side, JsonSide::Right);
+        assert_eq!(boundary.limit, 0.99);
+        // Without a left side, a low datum is never checked
+        assert_eq!(
+            Boundary::detect(&statistic, &data, -10.0).unwrap(),
+            Detection::Unchecked
+        );
+    }
+
+    #[test]
+    fn test_percentage() {
+        let statistic = JsonNewStatistic {
+            lower_limit: Some(5.0.into()),
+            upper_limit: Some(10.0.into()),
+            ..statistic(JsonStatisticKind::Percentage)
+        };
+        let data = [100.0, 100.0, 100.0];
+        assert_eq!(
+            Boundary::detect(&statistic, &data, 109.0).unwrap(),
+            Detection::Inside
+        );
+        assert_eq!(
+            Boundary::detect(&statistic, &data, 120.0).unwrap(),
+            Detection::Outlier(Boundary {
+                side: JsonSide::Right,
+                limit: 10.0,
+                outlier: 20.0,
+            })
+        );
+        assert_eq!(
+            Boundary::detect(&statistic, &data, 90.0).unwrap(),
+            Detection::Outlier(Boundary {
+                side: JsonSide::Left,
+                limit: 5.0,
+                outlier: 10.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_mad() {
+        let statistic = JsonNewStatistic {
+            lower_limit: Some(2.0.into()),
+            upper_limit: Some(3.0.into()),
+            ..statistic(JsonStatisticKind::Mad)
+        };
+        // A median of 2 and a median absolute deviation of 1
+        let data = [1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0];
+        // 3 raw deviations above the median is only about 2 scaled deviations
+        assert_eq!(
+            Boundary::detect(&statistic, &data, 5.0).unwrap(),
+            Detection::Inside
+        );
+        let boundary = outlier(Boundary::detect(&statistic, &data, 8.0).unwrap());
+        assert_eq!(boundary.side, JsonSide::Right);
+        assert_eq!(boundary.limit, 3.0);
+        assert!((boundary.outlier - 6.0 / 1.4826).abs() < f64::EPSILON);
+        let boundary = outlier(Boundary::detect(&statistic, &data, -2.0).unwrap());
+        assert_eq!(boundary.side, JsonSide::Left);
+        assert_eq!(boundary.limit, 2.0);
+    }
+
+    #[test]
+    fn test_iqr() {
+        let statistic = JsonNewStatistic {
+            upper_limit: Some(1.5.into()),
+            ..statistic(JsonStatisticKind::Iqr)
+        };
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        assert_eq!(
+            Boundary::detect(&statistic, &data, 8.0).unwrap(),
+            Detection::Inside
+        );
+        // Without a lower limit, a low datum is never checked
+        assert_eq!(
+            Boundary::detect(&statistic, &data, -10.0).unwrap(),
+            Detection::Unchecked
+        );
+        assert_eq!(
+            Boundary::detect(&statistic, &data, 14.5).unwrap(),
+            Detection::Outlier(Boundary {
+                side: JsonSide::Right,
+                limit: 1.5,
+                outlier: 2.0,
+            })
+        );
+    }
+}
@@ -0,0 +1,134 @@
+pub fn std_deviation(mean: f64, data: &[f64]) -> Option<f64> {
+    variance(mean, data).map(f64::sqrt)
+}
+
+#[allow(clippy::cast_precision_loss, clippy::float_arithmetic)]
+fn variance(mean: f64, data: &[f64]) -> Option<f64> {
+    if data.is_empty() {
+        return None;
+    }
+    Some(
+        data.iter()
+            .map(|value| (*value - mean).powi(2))
+            .sum::<f64>()
+            / data.len() as f64,
+    )
+}
+
+#[allow(clippy::cast_precision_loss, clippy::float_arithmetic)]
+pub fn mean(data: &[f64]) -> Option<f64> {
+    if data.is_empty() {
+        return None;
+    }
+    Some(data.iter().sum::<f64>() / data.len() as f64)
+}
+
+/// The change from the base to the head as a percent of the base
+#[allow(clippy::float_arithmetic)]
+pub fn percent_change(base: f64, head: f64) -> Option<f64> {
+    (base != 0.0).then(|| (head - base) / base.abs() * 100.0)
+}
+
+pub fn median(data: &[f64]) -> Option<f64> {
+    sorted_median(&sorted(data))
+}
+
+/// The scale factor that makes the median absolute deviation a consistent estimator
+/// of the standard deviation for normally distributed data, `1 / Φ⁻¹(3/4)`
+pub const MAD_SCALE_FACTOR: f64 = 1.4826;
+
+/// The raw, unscaled median absolute deviation.
+/// Multiply by `MAD_SCALE_FACTOR` to compare it to a standard deviation.
+#[allow(clippy::float_arithmetic)]
+pub fn median_absolute_deviation(median: f64, data: &[f64]) -> Option<f64> {
+    let deviations = data
+        .iter()
+        .map(|value| (*value - median).abs())
+        .collect::<Vec<_>>();
+    sorted_median(&sorted(&deviations))
+}
+
+// The lower and upper quartiles using the medians of the lower and upper halves of the data
+#[allow(clippy::integer_arithmetic)]
+pub fn quartiles(data: &[f64]) -> Option<(f64, f64)> {
+    if data.len() < 2 {
+        return None;
+    }
+    let sorted = sorted(data);
+    let half = sorted.len() / 2;
+    let lower_quartile = sorted_median(sorted.get(..half)?)?;
+    let upper_quartile = sorted_median(sorted.get(sorted.len() - half..)?)?;
+    Some((lower_quartile, upper_quartile))
+}
+
+fn sorted(data: &[f64]) -> Vec<f64> {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+#[allow(clippy::float_arithmetic, clippy::integer_arithmetic)]
+fn sorted_median(sorted: &[f64]) -> Option<f64> {
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        Some((sorted.get(middle.checked_sub(1)?)? + sorted.get(middle)?) / 2.0)
+    } else {
+        sorted.get(middle).copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{
+        mean, median, median_absolute_deviation, percent_change, quartiles, std_deviation,
+    };
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(mean(&[]), None);
+        assert_eq!(mean(&[1.0, 2.0, 3.0, 4.0]), Some(2.5));
+    }
+
+    #[test]
+    fn test_std_deviation() {
+        assert_eq!(std_deviation(0.0, &[]), None);
+        assert_eq!(
+            std_deviation(5.0, &[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn test_percent_change() {
+        assert_eq!(percent_change(0.0, 1.0), None);
+        assert_eq!(percent_change(100.0, 110.0), Some(10.0));
+        assert_eq!(percent_change(-100.0, -110.0), Some(-10.0));
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&[]), None);
+        assert_eq!(median(&[3.0, 1.0, 2.0]), Some(2.0));
+        assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), Some(2.5));
+    }
+
+    #[test]
+    fn test_median_absolute_deviation() {
+        assert_eq!(
+            median_absolute_deviation(2.0, &[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn test_quartiles() {
+        assert_eq!(quartiles(&[1.0]), None);
+        assert_eq!(
+            quartiles(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]),
+            Some((2.5, 6.5))
+        );
+        assert_eq!(quartiles(&[1.0, 2.0, 3.0, 4.0, 5.0]), Some((1.5, 4.5)));
+    }
+}
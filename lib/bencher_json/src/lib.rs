@@ -11,23 +11,28 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub mod organization;
+pub mod pagination;
 pub mod project;
 pub mod system;
 pub mod urlencoded;
 pub mod user;
 
 pub use organization::{member::JsonMember, JsonNewOrganization, JsonOrganization};
+pub use pagination::{JsonDirection, JsonPagination};
 pub use project::{
     alert::JsonAlert,
     benchmark::JsonBenchmark,
     branch::{JsonBranch, JsonNewBranch},
+    compare::{JsonCompare, JsonCompareQuery},
     metric::JsonMetric,
     metric_kind::{JsonMetricKind, JsonNewMetricKind},
-    perf::{JsonPerf, JsonPerfQuery},
+    notification::{JsonNewNotification, JsonNotification},
+    perf::{JsonPerf, JsonPerfChangepoints, JsonPerfQuery},
     report::{JsonNewReport, JsonReport},
     result::{JsonMetrics, JsonResult},
     testbed::{JsonNewTestbed, JsonTestbed},
     threshold::{JsonNewThreshold, JsonThreshold},
+    webhook::{JsonNewWebhook, JsonWebhook},
     JsonNewProject, JsonProject,
 };
 pub use system::{
@@ -6,6 +6,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::project::JsonProjectRole;
+
 pub const MEMBER_ROLE: &str = "member";
 pub const LEADER_ROLE: &str = "leader";
 
@@ -33,6 +35,21 @@ pub struct JsonUpdateMember {
     pub role: Option<JsonOrganizationRole>,
 }
 
+/// Assign a built-in or custom project role to an organization member
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateMemberProject {
+    pub role: JsonProjectRole,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMemberProject {
+    pub user: Uuid,
+    pub project: Uuid,
+    pub role: JsonProjectRole,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
@@ -68,3 +85,12 @@ impl fmt::Display for JsonOrganizationRole {
         )
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonMembersSort {
+    #[default]
+    Name,
+    Email,
+}
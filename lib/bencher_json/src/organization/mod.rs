@@ -10,6 +10,8 @@ pub mod entitlements;
 pub mod member;
 #[cfg(feature = "plus")]
 pub mod metered;
+pub mod role;
+pub mod service_account;
 #[cfg(feature = "plus")]
 pub mod usage;
 
@@ -51,3 +53,12 @@ pub enum JsonOrganizationPermission {
     #[display(fmt = "delete_role")]
     DeleteRole,
 }
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonOrganizationsSort {
+    #[default]
+    Name,
+    Created,
+}
@@ -0,0 +1,59 @@
+use bencher_valid::Slug;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewRole {
+    /// The name used to assign the custom role on a project
+    pub name: Slug,
+    pub permissions: Vec<JsonRolePermission>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateRole {
+    /// Replaces all of the permissions granted by the custom role
+    pub permissions: Vec<JsonRolePermission>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonRolePermission {
+    View,
+    Create,
+    Edit,
+    Delete,
+    Manage,
+    ViewRole,
+    CreateRole,
+    EditRole,
+    DeleteRole,
+    /// Only upload reports
+    CreateReport,
+    /// Only create thresholds
+    ManageThreshold,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRole {
+    pub uuid: Uuid,
+    pub organization: Uuid,
+    pub name: Slug,
+    pub permissions: Vec<JsonRolePermission>,
+    pub creation: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonRolesSort {
+    #[default]
+    Name,
+    Creation,
+}
@@ -0,0 +1,54 @@
+use bencher_valid::{ResourceId, Slug, UserName};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::project::JsonProjectRole;
+
+use super::member::JsonOrganizationRole;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewServiceAccount {
+    pub name: UserName,
+    pub slug: Option<Slug>,
+    /// The organization role, if the service account should have more than view access to the organization
+    pub role: Option<JsonOrganizationRole>,
+    /// The project roles for the service account
+    #[serde(default)]
+    pub projects: Vec<JsonServiceAccountProject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonServiceAccountProject {
+    pub project: ResourceId,
+    pub role: JsonProjectRole,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonServiceAccount {
+    /// The user UUID for the service account, which its reports and API tokens belong to
+    pub uuid: Uuid,
+    pub name: UserName,
+    pub slug: Slug,
+    pub organization: Uuid,
+    pub role: Option<JsonOrganizationRole>,
+    pub projects: Vec<JsonServiceAccountProject>,
+    /// The user that created the service account
+    pub creator: Uuid,
+    pub creation: DateTime<Utc>,
+    pub locked: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonServiceAccountsSort {
+    #[default]
+    Name,
+    Creation,
+}
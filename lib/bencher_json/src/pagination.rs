@@ -0,0 +1,53 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The response header with the total number of items across all pages
+pub const TOTAL_COUNT_HEADER: &str = "X-Total-Count";
+pub const DEFAULT_PER_PAGE: u8 = u8::MAX;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPagination<S> {
+    /// The field to sort by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<S>,
+    /// The direction to sort in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<JsonDirection>,
+    /// The number of items per page, defaults to and may not exceed 255
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u8>,
+    /// The page number, starting at 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonDirection {
+    Asc,
+    Desc,
+}
+
+impl<S> JsonPagination<S>
+where
+    S: Copy + Default,
+{
+    pub fn sort(&self) -> S {
+        self.sort.unwrap_or_default()
+    }
+
+    pub fn direction(&self, default: JsonDirection) -> JsonDirection {
+        self.direction.unwrap_or(default)
+    }
+
+    pub fn limit(&self) -> i64 {
+        i64::from(self.per_page.unwrap_or(DEFAULT_PER_PAGE))
+    }
+
+    pub fn offset(&self) -> i64 {
+        i64::from(self.page.unwrap_or(1).saturating_sub(1)) * self.limit()
+    }
+}
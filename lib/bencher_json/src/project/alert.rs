@@ -1,9 +1,12 @@
+use chrono::{DateTime, Utc};
 use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::project::threshold::JsonStatisticKind;
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonAlert {
@@ -14,12 +17,68 @@ pub struct JsonAlert {
     pub side: JsonSide,
     pub boundary: OrderedFloat<f32>,
     pub outlier: OrderedFloat<f32>,
+    /// The units of the boundary and outlier, which depend on the statistic test
+    pub units: JsonBoundaryUnits,
+    pub status: JsonAlertStatus,
+    /// The user that last updated the status, if it was not automatically resolved
+    pub status_user: Option<Uuid>,
+    pub status_time: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum JsonSide {
     Left,
     Right,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonBoundaryUnits {
+    /// A percentile between 0 and 1, for the z-score and t-test
+    Percentile,
+    /// A percent change from the baseline, for the percentage test
+    Percent,
+    /// A change from the baseline in the metric kind units, for the absolute test
+    Metric,
+    /// A multiple of the spread outside of the fences, for the MAD and IQR tests
+    Spread,
+}
+
+impl From<JsonStatisticKind> for JsonBoundaryUnits {
+    fn from(test: JsonStatisticKind) -> Self {
+        match test {
+            JsonStatisticKind::Z | JsonStatisticKind::T => Self::Percentile,
+            JsonStatisticKind::Percentage => Self::Percent,
+            JsonStatisticKind::Absolute => Self::Metric,
+            JsonStatisticKind::Mad | JsonStatisticKind::Iqr => Self::Spread,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonAlertStatus {
+    Active,
+    Acknowledged,
+    Dismissed,
+    Resolved,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateAlert {
+    pub status: Option<JsonAlertStatus>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonAlertsSort {
+    #[default]
+    DateTime,
+    Status,
+}
@@ -19,3 +19,12 @@ impl fmt::Display for JsonBenchmark {
         write!(f, "{}", self.name)
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonBenchmarksSort {
+    #[default]
+    Name,
+    Created,
+}
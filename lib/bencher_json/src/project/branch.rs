@@ -70,3 +70,12 @@ impl fmt::Display for JsonBranch {
         write!(f, "{}", self.name)
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonBranchesSort {
+    #[default]
+    Name,
+    Created,
+}
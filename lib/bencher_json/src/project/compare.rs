@@ -0,0 +1,131 @@
+use bencher_valid::GitHash;
+use chrono::{DateTime, Utc};
+use ordered_float::OrderedFloat;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{JsonBenchmark, JsonMetricKind, JsonProject, JsonTestbed, ResourceId};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareQuery {
+    /// Git commit hash, an abbreviated prefix of one, or a Report UUID to compare from
+    pub base: String,
+    /// Git commit hash, an abbreviated prefix of one, or a Report UUID to compare to
+    pub head: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testbed: Option<ResourceId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<ResourceId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompare {
+    pub project: JsonProject,
+    pub base: JsonCompareReport,
+    pub head: JsonCompareReport,
+    pub results: Vec<JsonCompareResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareReport {
+    pub report: Uuid,
+    pub testbed: JsonTestbed,
+    pub version_number: u32,
+    pub version_hash: Option<GitHash>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+/// The metrics for a benchmark and metric kind in both reports.
+/// If a benchmark was run for multiple iterations, then its metrics are averaged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareResult {
+    pub benchmark: JsonBenchmark,
+    pub metric_kind: JsonMetricKind,
+    pub base: Option<OrderedFloat<f64>>,
+    pub head: Option<OrderedFloat<f64>>,
+    /// The head value minus the base value
+    pub delta: Option<OrderedFloat<f64>>,
+    /// The delta as a percentage of the base value
+    pub percent: Option<OrderedFloat<f64>>,
+    /// The alerts generated by the head report
+    pub alerts: Vec<Uuid>,
+}
+
+#[cfg(feature = "table")]
+pub mod table {
+    use std::fmt;
+
+    use ordered_float::OrderedFloat;
+    use tabled::{Table, TableIteratorExt, Tabled};
+
+    use crate::{JsonBenchmark, JsonMetricKind};
+
+    use super::JsonCompare;
+
+    impl From<JsonCompare> for Table {
+        fn from(json_compare: JsonCompare) -> Self {
+            json_compare
+                .results
+                .into_iter()
+                .map(|result| CompareTable {
+                    benchmark: result.benchmark,
+                    metric_kind: result.metric_kind,
+                    base: Value(result.base),
+                    head: Value(result.head),
+                    delta: Value(result.delta),
+                    percent: Percent(result.percent),
+                    alerts: result.alerts.len(),
+                })
+                .table()
+        }
+    }
+
+    #[derive(Tabled)]
+    pub struct CompareTable {
+        #[tabled(rename = "Benchmark")]
+        pub benchmark: JsonBenchmark,
+        #[tabled(rename = "Metric Kind")]
+        pub metric_kind: JsonMetricKind,
+        #[tabled(rename = "Base")]
+        pub base: Value,
+        #[tabled(rename = "Head")]
+        pub head: Value,
+        #[tabled(rename = "Delta")]
+        pub delta: Value,
+        #[tabled(rename = "Change")]
+        pub percent: Percent,
+        #[tabled(rename = "Alerts")]
+        pub alerts: usize,
+    }
+
+    pub struct Value(pub Option<OrderedFloat<f64>>);
+
+    impl fmt::Display for Value {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if let Some(value) = self.0 {
+                write!(f, "{value}")
+            } else {
+                write!(f, "---")
+            }
+        }
+    }
+
+    pub struct Percent(pub Option<OrderedFloat<f64>>);
+
+    impl fmt::Display for Percent {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if let Some(percent) = self.0 {
+                write!(f, "{:+.2}%", percent.0)
+            } else {
+                write!(f, "---")
+            }
+        }
+    }
+}
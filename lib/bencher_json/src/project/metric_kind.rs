@@ -55,6 +55,174 @@ static THROUGHPUT_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
         .expect("Failed to parse metric kind units.")
 });
 
+pub const BYTES_NAME_STR: &str = "Bytes";
+#[allow(clippy::expect_used)]
+static BYTES_NAME: Lazy<NonEmpty> = Lazy::new(|| {
+    BYTES_NAME_STR
+        .parse()
+        .expect("Failed to parse metric kind name.")
+});
+pub const BYTES_SLUG_STR: &str = "bytes";
+#[allow(clippy::expect_used)]
+static BYTES_SLUG: Lazy<Option<Slug>> = Lazy::new(|| {
+    Some(
+        BYTES_SLUG_STR
+            .parse()
+            .expect("Failed to parse metric kind slug."),
+    )
+});
+pub const BYTES_UNITS_STR: &str = "bytes / operation (B/op)";
+#[allow(clippy::expect_used)]
+static BYTES_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
+    BYTES_UNITS_STR
+        .parse()
+        .expect("Failed to parse metric kind units.")
+});
+
+pub const ALLOCATIONS_NAME_STR: &str = "Allocations";
+#[allow(clippy::expect_used)]
+static ALLOCATIONS_NAME: Lazy<NonEmpty> = Lazy::new(|| {
+    ALLOCATIONS_NAME_STR
+        .parse()
+        .expect("Failed to parse metric kind name.")
+});
+pub const ALLOCATIONS_SLUG_STR: &str = "allocations";
+#[allow(clippy::expect_used)]
+static ALLOCATIONS_SLUG: Lazy<Option<Slug>> = Lazy::new(|| {
+    Some(
+        ALLOCATIONS_SLUG_STR
+            .parse()
+            .expect("Failed to parse metric kind slug."),
+    )
+});
+pub const ALLOCATIONS_UNITS_STR: &str = "allocations / operation (allocs/op)";
+#[allow(clippy::expect_used)]
+static ALLOCATIONS_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
+    ALLOCATIONS_UNITS_STR
+        .parse()
+        .expect("Failed to parse metric kind units.")
+});
+
+pub const INSTRUCTIONS_NAME_STR: &str = "Instructions";
+#[allow(clippy::expect_used)]
+static INSTRUCTIONS_NAME: Lazy<NonEmpty> = Lazy::new(|| {
+    INSTRUCTIONS_NAME_STR
+        .parse()
+        .expect("Failed to parse metric kind name.")
+});
+pub const INSTRUCTIONS_SLUG_STR: &str = "instructions";
+#[allow(clippy::expect_used)]
+static INSTRUCTIONS_SLUG: Lazy<Option<Slug>> = Lazy::new(|| {
+    Some(
+        INSTRUCTIONS_SLUG_STR
+            .parse()
+            .expect("Failed to parse metric kind slug."),
+    )
+});
+pub const INSTRUCTIONS_UNITS_STR: &str = "instructions";
+#[allow(clippy::expect_used)]
+static INSTRUCTIONS_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
+    INSTRUCTIONS_UNITS_STR
+        .parse()
+        .expect("Failed to parse metric kind units.")
+});
+
+pub const L1_ACCESSES_NAME_STR: &str = "L1 Accesses";
+#[allow(clippy::expect_used)]
+static L1_ACCESSES_NAME: Lazy<NonEmpty> = Lazy::new(|| {
+    L1_ACCESSES_NAME_STR
+        .parse()
+        .expect("Failed to parse metric kind name.")
+});
+pub const L1_ACCESSES_SLUG_STR: &str = "l1-accesses";
+#[allow(clippy::expect_used)]
+static L1_ACCESSES_SLUG: Lazy<Option<Slug>> = Lazy::new(|| {
+    Some(
+        L1_ACCESSES_SLUG_STR
+            .parse()
+            .expect("Failed to parse metric kind slug."),
+    )
+});
+pub const L1_ACCESSES_UNITS_STR: &str = "accesses";
+#[allow(clippy::expect_used)]
+static L1_ACCESSES_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
+    L1_ACCESSES_UNITS_STR
+        .parse()
+        .expect("Failed to parse metric kind units.")
+});
+
+pub const L2_ACCESSES_NAME_STR: &str = "L2 Accesses";
+#[allow(clippy::expect_used)]
+static L2_ACCESSES_NAME: Lazy<NonEmpty> = Lazy::new(|| {
+    L2_ACCESSES_NAME_STR
+        .parse()
+        .expect("Failed to parse metric kind name.")
+});
+pub const L2_ACCESSES_SLUG_STR: &str = "l2-accesses";
+#[allow(clippy::expect_used)]
+static L2_ACCESSES_SLUG: Lazy<Option<Slug>> = Lazy::new(|| {
+    Some(
+        L2_ACCESSES_SLUG_STR
+            .parse()
+            .expect("Failed to parse metric kind slug."),
+    )
+});
+pub const L2_ACCESSES_UNITS_STR: &str = "accesses";
+#[allow(clippy::expect_used)]
+static L2_ACCESSES_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
+    L2_ACCESSES_UNITS_STR
+        .parse()
+        .expect("Failed to parse metric kind units.")
+});
+
+pub const RAM_ACCESSES_NAME_STR: &str = "RAM Accesses";
+#[allow(clippy::expect_used)]
+static RAM_ACCESSES_NAME: Lazy<NonEmpty> = Lazy::new(|| {
+    RAM_ACCESSES_NAME_STR
+        .parse()
+        .expect("Failed to parse metric kind name.")
+});
+pub const RAM_ACCESSES_SLUG_STR: &str = "ram-accesses";
+#[allow(clippy::expect_used)]
+static RAM_ACCESSES_SLUG: Lazy<Option<Slug>> = Lazy::new(|| {
+    Some(
+        RAM_ACCESSES_SLUG_STR
+            .parse()
+            .expect("Failed to parse metric kind slug."),
+    )
+});
+pub const RAM_ACCESSES_UNITS_STR: &str = "accesses";
+#[allow(clippy::expect_used)]
+static RAM_ACCESSES_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
+    RAM_ACCESSES_UNITS_STR
+        .parse()
+        .expect("Failed to parse metric kind units.")
+});
+
+pub const ESTIMATED_CYCLES_NAME_STR: &str = "Estimated Cycles";
+#[allow(clippy::expect_used)]
+static ESTIMATED_CYCLES_NAME: Lazy<NonEmpty> = Lazy::new(|| {
+    ESTIMATED_CYCLES_NAME_STR
+        .parse()
+        .expect("Failed to parse metric kind name.")
+});
+pub const ESTIMATED_CYCLES_SLUG_STR: &str = "estimated-cycles";
+#[allow(clippy::expect_used)]
+static ESTIMATED_CYCLES_SLUG: Lazy<Option<Slug>> = Lazy::new(|| {
+    Some(
+        ESTIMATED_CYCLES_SLUG_STR
+            .parse()
+            .expect("Failed to parse metric kind slug."),
+    )
+});
+pub const ESTIMATED_CYCLES_UNITS_STR: &str = "estimated cycles";
+#[allow(clippy::expect_used)]
+static ESTIMATED_CYCLES_UNITS: Lazy<NonEmpty> = Lazy::new(|| {
+    ESTIMATED_CYCLES_UNITS_STR
+        .parse()
+        .expect("Failed to parse metric kind units.")
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonNewMetricKind {
@@ -79,6 +247,89 @@ impl JsonNewMetricKind {
             units: THROUGHPUT_UNITS.clone(),
         }
     }
+
+    pub fn bytes() -> Self {
+        Self {
+            name: BYTES_NAME.clone(),
+            slug: BYTES_SLUG.clone(),
+            units: BYTES_UNITS.clone(),
+        }
+    }
+
+    pub fn allocations() -> Self {
+        Self {
+            name: ALLOCATIONS_NAME.clone(),
+            slug: ALLOCATIONS_SLUG.clone(),
+            units: ALLOCATIONS_UNITS.clone(),
+        }
+    }
+
+    pub fn instructions() -> Self {
+        Self {
+            name: INSTRUCTIONS_NAME.clone(),
+            slug: INSTRUCTIONS_SLUG.clone(),
+            units: INSTRUCTIONS_UNITS.clone(),
+        }
+    }
+
+    pub fn l1_accesses() -> Self {
+        Self {
+            name: L1_ACCESSES_NAME.clone(),
+            slug: L1_ACCESSES_SLUG.clone(),
+            units: L1_ACCESSES_UNITS.clone(),
+        }
+    }
+
+    pub fn l2_accesses() -> Self {
+        Self {
+            name: L2_ACCESSES_NAME.clone(),
+            slug: L2_ACCESSES_SLUG.clone(),
+            units: L2_ACCESSES_UNITS.clone(),
+        }
+    }
+
+    pub fn ram_accesses() -> Self {
+        Self {
+            name: RAM_ACCESSES_NAME.clone(),
+            slug: RAM_ACCESSES_SLUG.clone(),
+            units: RAM_ACCESSES_UNITS.clone(),
+        }
+    }
+
+    pub fn estimated_cycles() -> Self {
+        Self {
+            name: ESTIMATED_CYCLES_NAME.clone(),
+            slug: ESTIMATED_CYCLES_SLUG.clone(),
+            units: ESTIMATED_CYCLES_UNITS.clone(),
+        }
+    }
+
+    /// Look up a built-in metric kind by its default slug.
+    pub fn builtin(slug: &str) -> Option<Self> {
+        Some(match slug {
+            LATENCY_SLUG_STR => Self::latency(),
+            THROUGHPUT_SLUG_STR => Self::throughput(),
+            BYTES_SLUG_STR => Self::bytes(),
+            ALLOCATIONS_SLUG_STR => Self::allocations(),
+            INSTRUCTIONS_SLUG_STR => Self::instructions(),
+            L1_ACCESSES_SLUG_STR => Self::l1_accesses(),
+            L2_ACCESSES_SLUG_STR => Self::l2_accesses(),
+            RAM_ACCESSES_SLUG_STR => Self::ram_accesses(),
+            ESTIMATED_CYCLES_SLUG_STR => Self::estimated_cycles(),
+            _ => return None,
+        })
+    }
+
+    /// A placeholder metric kind for a slug that is neither built-in nor declared,
+    /// using the slug as both its name and its units.
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        let name: NonEmpty = slug.parse().ok()?;
+        Some(Self {
+            units: name.clone(),
+            slug: slug.parse().ok(),
+            name,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -96,3 +347,12 @@ impl fmt::Display for JsonMetricKind {
         write!(f, "{}: {}", self.name, self.units)
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonMetricKindsSort {
+    #[default]
+    Name,
+    Created,
+}
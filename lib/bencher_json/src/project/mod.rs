@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use bencher_valid::{NonEmpty, Slug, Url};
 #[cfg(feature = "schema")]
@@ -9,13 +9,20 @@ use uuid::Uuid;
 pub mod alert;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
 pub mod metric;
 pub mod metric_kind;
+pub mod notification;
 pub mod perf;
 pub mod report;
 pub mod result;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
+
+pub const VIEWER_ROLE: &str = "viewer";
+pub const DEVELOPER_ROLE: &str = "developer";
+pub const MAINTAINER_ROLE: &str = "maintainer";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -24,6 +31,8 @@ pub struct JsonNewProject {
     pub slug: Option<Slug>,
     pub url: Option<Url>,
     pub visibility: Option<JsonVisibility>,
+    /// Create missing custom metric kinds the first time that they are reported
+    pub auto_metric_kinds: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +50,14 @@ pub struct JsonProject {
     pub slug: Slug,
     pub url: Option<Url>,
     pub visibility: JsonVisibility,
+    pub auto_metric_kinds: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateProject {
+    /// Create missing custom metric kinds the first time that they are reported
+    pub auto_metric_kinds: Option<bool>,
 }
 
 impl fmt::Display for JsonProject {
@@ -64,3 +81,75 @@ impl JsonVisibility {
         matches!(self, Self::Public)
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonProjectsSort {
+    #[default]
+    Name,
+    Created,
+}
+
+/// A built-in project role or the name of a custom role defined by the organization
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum JsonProjectRole {
+    Viewer,
+    Developer,
+    Maintainer,
+    Custom(Slug),
+}
+
+impl FromStr for JsonProjectRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            VIEWER_ROLE => Ok(Self::Viewer),
+            DEVELOPER_ROLE => Ok(Self::Developer),
+            MAINTAINER_ROLE => Ok(Self::Maintainer),
+            _ => s.parse().map(Self::Custom).map_err(|_| s.into()),
+        }
+    }
+}
+
+impl TryFrom<String> for JsonProjectRole {
+    type Error = String;
+
+    fn try_from(role: String) -> Result<Self, Self::Error> {
+        role.parse()
+    }
+}
+
+impl fmt::Display for JsonProjectRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Viewer => VIEWER_ROLE,
+                Self::Developer => DEVELOPER_ROLE,
+                Self::Maintainer => MAINTAINER_ROLE,
+                Self::Custom(role) => role.as_ref(),
+            }
+        )
+    }
+}
+
+impl From<JsonProjectRole> for String {
+    fn from(role: JsonProjectRole) -> Self {
+        role.to_string()
+    }
+}
+
+#[cfg(feature = "schema")]
+impl JsonSchema for JsonProjectRole {
+    fn schema_name() -> String {
+        "JsonProjectRole".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
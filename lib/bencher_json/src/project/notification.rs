@@ -0,0 +1,53 @@
+use bencher_valid::{Email, GitHash};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{
+    alert::JsonAlert, benchmark::JsonBenchmark, branch::JsonBranch, testbed::JsonTestbed,
+    JsonProject,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewNotification {
+    /// Send an email to this address
+    pub email: Email,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNotification {
+    pub uuid: Uuid,
+    pub project: Uuid,
+    pub email: Email,
+}
+
+/// The payload sent for all of the new alerts generated by a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAlertNotification {
+    pub project: JsonProject,
+    pub report: Uuid,
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub hash: Option<GitHash>,
+    pub alerts: Vec<JsonNotificationAlert>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNotificationAlert {
+    pub alert: JsonAlert,
+    pub benchmark: JsonBenchmark,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonNotificationsSort {
+    #[default]
+    Created,
+    Email,
+}
@@ -1,5 +1,6 @@
 use bencher_valid::GitHash;
 use chrono::{DateTime, Utc};
+use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::ser::{self, SerializeStruct};
@@ -208,6 +209,40 @@ pub struct JsonPerfMetric {
     pub metric: JsonMetric,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangepoints {
+    pub project: JsonProject,
+    pub metric_kind: JsonMetricKind,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub results: Vec<JsonPerfChangepointMetrics>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangepointMetrics {
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub benchmark: JsonBenchmark,
+    pub changepoints: Vec<JsonChangepoint>,
+}
+
+/// A shift in the mean of the metrics between two consecutive metrics.
+/// The commit range that caused the shift is from the version of `before` to the version of `after`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonChangepoint {
+    /// The last metric before the shift
+    pub before: JsonPerfMetric,
+    /// The first metric after the shift
+    pub after: JsonPerfMetric,
+    /// The mean of the metrics from the previous shift up until the shift
+    pub before_mean: OrderedFloat<f64>,
+    /// The mean of the metrics from the shift up until the next shift
+    pub after_mean: OrderedFloat<f64>,
+}
+
 #[cfg(feature = "table")]
 pub mod table {
     use std::fmt;
@@ -217,7 +252,8 @@ pub mod table {
     use tabled::{Table, TableIteratorExt, Tabled};
 
     use crate::{
-        JsonBenchmark, JsonBranch, JsonMetric, JsonMetricKind, JsonPerf, JsonProject, JsonTestbed,
+        project::perf::JsonPerfChangepoints, JsonBenchmark, JsonBranch, JsonMetric, JsonMetricKind,
+        JsonPerf, JsonProject, JsonTestbed,
     };
 
     impl From<JsonPerf> for Table {
@@ -270,6 +306,73 @@ pub mod table {
         pub metric: JsonMetric,
     }
 
+    impl From<JsonPerfChangepoints> for Table {
+        fn from(json_changepoints: JsonPerfChangepoints) -> Self {
+            let mut changepoint_table = Vec::new();
+            for result in json_changepoints.results {
+                for changepoint in result.changepoints {
+                    changepoint_table.push(ChangepointTable {
+                        branch: result.branch.clone(),
+                        testbed: result.testbed.clone(),
+                        benchmark: result.benchmark.clone(),
+                        before_version_number: changepoint.before.version_number,
+                        before_version_hash: VersionHash(changepoint.before.version_hash),
+                        after_version_number: changepoint.after.version_number,
+                        after_version_hash: VersionHash(changepoint.after.version_hash),
+                        before_mean: changepoint.before_mean.into(),
+                        after_mean: changepoint.after_mean.into(),
+                        change: Change {
+                            before_mean: changepoint.before_mean.into(),
+                            after_mean: changepoint.after_mean.into(),
+                        },
+                    })
+                }
+            }
+            changepoint_table.table()
+        }
+    }
+
+    #[derive(Tabled)]
+    pub struct ChangepointTable {
+        #[tabled(rename = "Branch")]
+        pub branch: JsonBranch,
+        #[tabled(rename = "Testbed")]
+        pub testbed: JsonTestbed,
+        #[tabled(rename = "Benchmark")]
+        pub benchmark: JsonBenchmark,
+        #[tabled(rename = "Before Version Number")]
+        pub before_version_number: u32,
+        #[tabled(rename = "Before Version Hash")]
+        pub before_version_hash: VersionHash,
+        #[tabled(rename = "After Version Number")]
+        pub after_version_number: u32,
+        #[tabled(rename = "After Version Hash")]
+        pub after_version_hash: VersionHash,
+        #[tabled(rename = "Before Mean")]
+        pub before_mean: f64,
+        #[tabled(rename = "After Mean")]
+        pub after_mean: f64,
+        #[tabled(rename = "Change")]
+        pub change: Change,
+    }
+
+    pub struct Change {
+        before_mean: f64,
+        after_mean: f64,
+    }
+
+    impl fmt::Display for Change {
+        #[allow(clippy::float_arithmetic)]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.before_mean == 0.0 {
+                write!(f, "---")
+            } else {
+                let change = (self.after_mean - self.before_mean) / self.before_mean.abs() * 100.0;
+                write!(f, "{change:+.2}%")
+            }
+        }
+    }
+
     pub struct VersionHash(Option<GitHash>);
 
     impl fmt::Display for VersionHash {
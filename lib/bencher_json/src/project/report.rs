@@ -5,9 +5,12 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::ResourceId;
+use crate::{
+    project::{alert::JsonAlertStatus, testbed::JsonFingerprint},
+    JsonNewMetricKind, ResourceId,
+};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonNewReport {
     pub branch: ResourceId,
@@ -17,14 +20,55 @@ pub struct JsonNewReport {
     pub end_time: DateTime<Utc>,
     pub results: Vec<String>,
     pub settings: Option<JsonReportSettings>,
+    /// The raw benchmark command output for each iteration, kept for debugging
+    /// Each output stream is truncated to its last `JsonReportOutput::MAX_LEN` bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Vec<JsonReportOutput>>,
+    /// The hardware and software of the testbed when the report was run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<JsonFingerprint>,
 }
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReportOutput {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl JsonReportOutput {
+    /// The max number of bytes kept from each output stream
+    pub const MAX_LEN: usize = 0x10000;
+
+    /// Only the end of each output stream is kept, as that is where failures are usually reported
+    pub fn new(stdout: String, stderr: String) -> Self {
+        Self {
+            stdout: truncate_output(stdout),
+            stderr: truncate_output(stderr),
+        }
+    }
+}
+
+fn truncate_output(mut output: String) -> String {
+    if output.len() <= JsonReportOutput::MAX_LEN {
+        return output;
+    }
+    let mut start = output.len() - JsonReportOutput::MAX_LEN;
+    while !output.is_char_boundary(start) {
+        start += 1;
+    }
+    output.split_off(start)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReportSettings {
     pub adapter: Option<JsonAdapter>,
     pub average: Option<JsonAverage>,
     pub fold: Option<JsonFold>,
+    pub adapter_config: Option<JsonAdapterConfig>,
+    /// Metric kinds to create the first time that they are reported
+    pub metric_kinds: Option<Vec<JsonNewMetricKind>>,
 }
 
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -34,6 +78,7 @@ pub enum JsonAdapter {
     #[default]
     Magic,
     Json,
+    Custom,
     CSharp,
     CSharpDotNet,
     Cpp,
@@ -54,6 +99,10 @@ pub enum JsonAdapter {
     Rust,
     RustBench,
     RustCriterion,
+    RustCriterionJson,
+    RustIai,
+    Shell,
+    ShellHyperfine,
 }
 
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -75,6 +124,32 @@ pub enum JsonFold {
     Median,
 }
 
+/// A user-defined adapter that gathers metrics from each line of output with regular expressions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAdapterConfig {
+    pub metrics: Vec<JsonAdapterConfigMetric>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAdapterConfigMetric {
+    /// Regular expression to match against each line of output
+    pub regex: String,
+    /// Capture group name or index for the benchmark name
+    pub name: String,
+    /// Capture group name or index for the metric value
+    pub value: String,
+    /// Capture group name or index for the metric lower bound
+    pub lower_bound: Option<String>,
+    /// Capture group name or index for the metric upper bound
+    pub upper_bound: Option<String>,
+    /// Time units of the captured values (ie `ns`, `us`, `ms`, or `s`) to convert to nanoseconds
+    pub units: Option<String>,
+    /// Metric kind slug or UUID (default is `latency`)
+    pub metric_kind: Option<ResourceId>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReport {
@@ -87,6 +162,13 @@ pub struct JsonReport {
     pub adapter: JsonAdapter,
     pub results: JsonReportResults,
     pub alerts: JsonReportAlerts,
+    pub fingerprint: Option<JsonFingerprint>,
+    /// Whether the testbed fingerprint is different from the one for the previous report on the same testbed
+    pub fingerprint_changed: bool,
+    /// The reported metric kinds that were skipped because they do not exist in the project
+    /// This is only set in the response to creating the report
+    #[serde(default)]
+    pub skipped_metric_kinds: Vec<ResourceId>,
 }
 
 pub type JsonReportResults = Vec<JsonReportResult>;
@@ -103,10 +185,51 @@ impl From<Uuid> for JsonReportResult {
 pub type JsonReportAlerts = Vec<JsonReportAlert>;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
-pub struct JsonReportAlert(pub Uuid);
+pub struct JsonReportAlert {
+    pub uuid: Uuid,
+    pub status: JsonAlertStatus,
+}
 
-impl From<Uuid> for JsonReportAlert {
-    fn from(uuid: Uuid) -> Self {
-        Self(uuid)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonReports {
+    /// Git commit hash or an abbreviated prefix of one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<ResourceId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub testbed: Option<ResourceId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<ResourceId>,
+    /// Only reports that started at or after this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Utc>>,
+    /// Only reports that ended at or before this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonReportsSort {
+    #[default]
+    DateTime,
+    Version,
+}
+
+#[cfg(test)]
+mod test {
+    use super::JsonReportOutput;
+
+    #[test]
+    fn test_report_output_truncate() {
+        let output = JsonReportOutput::new("short".into(), String::new());
+        assert_eq!(output.stdout, "short");
+
+        let long = format!("aé{}", "a".repeat(JsonReportOutput::MAX_LEN - 1));
+        let output = JsonReportOutput::new(String::new(), long);
+        assert_eq!(output.stderr, "a".repeat(JsonReportOutput::MAX_LEN - 1));
     }
 }
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{collections::BTreeMap, fmt};
 
 use bencher_valid::{NonEmpty, Slug};
 use once_cell::sync::Lazy;
@@ -53,3 +53,36 @@ impl fmt::Display for JsonTestbed {
         write!(f, "{}", self.name)
     }
 }
+
+/// The hardware and software that a report was run on
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonFingerprint {
+    /// CPU model name
+    pub cpu: Option<String>,
+    /// Logical CPU core count
+    pub cores: Option<u32>,
+    /// Total memory in bytes
+    pub memory: Option<u64>,
+    /// Operating system name
+    pub os: Option<String>,
+    /// Operating system kernel release
+    pub kernel: Option<String>,
+    /// CPU architecture
+    pub arch: Option<String>,
+    /// Toolchain versions by toolchain command
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub toolchains: BTreeMap<String, String>,
+    /// Whitelisted environment variables
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonTestbedsSort {
+    #[default]
+    Name,
+    Created,
+}
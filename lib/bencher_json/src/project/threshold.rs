@@ -4,7 +4,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::ResourceId;
+use crate::{project::report::JsonAverage, ResourceId};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -25,6 +25,19 @@ pub struct JsonNewStatistic {
     pub window: Option<u32>,
     pub left_side: Option<OrderedFloat<f32>>,
     pub right_side: Option<OrderedFloat<f32>>,
+    /// The central tendency of the historical data used as the baseline
+    /// for the percentage and absolute tests (defaults to the mean)
+    pub baseline: Option<JsonAverage>,
+    /// The lower limit, which must be non-negative and whose units depend on the test:
+    /// the largest allowed decrease below the baseline as a percent for the percentage test
+    /// or in the metric kind units for the absolute test,
+    /// or the largest allowed multiple of the spread below the lower fence for the MAD and IQR tests
+    pub lower_limit: Option<OrderedFloat<f64>>,
+    /// The upper limit, which must be non-negative and whose units depend on the test:
+    /// the largest allowed increase above the baseline as a percent for the percentage test
+    /// or in the metric kind units for the absolute test,
+    /// or the largest allowed multiple of the spread above the upper fence for the MAD and IQR tests
+    pub upper_limit: Option<OrderedFloat<f64>>,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
@@ -47,6 +60,47 @@ pub struct JsonStatistic {
     pub window: Option<u32>,
     pub left_side: Option<OrderedFloat<f32>>,
     pub right_side: Option<OrderedFloat<f32>>,
+    /// The central tendency of the historical data used as the baseline
+    /// for the percentage and absolute tests (defaults to the mean)
+    pub baseline: Option<JsonAverage>,
+    /// The lower limit, which must be non-negative and whose units depend on the test:
+    /// the largest allowed decrease below the baseline as a percent for the percentage test
+    /// or in the metric kind units for the absolute test,
+    /// or the largest allowed multiple of the spread below the lower fence for the MAD and IQR tests
+    pub lower_limit: Option<OrderedFloat<f64>>,
+    /// The upper limit, which must be non-negative and whose units depend on the test:
+    /// the largest allowed increase above the baseline as a percent for the percentage test
+    /// or in the metric kind units for the absolute test,
+    /// or the largest allowed multiple of the spread above the upper fence for the MAD and IQR tests
+    pub upper_limit: Option<OrderedFloat<f64>>,
+}
+
+impl From<JsonStatistic> for JsonNewStatistic {
+    fn from(statistic: JsonStatistic) -> Self {
+        let JsonStatistic {
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            left_side,
+            right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
+            ..
+        } = statistic;
+        Self {
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            left_side,
+            right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -55,4 +109,17 @@ pub struct JsonStatistic {
 pub enum JsonStatisticKind {
     Z,
     T,
+    Percentage,
+    Absolute,
+    Mad,
+    Iqr,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonThresholdsSort {
+    #[default]
+    Created,
+    Testbed,
 }
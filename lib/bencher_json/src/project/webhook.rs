@@ -0,0 +1,97 @@
+use std::fmt;
+
+use bencher_valid::{Secret, Url};
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewWebhook {
+    pub url: Url,
+    /// Sign the webhook payload with this secret
+    pub secret: Option<Secret>,
+    /// The events to send, defaults to all events
+    pub events: Option<Vec<JsonWebhookEvent>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateWebhook {
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub events: Option<Vec<JsonWebhookEvent>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhook {
+    pub uuid: Uuid,
+    pub project: Uuid,
+    pub url: Url,
+    /// Whether the webhook payload is signed
+    pub signed: bool,
+    pub events: Vec<JsonWebhookEvent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonWebhookEvent {
+    Report,
+    Branch,
+    Threshold,
+    Alert,
+}
+
+impl fmt::Display for JsonWebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Report => "report",
+                Self::Branch => "branch",
+                Self::Threshold => "threshold",
+                Self::Alert => "alert",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDelivery {
+    pub uuid: Uuid,
+    pub webhook: Uuid,
+    pub event: JsonWebhookEvent,
+    pub payload: serde_json::Value,
+    pub attempts: u32,
+    /// The HTTP status code of the last attempt
+    pub status_code: Option<u16>,
+    /// The error from the last attempt, if the request failed to send
+    pub error: Option<String>,
+    pub success: bool,
+    pub created: DateTime<Utc>,
+    pub modified: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonWebhooksSort {
+    #[default]
+    Created,
+    Url,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonWebhookDeliveriesSort {
+    #[default]
+    Created,
+    Modified,
+}
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::ResourceId;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAuditEvent {
+    pub uuid: Uuid,
+    /// The user that performed the action
+    pub user: Uuid,
+    pub organization: Option<Uuid>,
+    pub project: Option<Uuid>,
+    pub action: JsonAuditAction,
+    /// The UUID of the resource that was acted on
+    pub resource: Option<Uuid>,
+    /// Any action specific details, such as a new role
+    pub details: Option<serde_json::Value>,
+    pub creation: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonAuditAction {
+    ServerRestart,
+    ServerBackup,
+    ServerConfigUpdate,
+    TokenCreate,
+    TokenRevoke,
+    MemberInvite,
+    MemberRoleUpdate,
+    MemberRemove,
+    ServiceAccountCreate,
+    ServiceAccountDelete,
+    ProjectCreate,
+    BranchCreate,
+    ThresholdCreate,
+    AlertUpdate,
+    NotificationDelete,
+    WebhookUpdate,
+    WebhookDelete,
+    RoleCreate,
+    RoleDelete,
+    ProjectUpdate,
+    ProjectRoleUpdate,
+    ProjectRoleRemove,
+    OrganizationCreate,
+    PlanCreate,
+    WebhookCreate,
+    NotificationCreate,
+    RoleUpdate,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAuditEvents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<JsonAuditAction>,
+    /// The user that performed the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<ResourceId>,
+    /// Only events at or after this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Utc>>,
+    /// Only events at or before this time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonAuditEventsSort {
+    #[default]
+    Creation,
+    Action,
+}
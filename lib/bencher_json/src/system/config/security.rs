@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 pub struct JsonSecurity {
     pub issuer: Option<String>,
     pub secret_key: Secret,
+    /// Allow webhooks to be sent to loopback, private, and link-local destinations
+    pub allow_private_destinations: Option<bool>,
 }
 
 impl Sanitize for JsonSecurity {
@@ -1,4 +1,4 @@
-use bencher_valid::{Jwt, NonEmpty};
+use bencher_valid::{Jwt, NonEmpty, ResourceId};
 use chrono::{DateTime, Utc};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -10,6 +10,28 @@ use uuid::Uuid;
 pub struct JsonNewToken {
     pub name: NonEmpty,
     pub ttl: Option<u32>,
+    /// Limit the token to these permissions on these projects.
+    /// If not set, then the token has all of the permissions of its user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<JsonTokenScope>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonTokenScope {
+    pub project: ResourceId,
+    pub permissions: Vec<JsonTokenPermission>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonTokenPermission {
+    View,
+    Create,
+    Edit,
+    Delete,
+    Manage,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -21,4 +43,15 @@ pub struct JsonToken {
     pub token: Jwt,
     pub creation: DateTime<Utc>,
     pub expiration: DateTime<Utc>,
+    pub scopes: Option<Vec<JsonTokenScope>>,
+    pub revoked: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JsonTokensSort {
+    #[default]
+    Creation,
+    Name,
 }
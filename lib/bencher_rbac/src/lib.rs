@@ -21,6 +21,9 @@ const CREATE_ROLE_PERM: &str = "create_role";
 const EDIT_ROLE_PERM: &str = "edit_role";
 const DELETE_ROLE_PERM: &str = "delete_role";
 
+const CREATE_REPORT_PERM: &str = "create_report";
+const MANAGE_THRESHOLD_PERM: &str = "manage_threshold";
+
 pub const POLAR: &str = include_str!("../bencher.polar");
 
 pub fn init_rbac() -> oso::Result<Oso> {
@@ -65,6 +68,9 @@ mod test {
             locked: false,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
         };
 
         assert!(oso
@@ -79,6 +85,9 @@ mod test {
             locked: false,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
         };
 
         assert!(!oso
@@ -93,6 +102,9 @@ mod test {
             locked: true,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
         };
 
         assert!(!oso
@@ -107,6 +119,9 @@ mod test {
             locked: true,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
         };
 
         assert!(!oso
@@ -126,6 +141,9 @@ mod test {
                 org_id.to_string() => OrgRole::Leader
             },
             projects: HashMap::new(),
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
         };
 
         let org_member = User {
@@ -135,6 +153,9 @@ mod test {
                 org_id.to_string() => OrgRole::Member
             },
             projects: HashMap::new(),
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
         };
 
         let proj_member = User {
@@ -146,6 +167,9 @@ mod test {
             projects: literally::hmap! {
                 proj_id.to_string() => ProjRole::Developer
             },
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
         };
 
         let org = Organization {
@@ -222,6 +246,12 @@ mod test {
         assert!(oso
             .is_allowed(proj_member.clone(), ProjPerm::Create, proj.clone())
             .unwrap());
+        assert!(oso
+            .is_allowed(proj_member.clone(), ProjPerm::CreateReport, proj.clone())
+            .unwrap());
+        assert!(oso
+            .is_allowed(proj_member.clone(), ProjPerm::ManageThreshold, proj.clone())
+            .unwrap());
         assert!(!oso
             .is_allowed(proj_member.clone(), ProjPerm::Manage, proj)
             .unwrap());
@@ -298,4 +328,242 @@ mod test {
             .is_allowed(proj_member, ProjPerm::Manage, other_proj)
             .unwrap());
     }
+
+    #[test]
+    fn test_rbac_scoped() {
+        let oso = &*OSO;
+
+        let server = Server {};
+
+        let org_id = Uuid::new_v4();
+        let proj_id = Uuid::new_v4();
+        let other_proj_id = Uuid::new_v4();
+
+        let org = Organization {
+            id: org_id.to_string(),
+        };
+        let proj = Project {
+            id: proj_id.to_string(),
+            organization_id: org_id.to_string(),
+        };
+        let other_proj = Project {
+            id: other_proj_id.to_string(),
+            organization_id: org_id.to_string(),
+        };
+
+        let scoped_admin = User {
+            admin: true,
+            locked: false,
+            organizations: HashMap::new(),
+            projects: HashMap::new(),
+            custom_roles: HashMap::new(),
+            scoped: true,
+            scopes: literally::hmap! {
+                proj_id.to_string() => vec![ProjPerm::View, ProjPerm::Create]
+            },
+        };
+
+        assert!(!oso
+            .is_allowed(scoped_admin.clone(), SvrPerm::Administer, server)
+            .unwrap());
+        assert!(!oso
+            .is_allowed(scoped_admin.clone(), OrgPerm::View, org.clone())
+            .unwrap());
+        assert!(oso
+            .is_allowed(scoped_admin.clone(), ProjPerm::View, proj.clone())
+            .unwrap());
+        assert!(oso
+            .is_allowed(scoped_admin.clone(), ProjPerm::Create, proj.clone())
+            .unwrap());
+        assert!(!oso
+            .is_allowed(scoped_admin.clone(), ProjPerm::Delete, proj.clone())
+            .unwrap());
+        // Fine-grained permissions are in scope wherever their coarse permission is
+        assert!(oso
+            .is_allowed(scoped_admin.clone(), ProjPerm::CreateReport, proj.clone())
+            .unwrap());
+        assert!(oso
+            .is_allowed(
+                scoped_admin.clone(),
+                ProjPerm::ManageThreshold,
+                proj.clone()
+            )
+            .unwrap());
+        assert!(!oso
+            .is_allowed(scoped_admin, ProjPerm::View, other_proj.clone())
+            .unwrap());
+
+        let scoped_viewer = User {
+            admin: false,
+            locked: false,
+            organizations: literally::hmap! {
+                org_id.to_string() => OrgRole::Member
+            },
+            projects: literally::hmap! {
+                proj_id.to_string() => ProjRole::Viewer,
+                other_proj_id.to_string() => ProjRole::Developer
+            },
+            custom_roles: HashMap::new(),
+            scoped: true,
+            scopes: literally::hmap! {
+                proj_id.to_string() => vec![ProjPerm::View, ProjPerm::Create]
+            },
+        };
+
+        assert!(!oso
+            .is_allowed(scoped_viewer.clone(), OrgPerm::View, org)
+            .unwrap());
+        assert!(oso
+            .is_allowed(scoped_viewer.clone(), ProjPerm::View, proj.clone())
+            .unwrap());
+        // The scope can not grant more than the role
+        assert!(!oso
+            .is_allowed(scoped_viewer.clone(), ProjPerm::Create, proj)
+            .unwrap());
+        // The role can not grant more than the scope
+        assert!(!oso
+            .is_allowed(scoped_viewer, ProjPerm::Create, other_proj)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_rbac_custom() {
+        let org_id = Uuid::new_v4();
+        let proj_id = Uuid::new_v4();
+        let other_proj_id = Uuid::new_v4();
+
+        let oso = &*OSO;
+
+        let proj = Project {
+            id: proj_id.to_string(),
+            organization_id: org_id.to_string(),
+        };
+        let other_proj = Project {
+            id: other_proj_id.to_string(),
+            organization_id: org_id.to_string(),
+        };
+
+        let reporter = User {
+            admin: false,
+            locked: false,
+            organizations: literally::hmap! {
+                org_id.to_string() => OrgRole::Member
+            },
+            projects: HashMap::new(),
+            custom_roles: literally::hmap! {
+                proj_id.to_string() => vec![ProjPerm::CreateReport]
+            },
+            scoped: false,
+            scopes: HashMap::new(),
+        };
+
+        assert!(oso
+            .is_allowed(reporter.clone(), ProjPerm::CreateReport, proj.clone())
+            .unwrap());
+        assert!(!oso
+            .is_allowed(reporter.clone(), ProjPerm::View, proj.clone())
+            .unwrap());
+        assert!(!oso
+            .is_allowed(reporter.clone(), ProjPerm::Create, proj.clone())
+            .unwrap());
+        assert!(!oso
+            .is_allowed(reporter.clone(), ProjPerm::ManageThreshold, proj.clone())
+            .unwrap());
+        assert!(!oso
+            .is_allowed(reporter, ProjPerm::CreateReport, other_proj.clone())
+            .unwrap());
+
+        let threshold_admin = User {
+            admin: false,
+            locked: false,
+            organizations: literally::hmap! {
+                org_id.to_string() => OrgRole::Member
+            },
+            projects: literally::hmap! {
+                other_proj_id.to_string() => ProjRole::Viewer
+            },
+            custom_roles: literally::hmap! {
+                proj_id.to_string() => vec![ProjPerm::View, ProjPerm::ManageThreshold]
+            },
+            scoped: false,
+            scopes: HashMap::new(),
+        };
+
+        assert!(oso
+            .is_allowed(threshold_admin.clone(), ProjPerm::View, proj.clone())
+            .unwrap());
+        assert!(oso
+            .is_allowed(
+                threshold_admin.clone(),
+                ProjPerm::ManageThreshold,
+                proj.clone()
+            )
+            .unwrap());
+        assert!(!oso
+            .is_allowed(
+                threshold_admin.clone(),
+                ProjPerm::CreateReport,
+                proj.clone()
+            )
+            .unwrap());
+        assert!(!oso
+            .is_allowed(threshold_admin.clone(), ProjPerm::Delete, proj.clone())
+            .unwrap());
+        assert!(oso
+            .is_allowed(threshold_admin.clone(), ProjPerm::View, other_proj.clone())
+            .unwrap());
+        assert!(!oso
+            .is_allowed(threshold_admin, ProjPerm::ManageThreshold, other_proj)
+            .unwrap());
+
+        // Built-in roles keep the fine-grained permissions
+        let developer = User {
+            admin: false,
+            locked: false,
+            organizations: literally::hmap! {
+                org_id.to_string() => OrgRole::Member
+            },
+            projects: literally::hmap! {
+                proj_id.to_string() => ProjRole::Developer
+            },
+            custom_roles: HashMap::new(),
+            scoped: false,
+            scopes: HashMap::new(),
+        };
+
+        assert!(oso
+            .is_allowed(developer.clone(), ProjPerm::CreateReport, proj.clone())
+            .unwrap());
+        assert!(oso
+            .is_allowed(developer, ProjPerm::ManageThreshold, proj.clone())
+            .unwrap());
+
+        // A scoped API token can not grant more than the custom role
+        let scoped_reporter = User {
+            admin: false,
+            locked: false,
+            organizations: literally::hmap! {
+                org_id.to_string() => OrgRole::Member
+            },
+            projects: HashMap::new(),
+            custom_roles: literally::hmap! {
+                proj_id.to_string() => vec![ProjPerm::CreateReport]
+            },
+            scoped: true,
+            scopes: literally::hmap! {
+                proj_id.to_string() => vec![ProjPerm::View, ProjPerm::Create]
+            },
+        };
+
+        assert!(oso
+            .is_allowed(
+                scoped_reporter.clone(),
+                ProjPerm::CreateReport,
+                proj.clone()
+            )
+            .unwrap());
+        assert!(!oso
+            .is_allowed(scoped_reporter, ProjPerm::View, proj)
+            .unwrap());
+    }
 }
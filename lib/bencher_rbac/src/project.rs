@@ -1,16 +1,17 @@
 use std::{fmt, str::FromStr};
 
+use bencher_json::{
+    organization::role::JsonRolePermission,
+    project::{DEVELOPER_ROLE, MAINTAINER_ROLE, VIEWER_ROLE},
+    user::token::JsonTokenPermission,
+};
 use oso::{PolarClass, PolarValue, ToPolar};
 
 use crate::{
-    CREATE_PERM, CREATE_ROLE_PERM, DELETE_PERM, DELETE_ROLE_PERM, EDIT_PERM, EDIT_ROLE_PERM,
-    MANAGE_PERM, VIEW_PERM, VIEW_ROLE_PERM,
+    CREATE_PERM, CREATE_REPORT_PERM, CREATE_ROLE_PERM, DELETE_PERM, DELETE_ROLE_PERM, EDIT_PERM,
+    EDIT_ROLE_PERM, MANAGE_PERM, MANAGE_THRESHOLD_PERM, VIEW_PERM, VIEW_ROLE_PERM,
 };
 
-const VIEWER_ROLE: &str = "viewer";
-const DEVELOPER_ROLE: &str = "developer";
-const MAINTAINER_ROLE: &str = "maintainer";
-
 #[derive(Debug, Clone, PolarClass)]
 pub struct Project {
     #[polar(attribute)]
@@ -19,7 +20,7 @@ pub struct Project {
     pub organization_id: String,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Role {
     Viewer,
     Developer,
@@ -70,6 +71,8 @@ pub enum Permission {
     CreateRole,
     EditRole,
     DeleteRole,
+    CreateReport,
+    ManageThreshold,
 }
 
 impl fmt::Display for Permission {
@@ -87,6 +90,8 @@ impl fmt::Display for Permission {
                 Self::CreateRole => CREATE_ROLE_PERM,
                 Self::EditRole => EDIT_ROLE_PERM,
                 Self::DeleteRole => DELETE_ROLE_PERM,
+                Self::CreateReport => CREATE_REPORT_PERM,
+                Self::ManageThreshold => MANAGE_THRESHOLD_PERM,
             }
         )
     }
@@ -97,3 +102,33 @@ impl ToPolar for Permission {
         PolarValue::String(self.to_string())
     }
 }
+
+impl From<JsonTokenPermission> for Permission {
+    fn from(permission: JsonTokenPermission) -> Self {
+        match permission {
+            JsonTokenPermission::View => Self::View,
+            JsonTokenPermission::Create => Self::Create,
+            JsonTokenPermission::Edit => Self::Edit,
+            JsonTokenPermission::Delete => Self::Delete,
+            JsonTokenPermission::Manage => Self::Manage,
+        }
+    }
+}
+
+impl From<JsonRolePermission> for Permission {
+    fn from(permission: JsonRolePermission) -> Self {
+        match permission {
+            JsonRolePermission::View => Self::View,
+            JsonRolePermission::Create => Self::Create,
+            JsonRolePermission::Edit => Self::Edit,
+            JsonRolePermission::Delete => Self::Delete,
+            JsonRolePermission::Manage => Self::Manage,
+            JsonRolePermission::ViewRole => Self::ViewRole,
+            JsonRolePermission::CreateRole => Self::CreateRole,
+            JsonRolePermission::EditRole => Self::EditRole,
+            JsonRolePermission::DeleteRole => Self::DeleteRole,
+            JsonRolePermission::CreateReport => Self::CreateReport,
+            JsonRolePermission::ManageThreshold => Self::ManageThreshold,
+        }
+    }
+}
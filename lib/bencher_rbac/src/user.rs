@@ -13,7 +13,17 @@ pub struct User {
     pub organizations: OrganizationRoles,
     #[polar(attribute)]
     pub projects: ProjectRoles,
+    /// The permissions of any custom role that an organization has given the user for each of its projects
+    #[polar(attribute)]
+    pub custom_roles: ProjectCustomRoles,
+    /// A scoped API token is limited to the listed permissions for each of its projects
+    #[polar(attribute)]
+    pub scoped: bool,
+    #[polar(attribute)]
+    pub scopes: ProjectScopes,
 }
 
 pub type OrganizationRoles = HashMap<String, crate::organization::Role>;
 pub type ProjectRoles = HashMap<String, crate::project::Role>;
+pub type ProjectCustomRoles = HashMap<String, Vec<crate::project::Permission>>;
+pub type ProjectScopes = HashMap<String, Vec<crate::project::Permission>>;
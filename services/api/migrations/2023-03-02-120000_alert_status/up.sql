@@ -0,0 +1,46 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE up_alert (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    perf_id INTEGER NOT NULL,
+    threshold_id INTEGER NOT NULL,
+    statistic_id INTEGER NOT NULL,
+    side BOOLEAN NOT NULL,
+    boundary REAL NOT NULL,
+    outlier REAL NOT NULL,
+    -- status: Active, Acknowledged, Dismissed, or Resolved
+    status INTEGER NOT NULL,
+    -- the user that last updated the status, null if automatically updated
+    status_user_id INTEGER,
+    -- the time of the last status update
+    status_time BIGINT,
+    FOREIGN KEY (perf_id) REFERENCES perf (id),
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id),
+    FOREIGN KEY (statistic_id) REFERENCES statistic (id),
+    FOREIGN KEY (status_user_id) REFERENCES user (id)
+);
+INSERT INTO up_alert(
+        id,
+        uuid,
+        perf_id,
+        threshold_id,
+        statistic_id,
+        side,
+        boundary,
+        outlier,
+        status
+    )
+SELECT id,
+    uuid,
+    perf_id,
+    threshold_id,
+    statistic_id,
+    side,
+    boundary,
+    outlier,
+    0
+FROM alert;
+DROP TABLE alert;
+ALTER TABLE up_alert
+    RENAME TO alert;
+PRAGMA foreign_keys = on;
\ No newline at end of file
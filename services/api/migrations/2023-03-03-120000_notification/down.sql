@@ -0,0 +1 @@
+DROP TABLE notification;
\ No newline at end of file
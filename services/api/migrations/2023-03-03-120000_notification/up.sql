@@ -0,0 +1,12 @@
+CREATE TABLE notification (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    -- email address to notify
+    email TEXT,
+    -- webhook URL to notify
+    webhook TEXT,
+    -- webhook signing secret
+    secret TEXT,
+    FOREIGN KEY (project_id) REFERENCES project (id)
+);
\ No newline at end of file
@@ -0,0 +1,28 @@
+CREATE TABLE webhook (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    -- payload signing secret
+    secret TEXT,
+    -- bit flags for the subscribed events: Report (1), Branch (2), or Threshold (4)
+    events INTEGER NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id)
+);
+CREATE TABLE webhook_delivery (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    webhook_id INTEGER NOT NULL,
+    -- event: Report (1), Branch (2), or Threshold (4)
+    event INTEGER NOT NULL,
+    payload TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    -- HTTP status code of the last attempt
+    status_code INTEGER,
+    -- error of the last attempt
+    error TEXT,
+    success BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (webhook_id) REFERENCES webhook (id) ON DELETE CASCADE
+);
\ No newline at end of file
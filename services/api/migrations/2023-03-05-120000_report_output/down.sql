@@ -0,0 +1 @@
+DROP TABLE report_output;
\ No newline at end of file
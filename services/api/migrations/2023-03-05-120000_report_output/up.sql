@@ -0,0 +1,10 @@
+CREATE TABLE report_output (
+    id INTEGER PRIMARY KEY NOT NULL,
+    report_id INTEGER NOT NULL,
+    iteration INTEGER NOT NULL,
+    -- raw benchmark command output
+    stdout TEXT NOT NULL,
+    stderr TEXT NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE,
+    UNIQUE(report_id, iteration)
+);
@@ -0,0 +1 @@
+DROP TABLE report_fingerprint;
\ No newline at end of file
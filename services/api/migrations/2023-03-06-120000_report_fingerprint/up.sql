@@ -0,0 +1,9 @@
+CREATE TABLE report_fingerprint (
+    id INTEGER PRIMARY KEY NOT NULL,
+    report_id INTEGER NOT NULL UNIQUE,
+    -- JSON testbed hardware and software fingerprint
+    fingerprint TEXT NOT NULL,
+    -- whether the fingerprint is different from the previous fingerprint for the testbed
+    changed BOOLEAN NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE
+);
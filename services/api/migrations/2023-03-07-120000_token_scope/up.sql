@@ -0,0 +1,36 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE up_token (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    jwt TEXT NOT NULL,
+    creation BIGINT NOT NULL,
+    expiration BIGINT NOT NULL,
+    -- JSON project permission scopes, null if the token is unscoped
+    scopes TEXT,
+    -- the time the token was revoked, null if it has not been revoked
+    revoked BIGINT,
+    FOREIGN KEY (user_id) REFERENCES user (id)
+);
+INSERT INTO up_token(
+        id,
+        uuid,
+        user_id,
+        name,
+        jwt,
+        creation,
+        expiration
+    )
+SELECT id,
+    uuid,
+    user_id,
+    name,
+    jwt,
+    creation,
+    expiration
+FROM token;
+DROP TABLE token;
+ALTER TABLE up_token
+    RENAME TO token;
+PRAGMA foreign_keys = on;
\ No newline at end of file
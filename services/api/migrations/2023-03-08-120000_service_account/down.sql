@@ -0,0 +1 @@
+DROP TABLE service_account;
\ No newline at end of file
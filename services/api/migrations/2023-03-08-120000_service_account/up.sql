@@ -0,0 +1,13 @@
+CREATE TABLE service_account (
+    id INTEGER PRIMARY KEY NOT NULL,
+    -- the user that the service account reports and tokens belong to
+    user_id INTEGER NOT NULL UNIQUE,
+    -- the organization that owns the service account
+    organization_id INTEGER NOT NULL,
+    -- the user that created the service account
+    creator_id INTEGER NOT NULL,
+    creation BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user (id),
+    FOREIGN KEY (organization_id) REFERENCES organization (id),
+    FOREIGN KEY (creator_id) REFERENCES user (id)
+);
\ No newline at end of file
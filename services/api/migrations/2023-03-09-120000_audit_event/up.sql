@@ -0,0 +1,31 @@
+CREATE TABLE audit_event (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    -- the user that performed the action
+    user_id INTEGER NOT NULL,
+    -- the organization and project that the action was performed in, if any
+    organization_id INTEGER,
+    project_id INTEGER,
+    -- action: ServerRestart (0), ServerBackup (1), ServerConfigUpdate (2), TokenCreate (3), TokenRevoke (4),
+    -- MemberInvite (5), MemberRoleUpdate (6), MemberRemove (7), ServiceAccountCreate (8), ServiceAccountDelete (9),
+    -- ProjectCreate (10), BranchCreate (11), ThresholdCreate (12), AlertUpdate (13), NotificationDelete (14),
+    -- WebhookUpdate (15), or WebhookDelete (16)
+    action INTEGER NOT NULL,
+    -- the UUID of the resource that was acted on
+    resource TEXT,
+    -- action specific JSON details
+    details TEXT,
+    creation BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user (id),
+    FOREIGN KEY (organization_id) REFERENCES organization (id),
+    FOREIGN KEY (project_id) REFERENCES project (id)
+);
+CREATE INDEX audit_event_organization_id ON audit_event (organization_id);
+CREATE INDEX audit_event_project_id ON audit_event (project_id);
+-- The audit log is append-only
+CREATE TRIGGER audit_event_no_update BEFORE UPDATE ON audit_event BEGIN
+SELECT RAISE(ABORT, 'audit_event is append-only');
+END;
+CREATE TRIGGER audit_event_no_delete BEFORE DELETE ON audit_event BEGIN
+SELECT RAISE(ABORT, 'audit_event is append-only');
+END;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE custom_role;
\ No newline at end of file
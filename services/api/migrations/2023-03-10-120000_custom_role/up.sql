@@ -0,0 +1,13 @@
+CREATE TABLE custom_role (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    -- the organization that defines the role for its projects
+    organization_id INTEGER NOT NULL,
+    -- the role name that is stored in project_role
+    name TEXT NOT NULL,
+    -- the JSON array of project permissions granted by the role
+    permissions TEXT NOT NULL,
+    creation BIGINT NOT NULL,
+    FOREIGN KEY (organization_id) REFERENCES organization (id),
+    UNIQUE(organization_id, name)
+);
\ No newline at end of file
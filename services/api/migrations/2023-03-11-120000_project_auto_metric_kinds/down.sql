@@ -0,0 +1,33 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE down_project (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    url TEXT,
+    visibility INTEGER NOT NULL,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(organization_id, name)
+);
+INSERT INTO down_project(
+        id,
+        uuid,
+        organization_id,
+        name,
+        slug,
+        url,
+        visibility
+    )
+SELECT id,
+    uuid,
+    organization_id,
+    name,
+    slug,
+    url,
+    visibility
+FROM project;
+DROP TABLE project;
+ALTER TABLE down_project
+    RENAME TO project;
+PRAGMA foreign_keys = on;
\ No newline at end of file
@@ -0,0 +1,35 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE up_project (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    url TEXT,
+    visibility INTEGER NOT NULL,
+    -- create missing metric kinds the first time that they are reported
+    auto_metric_kinds BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(organization_id, name)
+);
+INSERT INTO up_project(
+        id,
+        uuid,
+        organization_id,
+        name,
+        slug,
+        url,
+        visibility
+    )
+SELECT id,
+    uuid,
+    organization_id,
+    name,
+    slug,
+    url,
+    visibility
+FROM project;
+DROP TABLE project;
+ALTER TABLE up_project
+    RENAME TO project;
+PRAGMA foreign_keys = on;
\ No newline at end of file
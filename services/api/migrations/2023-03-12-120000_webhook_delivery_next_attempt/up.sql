@@ -0,0 +1,49 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE up_webhook_delivery (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    webhook_id INTEGER NOT NULL,
+    -- event: Report (1), Branch (2), or Threshold (4)
+    event INTEGER NOT NULL,
+    payload TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    -- HTTP status code of the last attempt
+    status_code INTEGER,
+    -- error of the last attempt
+    error TEXT,
+    success BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    -- time of the next attempt, if the delivery is still pending
+    next_attempt BIGINT,
+    FOREIGN KEY (webhook_id) REFERENCES webhook (id) ON DELETE CASCADE
+);
+INSERT INTO up_webhook_delivery(
+        id,
+        uuid,
+        webhook_id,
+        event,
+        payload,
+        attempts,
+        status_code,
+        error,
+        success,
+        created,
+        modified
+    )
+SELECT id,
+    uuid,
+    webhook_id,
+    event,
+    payload,
+    attempts,
+    status_code,
+    error,
+    success,
+    created,
+    modified
+FROM webhook_delivery;
+DROP TABLE webhook_delivery;
+ALTER TABLE up_webhook_delivery
+    RENAME TO webhook_delivery;
+PRAGMA foreign_keys = on;
\ No newline at end of file
@@ -0,0 +1,58 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE down_notification (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    -- email address to notify
+    email TEXT,
+    -- webhook URL to notify
+    webhook TEXT,
+    -- webhook signing secret
+    secret TEXT,
+    FOREIGN KEY (project_id) REFERENCES project (id)
+);
+INSERT INTO down_notification(
+        id,
+        uuid,
+        project_id,
+        email
+    )
+SELECT id,
+    uuid,
+    project_id,
+    email
+FROM notification;
+DROP TABLE notification;
+ALTER TABLE down_notification
+    RENAME TO notification;
+PRAGMA foreign_keys = on;
+-- move alert webhooks back to webhook notifications
+UPDATE notification
+SET webhook = (
+        SELECT url
+        FROM webhook
+        WHERE webhook.uuid = notification.uuid
+    ),
+    secret = (
+        SELECT secret
+        FROM webhook
+        WHERE webhook.uuid = notification.uuid
+    )
+WHERE uuid IN (
+        SELECT uuid
+        FROM webhook
+        WHERE events = 8
+    );
+INSERT INTO notification(uuid, project_id, webhook, secret)
+SELECT uuid,
+    project_id,
+    url,
+    secret
+FROM webhook
+WHERE events = 8
+    AND uuid NOT IN (
+        SELECT uuid
+        FROM notification
+    );
+DELETE FROM webhook
+WHERE events = 8;
\ No newline at end of file
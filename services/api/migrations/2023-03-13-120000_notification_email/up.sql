@@ -0,0 +1,34 @@
+-- move webhook notifications to project webhooks for the alert (8) event
+INSERT INTO webhook(uuid, project_id, url, secret, events)
+SELECT uuid,
+    project_id,
+    webhook,
+    secret,
+    8
+FROM notification
+WHERE webhook IS NOT NULL;
+PRAGMA foreign_keys = off;
+CREATE TABLE up_notification (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    -- email address to notify
+    email TEXT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id)
+);
+INSERT INTO up_notification(
+        id,
+        uuid,
+        project_id,
+        email
+    )
+SELECT id,
+    uuid,
+    project_id,
+    email
+FROM notification
+WHERE email IS NOT NULL;
+DROP TABLE notification;
+ALTER TABLE up_notification
+    RENAME TO notification;
+PRAGMA foreign_keys = on;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, sync::Arc};
 
 #[cfg(feature = "plus")]
 use bencher_json::system::config::JsonPlus;
@@ -24,6 +24,7 @@ use url::Url;
 use crate::{
     context::{ApiContext, Database, DbConnection, Email, Messenger, SecretKey},
     endpoints::Api,
+    model::project::webhook::delivery::spawn_worker as spawn_webhook_worker,
     util::registrar::Registrar,
     ApiError,
 };
@@ -75,6 +76,7 @@ impl TryFrom<ConfigTx> for HttpServer<ApiContext> {
             JsonSecurity {
                 issuer: None,
                 secret_key,
+                allow_private_destinations: None,
             }
         } else {
             return Err(ApiError::MissingConfigKey("security.secret_key".into()));
@@ -121,21 +123,28 @@ fn into_private(
     } else {
         None
     };
-    let secret_key = SecretKey::new(
-        security.issuer.unwrap_or_else(|| BENCHER_DOT_DEV.into()),
-        security.secret_key,
-    );
+    let JsonSecurity {
+        issuer,
+        secret_key,
+        allow_private_destinations,
+    } = security;
+    let secret_key = SecretKey::new(issuer.unwrap_or_else(|| BENCHER_DOT_DEV.into()), secret_key);
+    let allow_private_destinations = allow_private_destinations.unwrap_or_default();
     #[cfg(feature = "plus")]
     let Plus { biller, licensor } = Plus::new(&endpoint, plus)?;
 
+    let connection = Arc::new(tokio::sync::Mutex::new(database_connection));
+    spawn_webhook_worker(&connection, allow_private_destinations);
+
     Ok(ApiContext {
         endpoint,
         secret_key,
         rbac: init_rbac().map_err(ApiError::Polar)?.into(),
         messenger: into_messenger(smtp),
+        allow_private_destinations,
         database: Database {
             path: json_database.file,
-            connection: tokio::sync::Mutex::new(database_connection),
+            connection,
             data_store,
         },
         restart_tx,
@@ -160,7 +169,7 @@ fn diesel_database_url(database_path: &str) {
     std::env::set_var(DATABASE_URL, database_path);
 }
 
-fn run_migrations(database: &mut DbConnection) -> Result<(), ApiError> {
+pub(crate) fn run_migrations(database: &mut DbConnection) -> Result<(), ApiError> {
     database
         .run_pending_migrations(MIGRATIONS)
         .map(|_| ())
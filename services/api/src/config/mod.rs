@@ -161,6 +161,7 @@ impl Default for Config {
             security: Some(JsonSecurity {
                 issuer: Some(BENCHER_DOT_DEV.into()),
                 secret_key: DEFAULT_SECRET_KEY.clone(),
+                allow_private_destinations: None,
             }),
             server: JsonServer {
                 bind_address: *DEFAULT_BIND_ADDRESS,
@@ -6,6 +6,7 @@ use crate::ApiError;
 use super::body::FmtBody;
 use super::Message;
 
+#[derive(Clone)]
 pub struct Email {
     pub hostname: String,
     pub port: u16,
@@ -1,11 +1,14 @@
 mod body;
 mod email;
 mod message;
+mod webhook;
 
 pub use body::{Body, ButtonBody};
 pub use email::Email;
 pub use message::Message;
+pub use webhook::Webhook;
 
+#[derive(Clone)]
 pub enum Messenger {
     StdOut,
     Email(Email),
@@ -0,0 +1,214 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+use bencher_json::Secret;
+use hmac::{Hmac, Mac};
+use reqwest::{redirect::Policy, StatusCode, Url};
+use sha2::Sha256;
+
+use crate::ApiError;
+
+pub const SIGNATURE_HEADER: &str = "X-Bencher-Signature";
+pub const EVENT_HEADER: &str = "X-Bencher-Event";
+pub const DELIVERY_HEADER: &str = "X-Bencher-Delivery";
+
+#[derive(Clone)]
+pub struct Webhook {
+    pub url: String,
+    pub secret: Option<Secret>,
+    /// Allow loopback, private, and link-local destinations
+    pub allow_private_destinations: bool,
+}
+
+impl Webhook {
+    pub const MAX_ATTEMPTS: u32 = 5;
+    /// The max time to wait for a single attempt to complete
+    pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Make a single attempt to send the payload, returning the response status code
+    pub async fn post(
+        &self,
+        event: &str,
+        delivery: Option<&str>,
+        payload: &str,
+    ) -> Result<StatusCode, ApiError> {
+        let mut request = client(&self.url, self.allow_private_destinations)
+            .await?
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, event)
+            .body(payload.to_owned());
+        if let Some(delivery) = delivery {
+            request = request.header(DELIVERY_HEADER, delivery);
+        }
+        if let Some(secret) = &self.secret {
+            request = request.header(SIGNATURE_HEADER, signature(secret, payload.as_bytes())?);
+        }
+        request
+            .send()
+            .await
+            .map(|response| response.status())
+            .map_err(ApiError::WebhookSend)
+    }
+
+    /// Verify that the webhook URL is a public destination,
+    /// unless private destinations are allowed by the server config
+    pub async fn validate_url(url: &str, allow_private_destinations: bool) -> Result<(), ApiError> {
+        resolve(url, allow_private_destinations).await.map(|_| ())
+    }
+
+    /// Exponential backoff between attempts: 1, 2, 4, 8... seconds
+    pub fn backoff(attempt: u32) -> Duration {
+        Duration::from_secs(2u64.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+/// Build a client for the webhook URL that is pinned to its resolved address,
+/// so that the destination can not be changed between validating and sending.
+/// Redirects are not followed, as they could lead to a disallowed destination.
+async fn client(url: &str, allow_private_destinations: bool) -> Result<reqwest::Client, ApiError> {
+    let (url, addrs) = resolve(url, allow_private_destinations).await?;
+    let builder = reqwest::Client::builder()
+        .timeout(Webhook::TIMEOUT)
+        .redirect(Policy::none());
+    let builder = match (url.domain(), addrs.first()) {
+        (Some(domain), Some(addr)) => builder.resolve(domain, *addr),
+        _ => builder,
+    };
+    builder.build().map_err(ApiError::WebhookSend)
+}
+
+/// Resolve the webhook URL and verify that none of its addresses are
+/// loopback, private, or link-local destinations, unless they are allowed.
+async fn resolve(
+    url: &str,
+    allow_private_destinations: bool,
+) -> Result<(Url, Vec<SocketAddr>), ApiError> {
+    let destination = || ApiError::WebhookDestination(url.into());
+    let parsed_url = Url::parse(url).map_err(|_e| destination())?;
+    let host = parsed_url.host_str().ok_or_else(destination)?;
+    let port = parsed_url.port_or_known_default().ok_or_else(destination)?;
+    // IPv6 literals are bracketed in URLs
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let addrs = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_e| destination())?
+        .collect::<Vec<_>>();
+    if addrs.is_empty()
+        || !(allow_private_destinations || addrs.iter().all(|addr| is_allowed_ip(addr.ip())))
+    {
+        return Err(destination());
+    }
+    Ok((parsed_url, addrs))
+}
+
+fn is_allowed_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            // "This network" (0.0.0.0/8) and shared address space (100.64.0.0/10) addresses
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_multicast()
+                || ip.is_broadcast()
+                || first == 0
+                || (first == 100 && second & 0xc0 == 64))
+        },
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_allowed_ip(IpAddr::V4(ip));
+            }
+            let segments = ip.segments();
+            // Unique local (fc00::/7), link-local (fe80::/10), NAT64 (64:ff9b::/96),
+            // and IPv4-compatible (::/96), including the loopback and unspecified addresses
+            !(ip.is_multicast()
+                || segments[0] & 0xfe00 == 0xfc00
+                || segments[0] & 0xffc0 == 0xfe80
+                || segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0]
+                || segments[..6] == [0; 6])
+        },
+    }
+}
+
+/// The hex encoded HMAC-SHA256 of the payload, prefixed with `sha256=`
+pub fn signature(secret: &Secret, payload: &[u8]) -> Result<String, ApiError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_ref().as_bytes())
+        .map_err(ApiError::WebhookSignature)?;
+    mac.update(payload);
+    Ok(format!(
+        "sha256={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use bencher_json::Secret;
+
+    use super::{is_allowed_ip, resolve, signature};
+
+    // https://www.rfc-editor.org/rfc/rfc4231#section-4.3
+    #[test]
+    fn test_signature() {
+        let secret = Secret::from_str("Jefe").unwrap();
+        assert_eq!(
+            signature(&secret, b"what do ya want for nothing?").unwrap(),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_is_allowed_ip() {
+        for ip in [
+            "93.184.216.34",
+            "100.63.255.255",
+            "100.128.0.1",
+            "2606:2800:220:1:248:1893:25c8:1946",
+            "::ffff:93.184.216.34",
+        ] {
+            assert!(is_allowed_ip(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "0.0.0.0",
+            "0.1.2.3",
+            "100.64.0.1",
+            "100.127.255.254",
+            "224.0.0.1",
+            "239.255.255.250",
+            "255.255.255.255",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "ff02::1",
+            "64:ff9b::7f00:1",
+            "64:ff9b::5db8:d822",
+            "::7f00:1",
+            "::5db8:d822",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+            "::ffff:100.64.0.1",
+        ] {
+            assert!(!is_allowed_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_private() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let url = "http://127.0.0.1:8080/webhook";
+        assert!(runtime.block_on(resolve(url, false)).is_err());
+        let (_url, addrs) = runtime.block_on(resolve(url, true)).unwrap();
+        assert_eq!(addrs, vec!["127.0.0.1:8080".parse().unwrap()]);
+    }
+}
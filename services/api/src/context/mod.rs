@@ -11,7 +11,7 @@ mod rbac;
 mod secret_key;
 
 pub use database::{Database, DbConnection};
-pub use messenger::{Body, ButtonBody, Email, Message, Messenger};
+pub use messenger::{Body, ButtonBody, Email, Message, Messenger, Webhook};
 pub use rbac::Rbac;
 pub use secret_key::SecretKey;
 
@@ -20,6 +20,8 @@ pub struct ApiContext {
     pub secret_key: SecretKey,
     pub rbac: Rbac,
     pub messenger: Messenger,
+    /// Allow webhooks to be sent to loopback, private, and link-local destinations
+    pub allow_private_destinations: bool,
     pub database: Database,
     pub restart_tx: Sender<()>,
     #[cfg(feature = "plus")]
@@ -1,6 +1,8 @@
 use std::fmt;
 
+use bencher_json::pagination::TOTAL_COUNT_HEADER;
 use dropshot::{HttpCodedResponse, HttpResponseAccepted, HttpResponseHeaders, HttpResponseOk};
+use http::header::HeaderValue;
 use schemars::JsonSchema;
 use serde::Serialize;
 
@@ -65,6 +67,20 @@ impl Endpoint {
     }
 }
 
+/// Add the total number of items across all pages to a list response
+pub fn total_count<T>(mut response: ResponseOk<T>, total_count: i64) -> ResponseOk<T>
+where
+    T: JsonSchema + Serialize + Send + Sync,
+{
+    let headers = response.headers_mut();
+    headers.insert(TOTAL_COUNT_HEADER, HeaderValue::from(total_count));
+    headers.insert(
+        "Access-Control-Expose-Headers",
+        HeaderValue::from_static(TOTAL_COUNT_HEADER),
+    );
+    response
+}
+
 impl fmt::Debug for Endpoint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         <Endpoint as fmt::Display>::fmt(self, f)
@@ -88,6 +104,12 @@ macro_rules! pub_response_ok {
     ($endpoint:expr, $body:expr) => {
         Ok($endpoint.pub_response_headers(dropshot::HttpResponseOk($body)))
     };
+    ($endpoint:expr, $body:expr, $total_count:expr) => {
+        Ok(crate::endpoints::endpoint::total_count(
+            $endpoint.pub_response_headers(dropshot::HttpResponseOk($body)),
+            $total_count,
+        ))
+    };
 }
 
 pub(crate) use pub_response_ok;
@@ -104,6 +126,12 @@ macro_rules! response_ok {
     ($endpoint:expr, $body:expr) => {
         Ok($endpoint.response_headers(dropshot::HttpResponseOk($body)))
     };
+    ($endpoint:expr, $body:expr, $total_count:expr) => {
+        Ok(crate::endpoints::endpoint::total_count(
+            $endpoint.response_headers(dropshot::HttpResponseOk($body)),
+            $total_count,
+        ))
+    };
 }
 
 pub(crate) use response_ok;
@@ -35,6 +35,8 @@ fn register(api: &mut ApiDescription<ApiContext>) -> Result<(), String> {
     api.register(system::server::config::get_one)?;
     api.register(system::server::backup::options)?;
     api.register(system::server::backup::post)?;
+    api.register(system::server::audit::options)?;
+    api.register(system::server::audit::get_ls)?;
     // Auth
     api.register(system::auth::signup::options)?;
     api.register(system::auth::signup::post)?;
@@ -59,12 +61,33 @@ fn register(api: &mut ApiDescription<ApiContext>) -> Result<(), String> {
     api.register(organization::members::one_options)?;
     api.register(organization::members::get_one)?;
     api.register(organization::members::patch)?;
+    api.register(organization::members::project_options)?;
+    api.register(organization::members::put_project)?;
+    api.register(organization::members::delete_project)?;
     // Organization Projects
     api.register(organization::projects::dir_options)?;
     api.register(organization::projects::get_ls)?;
     api.register(organization::projects::post)?;
     api.register(organization::projects::one_options)?;
     api.register(organization::projects::get_one)?;
+    // Organization Custom Roles
+    api.register(organization::roles::dir_options)?;
+    api.register(organization::roles::get_ls)?;
+    api.register(organization::roles::post)?;
+    api.register(organization::roles::one_options)?;
+    api.register(organization::roles::get_one)?;
+    api.register(organization::roles::patch)?;
+    api.register(organization::roles::delete)?;
+    // Organization Service Accounts
+    api.register(organization::service_accounts::dir_options)?;
+    api.register(organization::service_accounts::get_ls)?;
+    api.register(organization::service_accounts::post)?;
+    api.register(organization::service_accounts::one_options)?;
+    api.register(organization::service_accounts::get_one)?;
+    api.register(organization::service_accounts::delete)?;
+    // Organization Audit Log
+    api.register(organization::audit::dir_options)?;
+    api.register(organization::audit::get_ls)?;
     // Organization Metered Subscription Plan
     #[cfg(feature = "plus")]
     api.register(organization::plan::options)?;
@@ -84,18 +107,27 @@ fn register(api: &mut ApiDescription<ApiContext>) -> Result<(), String> {
     api.register(project::projects::get_ls)?;
     api.register(project::projects::one_options)?;
     api.register(project::projects::get_one)?;
+    api.register(project::projects::patch)?;
     // Perf
     api.register(project::perf::options)?;
     api.register(project::perf::get)?;
     // Perf Image
     api.register(project::perf::img::options)?;
     api.register(project::perf::img::get)?;
+    // Perf Changepoints
+    api.register(project::perf::changepoints::options)?;
+    api.register(project::perf::changepoints::get)?;
+    // Compare
+    api.register(project::compare::options)?;
+    api.register(project::compare::get)?;
     // Reports
     api.register(project::reports::dir_options)?;
     api.register(project::reports::get_ls)?;
     api.register(project::reports::post)?;
     api.register(project::reports::one_options)?;
     api.register(project::reports::get_one)?;
+    api.register(project::reports::output_options)?;
+    api.register(project::reports::get_output)?;
     // Results
     api.register(project::results::one_options)?;
     api.register(project::results::get_one)?;
@@ -133,6 +165,27 @@ fn register(api: &mut ApiDescription<ApiContext>) -> Result<(), String> {
     api.register(project::alerts::get_ls)?;
     api.register(project::alerts::one_options)?;
     api.register(project::alerts::get_one)?;
+    api.register(project::alerts::patch)?;
+    // Notifications
+    api.register(project::notifications::dir_options)?;
+    api.register(project::notifications::get_ls)?;
+    api.register(project::notifications::post)?;
+    api.register(project::notifications::one_options)?;
+    api.register(project::notifications::get_one)?;
+    api.register(project::notifications::delete)?;
+    // Webhooks
+    api.register(project::webhooks::dir_options)?;
+    api.register(project::webhooks::get_ls)?;
+    api.register(project::webhooks::post)?;
+    api.register(project::webhooks::one_options)?;
+    api.register(project::webhooks::get_one)?;
+    api.register(project::webhooks::patch)?;
+    api.register(project::webhooks::delete)?;
+    api.register(project::webhooks::deliveries_options)?;
+    api.register(project::webhooks::get_deliveries)?;
+    // Audit Log
+    api.register(project::audit::dir_options)?;
+    api.register(project::audit::get_ls)?;
 
     // Users
     api.register(user::users::one_options)?;
@@ -143,6 +196,7 @@ fn register(api: &mut ApiDescription<ApiContext>) -> Result<(), String> {
     api.register(user::tokens::post)?;
     api.register(user::tokens::one_options)?;
     api.register(user::tokens::get_one)?;
+    api.register(user::tokens::delete)?;
 
     Ok(())
 }
@@ -0,0 +1,100 @@
+use bencher_json::{
+    system::audit::{JsonAuditEvent, JsonAuditEvents, JsonAuditEventsSort},
+    JsonPagination, ResourceId,
+};
+use bencher_rbac::organization::Permission;
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{response_ok, ResponseOk},
+        Endpoint, Method,
+    },
+    model::{
+        audit::{AuditScope, QueryAuditEvent},
+        organization::QueryOrganization,
+        user::auth::AuthUser,
+    },
+    util::cors::{get_cors, CorsResponse},
+    ApiError,
+};
+
+use super::Resource;
+
+const AUDIT_RESOURCE: Resource = Resource::AuditEvent;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DirPath {
+    pub organization: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/audit",
+    tags = ["organizations", "audit"]
+}]
+pub async fn dir_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<DirPath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/audit",
+    tags = ["organizations", "audit"]
+}]
+pub async fn get_ls(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonAuditEventsSort>>,
+    query_params: Query<JsonAuditEvents>,
+) -> Result<ResponseOk<Vec<JsonAuditEvent>>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(AUDIT_RESOURCE, Method::GetLs);
+
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        &pagination_params.into_inner(),
+        &query_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json, total_count)
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: DirPath,
+    pagination_params: &JsonPagination<JsonAuditEventsSort>,
+    json_audit_events: &JsonAuditEvents,
+    endpoint: Endpoint,
+) -> Result<(Vec<JsonAuditEvent>, i64), ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    QueryAuditEvent::get_ls(
+        conn,
+        AuditScope::Organization(query_organization.id),
+        json_audit_events,
+        pagination_params,
+        endpoint,
+    )
+}
@@ -1,12 +1,16 @@
 use std::str::FromStr;
 
 use bencher_json::{
-    organization::member::{JsonNewMember, JsonUpdateMember},
-    JsonEmpty, JsonMember, ResourceId,
+    organization::member::{
+        JsonMemberProject, JsonMembersSort, JsonNewMember, JsonUpdateMember,
+        JsonUpdateMemberProject,
+    },
+    project::JsonProjectRole,
+    JsonDirection, JsonEmpty, JsonMember, JsonPagination, ResourceId,
 };
 use bencher_rbac::organization::Permission;
 use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use uuid::Uuid;
@@ -18,8 +22,12 @@ use crate::{
         Endpoint, Method,
     },
     error::api_error,
-    model::organization::{member::QueryMember, QueryOrganization},
     model::user::{auth::AuthUser, QueryUser},
+    model::{
+        audit::{AuditAction, InsertAuditEvent},
+        organization::{custom_role::QueryCustomRole, member::QueryMember, QueryOrganization},
+        project::{project_role::InsertProjectRole, QueryProject},
+    },
     schema,
     util::{
         cors::{get_cors, CorsResponse},
@@ -61,28 +69,31 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonMembersSort>>,
 ) -> Result<ResponseOk<Vec<JsonMember>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await?;
     let endpoint = Endpoint::new(MEMBER_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         &auth_user,
         path_params.into_inner(),
+        &pagination_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
-    response_ok!(endpoint, json)
+    response_ok!(endpoint, json, total_count)
 }
 
 async fn get_ls_inner(
     context: &ApiContext,
     auth_user: &AuthUser,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonMembersSort>,
     endpoint: Endpoint,
-) -> Result<Vec<JsonMember>, ApiError> {
+) -> Result<(Vec<JsonMember>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_organization = QueryOrganization::is_allowed_resource_id(
@@ -93,25 +104,60 @@ async fn get_ls_inner(
         Permission::ViewRole,
     )?;
 
-    Ok(schema::user::table
-        .inner_join(
-            schema::organization_role::table
-                .on(schema::user::id.eq(schema::organization_role::user_id)),
-        )
-        .filter(schema::organization_role::organization_id.eq(query_organization.id))
-        .select((
-            schema::user::uuid,
-            schema::user::name,
-            schema::user::slug,
-            schema::user::email,
-            schema::organization_role::role,
-        ))
-        .order((schema::user::name, schema::user::slug))
+    let filtered = || {
+        schema::user::table
+            .inner_join(
+                schema::organization_role::table
+                    .on(schema::user::id.eq(schema::organization_role::user_id)),
+            )
+            .filter(schema::organization_role::organization_id.eq(query_organization.id))
+            // Service accounts are not human members of the organization
+            .filter(
+                schema::user::id.ne_all(
+                    schema::service_account::table.select(schema::service_account::user_id),
+                ),
+            )
+            .select((
+                schema::user::uuid,
+                schema::user::name,
+                schema::user::slug,
+                schema::user::email,
+                schema::organization_role::role,
+            ))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonMembersSort::Name, JsonDirection::Asc) => {
+            filtered().order((schema::user::name.asc(), schema::user::slug.asc()))
+        },
+        (JsonMembersSort::Name, JsonDirection::Desc) => {
+            filtered().order((schema::user::name.desc(), schema::user::slug.desc()))
+        },
+        (JsonMembersSort::Email, JsonDirection::Asc) => filtered().order(schema::user::email.asc()),
+        (JsonMembersSort::Email, JsonDirection::Desc) => {
+            filtered().order(schema::user::email.desc())
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryMember>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -184,6 +230,13 @@ async fn post_inner(
     )?;
     let token_string = token.to_string();
 
+    InsertAuditEvent::organization(conn, auth_user.id, query_org.id, AuditAction::MemberInvite)?
+        .details(&serde_json::json!({
+            "email": email,
+            "role": json_new_member.role,
+        }))?
+        .insert(conn)?;
+
     let org_name = &query_org.name;
     let org_role = json_new_member.role;
     let body = Body::Button(Box::new(ButtonBody {
@@ -337,6 +390,16 @@ async fn patch_inner(
         .set(schema::organization_role::role.eq(role.to_string()))
         .execute(conn)
         .map_err(api_error!())?;
+
+        InsertAuditEvent::organization(
+            conn,
+            auth_user.id,
+            query_organization.id,
+            AuditAction::MemberRoleUpdate,
+        )?
+        .resource(&query_user.uuid)
+        .details(&serde_json::json!({ "role": role }))?
+        .insert(conn)?;
     }
 
     json_member(conn, query_user.id, query_organization.id)
@@ -387,6 +450,15 @@ async fn delete_inner(
     .execute(conn)
     .map_err(api_error!())?;
 
+    InsertAuditEvent::organization(
+        conn,
+        auth_user.id,
+        query_organization.id,
+        AuditAction::MemberRemove,
+    )?
+    .resource(&query_user.uuid)
+    .insert(conn)?;
+
     Ok(json_member)
 }
 
@@ -413,3 +485,201 @@ fn json_member(
         .map_err(api_error!())?
         .into_json()
 }
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjectPath {
+    pub organization: ResourceId,
+    pub user: ResourceId,
+    pub project: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/members/{user}/projects/{project}",
+    tags = ["organizations", "members"]
+}]
+pub async fn project_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjectPath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = PUT,
+    path =  "/v0/organizations/{organization}/members/{user}/projects/{project}",
+    tags = ["organizations", "members"]
+}]
+pub async fn put_project(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjectPath>,
+    body: TypedBody<JsonUpdateMemberProject>,
+) -> Result<ResponseAccepted<JsonMemberProject>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(MEMBER_RESOURCE, Method::Put);
+
+    let json = put_project_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn put_project_inner(
+    context: &ApiContext,
+    path_params: ProjectPath,
+    json_update: JsonUpdateMemberProject,
+    auth_user: &AuthUser,
+) -> Result<JsonMemberProject, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let (query_user, query_project) = member_project(
+        conn,
+        context,
+        &path_params,
+        auth_user,
+        bencher_rbac::project::Permission::CreateRole,
+    )?;
+
+    let JsonUpdateMemberProject { role } = json_update;
+    if let JsonProjectRole::Custom(custom_role) = &role {
+        if !QueryCustomRole::exists(conn, query_project.organization_id, custom_role.as_ref()) {
+            return Err(ApiError::UnknownProjectRole(
+                query_project.organization_id,
+                custom_role.to_string(),
+            ));
+        }
+    }
+
+    // A member has at most one role on each project, so any existing role is replaced
+    delete_project_role(conn, query_user.id, query_project.id)?;
+    let insert_proj_role = InsertProjectRole {
+        user_id: query_user.id,
+        project_id: query_project.id,
+        role: role.to_string(),
+    };
+    diesel::insert_into(schema::project_role::table)
+        .values(&insert_proj_role)
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::ProjectRoleUpdate,
+    )?
+    .resource(&query_user.uuid)
+    .details(&serde_json::json!({ "role": role }))?
+    .insert(conn)?;
+
+    Ok(JsonMemberProject {
+        user: Uuid::from_str(&query_user.uuid).map_err(api_error!())?,
+        project: Uuid::from_str(&query_project.uuid).map_err(api_error!())?,
+        role,
+    })
+}
+
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/organizations/{organization}/members/{user}/projects/{project}",
+    tags = ["organizations", "members"]
+}]
+pub async fn delete_project(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjectPath>,
+) -> Result<ResponseAccepted<JsonEmpty>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(MEMBER_RESOURCE, Method::Delete);
+
+    let json = delete_project_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn delete_project_inner(
+    context: &ApiContext,
+    path_params: ProjectPath,
+    auth_user: &AuthUser,
+) -> Result<JsonEmpty, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let (query_user, query_project) = member_project(
+        conn,
+        context,
+        &path_params,
+        auth_user,
+        bencher_rbac::project::Permission::DeleteRole,
+    )?;
+
+    delete_project_role(conn, query_user.id, query_project.id)?;
+
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::ProjectRoleRemove,
+    )?
+    .resource(&query_user.uuid)
+    .insert(conn)?;
+
+    Ok(JsonEmpty {})
+}
+
+/// The user must be a human member of the organization and the project must belong to it
+fn member_project(
+    conn: &mut DbConnection,
+    context: &ApiContext,
+    path_params: &ProjectPath,
+    auth_user: &AuthUser,
+    permission: bencher_rbac::project::Permission,
+) -> Result<(QueryUser, QueryProject), ApiError> {
+    let query_organization = QueryOrganization::from_resource_id(conn, &path_params.organization)?;
+    let query_project = QueryProject::from_resource_id(conn, &path_params.project)?;
+    if query_project.organization_id != query_organization.id {
+        return Err(ApiError::MemberProject {
+            organization_id: query_organization.id,
+            project_id: query_project.id,
+        });
+    }
+    context
+        .rbac
+        .is_allowed_project(auth_user, permission, &query_project)?;
+
+    let query_user = QueryUser::from_resource_id(conn, &path_params.user)?;
+    let service_account = schema::service_account::table
+        .filter(schema::service_account::user_id.eq(query_user.id))
+        .select(schema::service_account::id)
+        .first::<i32>(conn)
+        .is_ok();
+    if service_account {
+        return Err(ApiError::ServiceAccountMember(query_user.id));
+    }
+    // Verify that the user is a member of the organization
+    json_member(conn, query_user.id, query_organization.id)?;
+
+    Ok((query_user, query_project))
+}
+
+fn delete_project_role(
+    conn: &mut DbConnection,
+    user_id: i32,
+    project_id: i32,
+) -> Result<(), ApiError> {
+    diesel::delete(
+        schema::project_role::table
+            .filter(schema::project_role::user_id.eq(user_id))
+            .filter(schema::project_role::project_id.eq(project_id)),
+    )
+    .execute(conn)
+    .map_err(api_error!())?;
+    Ok(())
+}
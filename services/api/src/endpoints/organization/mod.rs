@@ -3,20 +3,26 @@ use derive_more::Display;
 use crate::WordStr;
 
 pub mod allowed;
+pub mod audit;
 pub mod members;
 pub mod organizations;
 #[cfg(feature = "plus")]
 pub mod plan;
 pub mod projects;
+pub mod roles;
+pub mod service_accounts;
 #[cfg(feature = "plus")]
 pub mod usage;
 
 #[derive(Debug, Display, Clone, Copy)]
 pub enum Resource {
+    AuditEvent,
     Member,
     Organization,
     OrganizationPermission,
     Project,
+    Role,
+    ServiceAccount,
     #[cfg(feature = "plus")]
     Plan,
     #[cfg(feature = "plus")]
@@ -26,10 +32,13 @@ pub enum Resource {
 impl WordStr for Resource {
     fn singular(&self) -> &str {
         match self {
+            Self::AuditEvent => "audit event",
             Self::Member => "member",
             Self::Organization => "organization",
             Self::OrganizationPermission => "organization permission",
             Self::Project => "project",
+            Self::Role => "role",
+            Self::ServiceAccount => "service account",
             #[cfg(feature = "plus")]
             Self::Plan => "plan",
             #[cfg(feature = "plus")]
@@ -39,10 +48,13 @@ impl WordStr for Resource {
 
     fn plural(&self) -> &str {
         match self {
+            Self::AuditEvent => "audit events",
             Self::Member => "members",
             Self::Organization => "organizations",
             Self::OrganizationPermission => "organization permissions",
             Self::Project => "projects",
+            Self::Role => "roles",
+            Self::ServiceAccount => "service accounts",
             #[cfg(feature = "plus")]
             Self::Plan => "plans",
             #[cfg(feature = "plus")]
@@ -1,7 +1,10 @@
-use bencher_json::{JsonNewOrganization, JsonOrganization, ResourceId};
+use bencher_json::{
+    organization::JsonOrganizationsSort, JsonDirection, JsonNewOrganization, JsonOrganization,
+    JsonPagination, ResourceId,
+};
 use bencher_rbac::organization::{Permission, Role};
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -13,6 +16,7 @@ use crate::{
     },
     error::api_error,
     model::{
+        audit::{AuditAction, InsertAuditEvent},
         organization::{
             organization_role::InsertOrganizationRole, InsertOrganization, QueryOrganization,
         },
@@ -47,38 +51,77 @@ pub async fn dir_options(_rqctx: RequestContext<ApiContext>) -> Result<CorsRespo
 }]
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
+    pagination_params: Query<JsonPagination<JsonOrganizationsSort>>,
 ) -> Result<ResponseOk<Vec<JsonOrganization>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await?;
     let endpoint = Endpoint::new(ORGANIZATION_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(rqctx.context(), &auth_user, endpoint)
-        .await
-        .map_err(|e| endpoint.err(e))?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        &pagination_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
 
-    response_ok!(endpoint, json)
+    response_ok!(endpoint, json, total_count)
 }
 
 async fn get_ls_inner(
     context: &ApiContext,
     auth_user: &AuthUser,
+    pagination_params: &JsonPagination<JsonOrganizationsSort>,
     endpoint: Endpoint,
-) -> Result<Vec<JsonOrganization>, ApiError> {
+) -> Result<(Vec<JsonOrganization>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
-    let mut sql = schema::organization::table.into_boxed();
+    let organizations = (!auth_user.is_admin(&context.rbac))
+        .then(|| auth_user.organizations(&context.rbac, Permission::View));
 
-    if !auth_user.is_admin(&context.rbac) {
-        let organizations = auth_user.organizations(&context.rbac, Permission::View);
-        sql = sql.filter(schema::organization::id.eq_any(organizations));
-    }
+    let filtered = || {
+        let mut sql = schema::organization::table.into_boxed();
+        if let Some(organizations) = &organizations {
+            sql = sql.filter(schema::organization::id.eq_any(organizations.clone()));
+        }
+        sql
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
 
-    Ok(sql
-        .order((schema::organization::name, schema::organization::slug))
+    let sql = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonOrganizationsSort::Name, JsonDirection::Asc) => filtered().order((
+            schema::organization::name.asc(),
+            schema::organization::slug.asc(),
+        )),
+        (JsonOrganizationsSort::Name, JsonDirection::Desc) => filtered().order((
+            schema::organization::name.desc(),
+            schema::organization::slug.desc(),
+        )),
+        (JsonOrganizationsSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::organization::id.asc())
+        },
+        (JsonOrganizationsSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::organization::id.desc())
+        },
+    };
+
+    let json = sql
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryOrganization>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -133,6 +176,15 @@ async fn post_inner(
         .execute(conn)
         .map_err(api_error!())?;
 
+    InsertAuditEvent::organization(
+        conn,
+        auth_user.id,
+        query_organization.id,
+        AuditAction::OrganizationCreate,
+    )?
+    .resource(&query_organization.uuid)
+    .insert(conn)?;
+
     query_organization.into_json()
 }
 
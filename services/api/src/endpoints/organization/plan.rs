@@ -20,6 +20,7 @@ use crate::{
         Endpoint, Method,
     },
     error::api_error,
+    model::audit::{AuditAction, InsertAuditEvent},
     model::organization::QueryOrganization,
     model::user::{auth::AuthUser, QueryUser},
     schema,
@@ -136,6 +137,12 @@ async fn post_inner(
         .execute(conn)
         .map_err(api_error!())?;
 
+    // The payment card itself is not recorded
+    InsertAuditEvent::organization(conn, auth_user.id, query_org.id, AuditAction::PlanCreate)?
+        .resource(subscription.id.as_ref())
+        .details(&serde_json::json!({ "level": json_plan.level }))?
+        .insert(conn)?;
+
     Ok(JsonEmpty::default())
 }
 
@@ -1,7 +1,10 @@
-use bencher_json::{JsonNewProject, JsonProject, ResourceId};
+use bencher_json::{
+    project::JsonProjectsSort, JsonDirection, JsonNewProject, JsonPagination, JsonProject,
+    ResourceId,
+};
 use bencher_rbac::{organization::Permission, project::Role};
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -13,6 +16,7 @@ use crate::{
     },
     error::api_error,
     model::{
+        audit::{AuditAction, InsertAuditEvent},
         organization::QueryOrganization,
         project::{
             branch::InsertBranch, metric_kind::InsertMetricKind, project_role::InsertProjectRole,
@@ -58,28 +62,31 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonProjectsSort>>,
 ) -> Result<ResponseOk<Vec<JsonProject>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await?;
     let endpoint = Endpoint::new(PROJECT_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         path_params.into_inner(),
+        &pagination_params.into_inner(),
         &auth_user,
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
-    response_ok!(endpoint, json)
+    response_ok!(endpoint, json, total_count)
 }
 
 async fn get_ls_inner(
     context: &ApiContext,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonProjectsSort>,
     auth_user: &AuthUser,
     endpoint: Endpoint,
-) -> Result<Vec<JsonProject>, ApiError> {
+) -> Result<(Vec<JsonProject>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_organization = QueryOrganization::is_allowed_resource_id(
@@ -90,14 +97,45 @@ async fn get_ls_inner(
         Permission::View,
     )?;
 
-    Ok(schema::project::table
-        .filter(schema::project::organization_id.eq(query_organization.id))
-        .order((schema::project::name, schema::project::slug))
+    let filtered = || {
+        schema::project::table
+            .filter(schema::project::organization_id.eq(query_organization.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonProjectsSort::Name, JsonDirection::Asc) => {
+            filtered().order((schema::project::name.asc(), schema::project::slug.asc()))
+        },
+        (JsonProjectsSort::Name, JsonDirection::Desc) => {
+            filtered().order((schema::project::name.desc(), schema::project::slug.desc()))
+        },
+        (JsonProjectsSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::project::id.asc())
+        },
+        (JsonProjectsSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::project::id.desc())
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryProject>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -163,6 +201,15 @@ async fn post_inner(
         .first::<QueryProject>(conn)
         .map_err(api_error!())?;
 
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::ProjectCreate,
+    )?
+    .resource(&query_project.uuid)
+    .insert(conn)?;
+
     // Connect the user to the project as a `Maintainer`
     let insert_proj_role = InsertProjectRole {
         user_id: auth_user.id,
@@ -0,0 +1,420 @@
+use bencher_json::{
+    organization::role::{JsonNewRole, JsonRole, JsonRolesSort, JsonUpdateRole},
+    project::JsonProjectRole,
+    JsonDirection, JsonPagination, ResourceId,
+};
+use bencher_rbac::organization::Permission;
+use chrono::Utc;
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{response_accepted, response_ok, ResponseAccepted, ResponseOk},
+        Endpoint, Method,
+    },
+    error::api_error,
+    model::{
+        audit::{AuditAction, InsertAuditEvent},
+        organization::{
+            custom_role::{InsertCustomRole, QueryCustomRole},
+            QueryOrganization,
+        },
+        user::auth::AuthUser,
+    },
+    schema,
+    util::{
+        cors::{get_cors, CorsResponse},
+        error::into_json,
+    },
+    ApiError,
+};
+
+use super::Resource;
+
+const ROLE_RESOURCE: Resource = Resource::Role;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DirPath {
+    pub organization: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/roles",
+    tags = ["organizations", "roles"]
+}]
+pub async fn dir_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<DirPath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/roles",
+    tags = ["organizations", "roles"]
+}]
+pub async fn get_ls(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonRolesSort>>,
+) -> Result<ResponseOk<Vec<JsonRole>>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(ROLE_RESOURCE, Method::GetLs);
+
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        &pagination_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json, total_count)
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: DirPath,
+    pagination_params: &JsonPagination<JsonRolesSort>,
+    endpoint: Endpoint,
+) -> Result<(Vec<JsonRole>, i64), ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    let filtered = || {
+        schema::custom_role::table
+            .filter(schema::custom_role::organization_id.eq(query_organization.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonRolesSort::Name, JsonDirection::Asc) => {
+            filtered().order(schema::custom_role::name.asc())
+        },
+        (JsonRolesSort::Name, JsonDirection::Desc) => {
+            filtered().order(schema::custom_role::name.desc())
+        },
+        (JsonRolesSort::Creation, JsonDirection::Asc) => filtered().order((
+            schema::custom_role::creation.asc(),
+            schema::custom_role::id.asc(),
+        )),
+        (JsonRolesSort::Creation, JsonDirection::Desc) => filtered().order((
+            schema::custom_role::creation.desc(),
+            schema::custom_role::id.desc(),
+        )),
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryCustomRole>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .filter_map(into_json!(endpoint, conn))
+        .collect();
+
+    Ok((json, total_count))
+}
+
+#[endpoint {
+    method = POST,
+    path =  "/v0/organizations/{organization}/roles",
+    tags = ["organizations", "roles"]
+}]
+pub async fn post(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    body: TypedBody<JsonNewRole>,
+) -> Result<ResponseAccepted<JsonRole>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(ROLE_RESOURCE, Method::Post);
+
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: DirPath,
+    json_role: JsonNewRole,
+    auth_user: &AuthUser,
+) -> Result<JsonRole, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::CreateRole,
+    )?;
+
+    let JsonNewRole { name, permissions } = json_role;
+    // A custom role can not shadow a built-in project role
+    if !matches!(
+        name.as_ref().parse::<JsonProjectRole>(),
+        Ok(JsonProjectRole::Custom(_))
+    ) {
+        return Err(ApiError::BuiltInRole(name.into()));
+    }
+
+    let insert_role = InsertCustomRole {
+        uuid: Uuid::new_v4().to_string(),
+        organization_id: query_organization.id,
+        name: name.into(),
+        permissions: serde_json::to_string(&permissions).map_err(ApiError::Serialize)?,
+        creation: Utc::now().timestamp(),
+    };
+    diesel::insert_into(schema::custom_role::table)
+        .values(&insert_role)
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::organization(
+        conn,
+        auth_user.id,
+        query_organization.id,
+        AuditAction::RoleCreate,
+    )?
+    .resource(&insert_role.uuid)
+    .details(&permissions)?
+    .insert(conn)?;
+
+    schema::custom_role::table
+        .filter(schema::custom_role::uuid.eq(&insert_role.uuid))
+        .first::<QueryCustomRole>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OnePath {
+    pub organization: ResourceId,
+    pub role: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn one_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OnePath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn get_one(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseOk<JsonRole>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(ROLE_RESOURCE, Method::GetOne);
+
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json)
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonRole, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    QueryCustomRole::from_resource_id(conn, query_organization.id, &path_params.role)?
+        .into_json(conn)
+}
+
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn patch(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+    body: TypedBody<JsonUpdateRole>,
+) -> Result<ResponseAccepted<JsonRole>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(ROLE_RESOURCE, Method::Patch);
+
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    json_update: JsonUpdateRole,
+    auth_user: &AuthUser,
+) -> Result<JsonRole, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::EditRole,
+    )?;
+
+    let query_role =
+        QueryCustomRole::from_resource_id(conn, query_organization.id, &path_params.role)?;
+
+    // The name is not editable, as projects assign the custom role by its name
+    let JsonUpdateRole { permissions } = json_update;
+    diesel::update(schema::custom_role::table.filter(schema::custom_role::id.eq(query_role.id)))
+        .set(
+            schema::custom_role::permissions
+                .eq(serde_json::to_string(&permissions).map_err(ApiError::Serialize)?),
+        )
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::organization(
+        conn,
+        auth_user.id,
+        query_organization.id,
+        AuditAction::RoleUpdate,
+    )?
+    .resource(&query_role.uuid)
+    .details(&permissions)?
+    .insert(conn)?;
+
+    schema::custom_role::table
+        .filter(schema::custom_role::id.eq(query_role.id))
+        .first::<QueryCustomRole>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn delete(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseAccepted<JsonRole>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(ROLE_RESOURCE, Method::Delete);
+
+    let json = delete_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonRole, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::DeleteRole,
+    )?;
+
+    let query_role =
+        QueryCustomRole::from_resource_id(conn, query_organization.id, &path_params.role)?;
+
+    // A role that is still assigned would silently grant nothing, so it must be unassigned first
+    let assigned = schema::project_role::table
+        .inner_join(
+            schema::project::table.on(schema::project_role::project_id.eq(schema::project::id)),
+        )
+        .filter(schema::project::organization_id.eq(query_organization.id))
+        .filter(schema::project_role::role.eq(&query_role.name))
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+    if assigned > 0 {
+        return Err(ApiError::CustomRoleInUse(
+            query_organization.id,
+            query_role.name,
+        ));
+    }
+
+    diesel::delete(schema::custom_role::table.filter(schema::custom_role::id.eq(query_role.id)))
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::organization(
+        conn,
+        auth_user.id,
+        query_organization.id,
+        AuditAction::RoleDelete,
+    )?
+    .resource(&query_role.uuid)
+    .insert(conn)?;
+
+    query_role.into_json(conn)
+}
@@ -0,0 +1,427 @@
+use bencher_json::{
+    organization::service_account::{
+        JsonNewServiceAccount, JsonServiceAccount, JsonServiceAccountProject,
+        JsonServiceAccountsSort,
+    },
+    project::JsonProjectRole,
+    JsonDirection, JsonPagination, ResourceId,
+};
+use bencher_rbac::organization::{Permission, Role};
+use chrono::Utc;
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{response_accepted, response_ok, ResponseAccepted, ResponseOk},
+        Endpoint, Method,
+    },
+    error::api_error,
+    model::{
+        audit::{AuditAction, InsertAuditEvent},
+        organization::{
+            custom_role::QueryCustomRole,
+            organization_role::InsertOrganizationRole,
+            service_account::{service_account_email, InsertServiceAccount, QueryServiceAccount},
+            QueryOrganization,
+        },
+        project::{project_role::InsertProjectRole, QueryProject},
+        user::{auth::AuthUser, InsertUser, QueryUser},
+    },
+    schema,
+    util::{
+        cors::{get_cors, CorsResponse},
+        error::into_json,
+        slug::unwrap_slug,
+    },
+    ApiError,
+};
+
+use super::Resource;
+
+const SERVICE_ACCOUNT_RESOURCE: Resource = Resource::ServiceAccount;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DirPath {
+    pub organization: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/service-accounts",
+    tags = ["organizations", "service accounts"]
+}]
+pub async fn dir_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<DirPath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/service-accounts",
+    tags = ["organizations", "service accounts"]
+}]
+pub async fn get_ls(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonServiceAccountsSort>>,
+) -> Result<ResponseOk<Vec<JsonServiceAccount>>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(SERVICE_ACCOUNT_RESOURCE, Method::GetLs);
+
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        &pagination_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json, total_count)
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: DirPath,
+    pagination_params: &JsonPagination<JsonServiceAccountsSort>,
+    endpoint: Endpoint,
+) -> Result<(Vec<JsonServiceAccount>, i64), ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    let filtered = || {
+        schema::service_account::table
+            .inner_join(
+                schema::user::table.on(schema::service_account::user_id.eq(schema::user::id)),
+            )
+            .filter(schema::service_account::organization_id.eq(query_organization.id))
+            .select((
+                schema::service_account::id,
+                schema::service_account::user_id,
+                schema::service_account::organization_id,
+                schema::service_account::creator_id,
+                schema::service_account::creation,
+            ))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonServiceAccountsSort::Name, JsonDirection::Asc) => {
+            filtered().order((schema::user::name.asc(), schema::user::slug.asc()))
+        },
+        (JsonServiceAccountsSort::Name, JsonDirection::Desc) => {
+            filtered().order((schema::user::name.desc(), schema::user::slug.desc()))
+        },
+        (JsonServiceAccountsSort::Creation, JsonDirection::Asc) => filtered().order((
+            schema::service_account::creation.asc(),
+            schema::service_account::id.asc(),
+        )),
+        (JsonServiceAccountsSort::Creation, JsonDirection::Desc) => filtered().order((
+            schema::service_account::creation.desc(),
+            schema::service_account::id.desc(),
+        )),
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryServiceAccount>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .filter_map(into_json!(endpoint, conn))
+        .collect();
+
+    Ok((json, total_count))
+}
+
+#[endpoint {
+    method = POST,
+    path =  "/v0/organizations/{organization}/service-accounts",
+    tags = ["organizations", "service accounts"]
+}]
+pub async fn post(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    body: TypedBody<JsonNewServiceAccount>,
+) -> Result<ResponseAccepted<JsonServiceAccount>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(SERVICE_ACCOUNT_RESOURCE, Method::Post);
+
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: DirPath,
+    json_service_account: JsonNewServiceAccount,
+    auth_user: &AuthUser,
+) -> Result<JsonServiceAccount, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::CreateRole,
+    )?;
+
+    let JsonNewServiceAccount {
+        name,
+        slug,
+        role,
+        projects,
+    } = json_service_account;
+
+    // The projects must belong to the organization,
+    // and the service account can not be given a role on a project that the user can not manage
+    let mut project_roles = Vec::with_capacity(projects.len());
+    for JsonServiceAccountProject { project, role } in projects {
+        let query_project = QueryProject::from_resource_id(conn, &project)?;
+        if query_project.organization_id != query_organization.id {
+            return Err(ApiError::ServiceAccountProject {
+                organization_id: query_organization.id,
+                project_id: query_project.id,
+            });
+        }
+        context.rbac.is_allowed_project(
+            auth_user,
+            bencher_rbac::project::Permission::CreateRole,
+            &query_project,
+        )?;
+        if let JsonProjectRole::Custom(custom_role) = &role {
+            if !QueryCustomRole::exists(conn, query_organization.id, custom_role.as_ref()) {
+                return Err(ApiError::UnknownProjectRole(
+                    query_organization.id,
+                    custom_role.to_string(),
+                ));
+            }
+        }
+        project_roles.push((query_project.id, role));
+    }
+
+    // A service account is a user that belongs to the organization, so that it works with API tokens and roles.
+    // Its placeholder email can not be used to login.
+    let uuid = Uuid::new_v4().to_string();
+    let insert_user = InsertUser {
+        email: service_account_email(&uuid),
+        slug: unwrap_slug!(conn, name.as_ref(), slug, user, QueryUser),
+        uuid,
+        name: name.into(),
+        admin: false,
+        locked: false,
+    };
+    diesel::insert_into(schema::user::table)
+        .values(&insert_user)
+        .execute(conn)
+        .map_err(api_error!())?;
+    let user_id = QueryUser::get_id(conn, &insert_user.uuid)?;
+
+    let insert_org_role = InsertOrganizationRole {
+        user_id,
+        organization_id: query_organization.id,
+        role: role.map_or_else(|| Role::Member.to_string(), |role| role.to_string()),
+    };
+    diesel::insert_into(schema::organization_role::table)
+        .values(&insert_org_role)
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    for (project_id, role) in project_roles {
+        let insert_proj_role = InsertProjectRole {
+            user_id,
+            project_id,
+            role: role.to_string(),
+        };
+        diesel::insert_into(schema::project_role::table)
+            .values(&insert_proj_role)
+            .execute(conn)
+            .map_err(api_error!())?;
+    }
+
+    let insert_service_account = InsertServiceAccount {
+        user_id,
+        organization_id: query_organization.id,
+        creator_id: auth_user.id,
+        creation: Utc::now().timestamp(),
+    };
+    diesel::insert_into(schema::service_account::table)
+        .values(&insert_service_account)
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::organization(
+        conn,
+        auth_user.id,
+        query_organization.id,
+        AuditAction::ServiceAccountCreate,
+    )?
+    .resource(&insert_user.uuid)
+    .insert(conn)?;
+
+    schema::service_account::table
+        .filter(schema::service_account::user_id.eq(user_id))
+        .first::<QueryServiceAccount>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OnePath {
+    pub organization: ResourceId,
+    pub service_account: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/service-accounts/{service_account}",
+    tags = ["organizations", "service accounts"]
+}]
+pub async fn one_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OnePath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/service-accounts/{service_account}",
+    tags = ["organizations", "service accounts"]
+}]
+pub async fn get_one(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseOk<JsonServiceAccount>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(SERVICE_ACCOUNT_RESOURCE, Method::GetOne);
+
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json)
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonServiceAccount, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    query_service_account(conn, query_organization.id, &path_params.service_account)?
+        .into_json(conn)
+}
+
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/organizations/{organization}/service-accounts/{service_account}",
+    tags = ["organizations", "service accounts"]
+}]
+pub async fn delete(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseAccepted<JsonServiceAccount>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(SERVICE_ACCOUNT_RESOURCE, Method::Delete);
+
+    let json = delete_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+/// Lock a service account so that none of its API tokens can be used.
+/// The service account is kept so that its reports are still attributed to it.
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonServiceAccount, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::DeleteRole,
+    )?;
+
+    let query_service_account =
+        query_service_account(conn, query_organization.id, &path_params.service_account)?;
+
+    diesel::update(schema::user::table.filter(schema::user::id.eq(query_service_account.user_id)))
+        .set(schema::user::locked.eq(true))
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::organization(
+        conn,
+        auth_user.id,
+        query_organization.id,
+        AuditAction::ServiceAccountDelete,
+    )?
+    .resource(QueryUser::get_uuid(conn, query_service_account.user_id)?)
+    .insert(conn)?;
+
+    query_service_account.into_json(conn)
+}
+
+fn query_service_account(
+    conn: &mut crate::context::DbConnection,
+    organization_id: i32,
+    service_account: &ResourceId,
+) -> Result<QueryServiceAccount, ApiError> {
+    let query_user = QueryUser::from_resource_id(conn, service_account)?;
+    schema::service_account::table
+        .filter(schema::service_account::organization_id.eq(organization_id))
+        .filter(schema::service_account::user_id.eq(query_user.id))
+        .first::<QueryServiceAccount>(conn)
+        .map_err(api_error!())
+}
@@ -1,6 +1,11 @@
-use bencher_json::{JsonAlert, ResourceId};
+use bencher_json::{
+    project::alert::{JsonAlertStatus, JsonAlertsSort, JsonUpdateAlert},
+    JsonAlert, JsonDirection, JsonPagination, ResourceId,
+};
+use bencher_rbac::project::Permission;
+use chrono::Utc;
 use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use uuid::Uuid;
@@ -8,11 +13,15 @@ use uuid::Uuid;
 use crate::{
     context::ApiContext,
     endpoints::{
-        endpoint::{pub_response_ok, response_ok, ResponseOk},
+        endpoint::{pub_response_ok, response_accepted, response_ok, ResponseAccepted, ResponseOk},
         Endpoint, Method,
     },
     error::api_error,
-    model::project::{threshold::alert::QueryAlert, QueryProject},
+    model::audit::{AuditAction, InsertAuditEvent},
+    model::project::{
+        threshold::alert::{AlertStatus, QueryAlert},
+        QueryProject,
+    },
     model::user::auth::AuthUser,
     schema,
     util::{
@@ -52,23 +61,25 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonAlertsSort>>,
 ) -> Result<ResponseOk<Vec<JsonAlert>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(ALERT_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
+        &pagination_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
@@ -76,36 +87,77 @@ async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonAlertsSort>,
     endpoint: Endpoint,
-) -> Result<Vec<JsonAlert>, ApiError> {
+) -> Result<(Vec<JsonAlert>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
-    Ok(schema::alert::table
-        .left_join(schema::perf::table.on(schema::alert::perf_id.eq(schema::perf::id)))
-        .left_join(
-            schema::benchmark::table.on(schema::perf::benchmark_id.eq(schema::benchmark::id)),
-        )
-        .filter(schema::benchmark::project_id.eq(query_project.id))
-        .left_join(schema::report::table.on(schema::perf::report_id.eq(schema::report::id)))
-        .order((schema::report::start_time, schema::perf::iteration))
-        .select((
-            schema::alert::id,
-            schema::alert::uuid,
-            schema::alert::perf_id,
-            schema::alert::threshold_id,
-            schema::alert::statistic_id,
-            schema::alert::side,
-            schema::alert::boundary,
-            schema::alert::outlier,
-        ))
+    let filtered = || {
+        schema::alert::table
+            .left_join(schema::perf::table.on(schema::alert::perf_id.eq(schema::perf::id)))
+            .left_join(
+                schema::benchmark::table.on(schema::perf::benchmark_id.eq(schema::benchmark::id)),
+            )
+            .filter(schema::benchmark::project_id.eq(query_project.id))
+            .left_join(schema::report::table.on(schema::perf::report_id.eq(schema::report::id)))
+            .select((
+                schema::alert::id,
+                schema::alert::uuid,
+                schema::alert::perf_id,
+                schema::alert::threshold_id,
+                schema::alert::statistic_id,
+                schema::alert::side,
+                schema::alert::boundary,
+                schema::alert::outlier,
+                schema::alert::status,
+                schema::alert::status_user_id,
+                schema::alert::status_time,
+            ))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonAlertsSort::DateTime, JsonDirection::Asc) => filtered().order((
+            schema::report::start_time.asc(),
+            schema::perf::iteration.asc(),
+        )),
+        (JsonAlertsSort::DateTime, JsonDirection::Desc) => filtered().order((
+            schema::report::start_time.desc(),
+            schema::perf::iteration.desc(),
+        )),
+        (JsonAlertsSort::Status, JsonDirection::Asc) => filtered().order((
+            schema::alert::status.asc(),
+            schema::report::start_time.asc(),
+            schema::perf::iteration.asc(),
+        )),
+        (JsonAlertsSort::Status, JsonDirection::Desc) => filtered().order((
+            schema::alert::status.desc(),
+            schema::report::start_time.desc(),
+            schema::perf::iteration.desc(),
+        )),
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryAlert>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -180,8 +232,102 @@ async fn get_one_inner(
             schema::alert::side,
             schema::alert::boundary,
             schema::alert::outlier,
+            schema::alert::status,
+            schema::alert::status_user_id,
+            schema::alert::status_time,
         ))
         .first::<QueryAlert>(conn)
         .map_err(api_error!())?
         .into_json(conn)
 }
+
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/alerts/{alert}",
+    tags = ["projects", "alerts"]
+}]
+pub async fn patch(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+    body: TypedBody<JsonUpdateAlert>,
+) -> Result<ResponseAccepted<JsonAlert>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(ALERT_RESOURCE, Method::Patch);
+
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    json_update: JsonUpdateAlert,
+    auth_user: &AuthUser,
+) -> Result<JsonAlert, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    let (alert_id, current_status) = schema::alert::table
+        .left_join(schema::perf::table.on(schema::alert::perf_id.eq(schema::perf::id)))
+        .left_join(
+            schema::benchmark::table.on(schema::perf::benchmark_id.eq(schema::benchmark::id)),
+        )
+        .filter(schema::benchmark::project_id.eq(query_project.id))
+        .filter(schema::alert::uuid.eq(path_params.alert.to_string()))
+        .select((schema::alert::id, schema::alert::status))
+        .first::<(i32, i32)>(conn)
+        .map_err(api_error!())?;
+
+    if let Some(status) = json_update.status {
+        // Alerts are only ever activated and resolved automatically
+        if !matches!(
+            status,
+            JsonAlertStatus::Acknowledged | JsonAlertStatus::Dismissed
+        ) {
+            return Err(ApiError::AlertStatusUpdate(path_params.alert));
+        }
+        if let AlertStatus::Resolved = AlertStatus::try_from(current_status)? {
+            return Err(ApiError::AlertResolved(path_params.alert));
+        }
+
+        diesel::update(schema::alert::table.filter(schema::alert::id.eq(alert_id)))
+            .set((
+                schema::alert::status.eq(AlertStatus::from(status) as i32),
+                schema::alert::status_user_id.eq(auth_user.id),
+                schema::alert::status_time.eq(Utc::now().timestamp_nanos()),
+            ))
+            .execute(conn)
+            .map_err(api_error!())?;
+
+        InsertAuditEvent::project(
+            conn,
+            auth_user.id,
+            query_project.id,
+            AuditAction::AlertUpdate,
+        )?
+        .resource(path_params.alert)
+        .details(&serde_json::json!({ "status": status }))?
+        .insert(conn)?;
+    }
+
+    schema::alert::table
+        .filter(schema::alert::id.eq(alert_id))
+        .first::<QueryAlert>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
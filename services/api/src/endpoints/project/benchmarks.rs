@@ -1,6 +1,9 @@
-use bencher_json::{JsonBenchmark, ResourceId};
+use bencher_json::{
+    project::benchmark::JsonBenchmarksSort, JsonBenchmark, JsonDirection, JsonPagination,
+    ResourceId,
+};
 use diesel::{expression_methods::BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use uuid::Uuid;
@@ -54,23 +57,25 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonBenchmarksSort>>,
 ) -> Result<ResponseOk<Vec<JsonBenchmark>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(BENCHMARK_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
+        &pagination_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
@@ -78,21 +83,53 @@ async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonBenchmarksSort>,
     endpoint: Endpoint,
-) -> Result<Vec<JsonBenchmark>, ApiError> {
+) -> Result<(Vec<JsonBenchmark>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
-    Ok(schema::benchmark::table
-        .filter(schema::benchmark::project_id.eq(&query_project.id))
-        .order(schema::benchmark::name)
+    let filtered = || {
+        schema::benchmark::table
+            .filter(schema::benchmark::project_id.eq(query_project.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonBenchmarksSort::Name, JsonDirection::Asc) => {
+            filtered().order(schema::benchmark::name.asc())
+        },
+        (JsonBenchmarksSort::Name, JsonDirection::Desc) => {
+            filtered().order(schema::benchmark::name.desc())
+        },
+        (JsonBenchmarksSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::benchmark::id.asc())
+        },
+        (JsonBenchmarksSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::benchmark::id.desc())
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryBenchmark>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[derive(Deserialize, JsonSchema)]
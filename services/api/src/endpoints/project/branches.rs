@@ -1,4 +1,7 @@
-use bencher_json::{project::branch::JsonBranches, JsonBranch, JsonNewBranch, ResourceId};
+use bencher_json::{
+    project::branch::{JsonBranches, JsonBranchesSort},
+    JsonBranch, JsonDirection, JsonNewBranch, JsonPagination, ResourceId,
+};
 use bencher_rbac::project::Permission;
 use diesel::{expression_methods::BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
@@ -12,8 +15,10 @@ use crate::{
         Endpoint, Method,
     },
     error::api_error,
+    model::audit::{AuditAction, InsertAuditEvent},
     model::project::{
         branch::{InsertBranch, QueryBranch},
+        webhook::{dispatch, WebhookEvent},
         QueryProject,
     },
     model::user::auth::AuthUser,
@@ -44,6 +49,7 @@ pub struct DirPath {
 pub async fn dir_options(
     _rqctx: RequestContext<ApiContext>,
     _path_params: Path<DirPath>,
+    _pagination_params: Query<JsonPagination<JsonBranchesSort>>,
     _query_params: Query<JsonBranches>,
 ) -> Result<CorsResponse, HttpError> {
     Ok(get_cors::<ApiContext>())
@@ -57,25 +63,27 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonBranchesSort>>,
     query_params: Query<JsonBranches>,
 ) -> Result<ResponseOk<Vec<JsonBranch>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(BRANCH_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
-        query_params.into_inner(),
+        &pagination_params.into_inner(),
+        &query_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
@@ -83,29 +91,58 @@ async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: DirPath,
-    json_branches: JsonBranches,
+    pagination_params: &JsonPagination<JsonBranchesSort>,
+    json_branches: &JsonBranches,
     endpoint: Endpoint,
-) -> Result<Vec<JsonBranch>, ApiError> {
+) -> Result<(Vec<JsonBranch>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
-    let mut query = schema::branch::table
-        .filter(schema::branch::project_id.eq(&query_project.id))
-        .into_boxed();
+    let filtered = || {
+        let mut query = schema::branch::table
+            .filter(schema::branch::project_id.eq(query_project.id))
+            .into_boxed();
+        if let Some(name) = &json_branches.name {
+            query = query.filter(schema::branch::name.eq(name.clone()));
+        }
+        query
+    };
 
-    if let Some(name) = json_branches.name {
-        query = query.filter(schema::branch::name.eq(name));
-    }
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonBranchesSort::Name, JsonDirection::Asc) => {
+            filtered().order((schema::branch::name.asc(), schema::branch::slug.asc()))
+        },
+        (JsonBranchesSort::Name, JsonDirection::Desc) => {
+            filtered().order((schema::branch::name.desc(), schema::branch::slug.desc()))
+        },
+        (JsonBranchesSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::branch::id.asc())
+        },
+        (JsonBranchesSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::branch::id.desc())
+        },
+    };
 
-    Ok(query
-        .order((schema::branch::name, schema::branch::slug))
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryBranch>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -173,11 +210,32 @@ async fn post_inner(
         insert_branch.start_point(conn, start_point)?;
     }
 
-    schema::branch::table
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        insert_branch.project_id,
+        AuditAction::BranchCreate,
+    )?
+    .resource(&insert_branch.uuid)
+    .insert(conn)?;
+
+    let json_branch = schema::branch::table
         .filter(schema::branch::uuid.eq(&insert_branch.uuid))
         .first::<QueryBranch>(conn)
         .map_err(api_error!())?
-        .into_json(conn)
+        .into_json(conn)?;
+
+    // Don't fail the branch if the webhooks fail to dispatch
+    if let Err(e) = dispatch(
+        conn,
+        insert_branch.project_id,
+        WebhookEvent::Branch,
+        &json_branch,
+    ) {
+        tracing::error!("Failed to dispatch branch webhooks: {e}");
+    }
+
+    Ok(json_branch)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -0,0 +1,359 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use bencher_boundary::stats::percent_change;
+use bencher_json::{
+    project::compare::{JsonCompareReport, JsonCompareResult},
+    JsonCompare, JsonCompareQuery, ResourceId,
+};
+use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, TextExpressionMethods};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    context::{ApiContext, DbConnection},
+    endpoints::{
+        endpoint::{pub_response_ok, response_ok, ResponseOk},
+        Endpoint, Method,
+    },
+    error::api_error,
+    model::project::{
+        benchmark::QueryBenchmark,
+        branch::QueryBranch,
+        metric_kind::QueryMetricKind,
+        report::{to_date_time, QueryReport},
+        testbed::QueryTestbed,
+        version::{hash_prefix, QueryVersion},
+        QueryProject,
+    },
+    model::user::auth::AuthUser,
+    schema,
+    util::cors::{get_cors, CorsResponse},
+    ApiError,
+};
+
+use super::Resource;
+
+const COMPARE_RESOURCE: Resource = Resource::Compare;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DirPath {
+    pub project: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "compare"]
+}]
+pub async fn options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<DirPath>,
+    _query_params: Query<JsonCompareQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "compare"]
+}]
+pub async fn get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    query_params: Query<JsonCompareQuery>,
+) -> Result<ResponseOk<JsonCompare>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await.ok();
+    let endpoint = Endpoint::new(COMPARE_RESOURCE, Method::GetOne);
+
+    let json = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        query_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    if auth_user.is_some() {
+        response_ok!(endpoint, json)
+    } else {
+        pub_response_ok!(endpoint, json)
+    }
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: DirPath,
+    json_compare_query: JsonCompareQuery,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonCompare, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project =
+        QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
+
+    let JsonCompareQuery {
+        base,
+        head,
+        testbed,
+        branch,
+    } = json_compare_query;
+    let testbed_id = testbed
+        .as_ref()
+        .map(|testbed| QueryTestbed::from_resource_id(conn, query_project.id, testbed))
+        .transpose()?
+        .map(|testbed| testbed.id);
+    let branch_id = branch
+        .as_ref()
+        .map(|branch| QueryBranch::from_resource_id(conn, query_project.id, branch))
+        .transpose()?
+        .map(|branch| branch.id);
+
+    let base = find_report(conn, query_project.id, testbed_id, branch_id, &base)?;
+    let head = find_report(conn, query_project.id, testbed_id, branch_id, &head)?;
+
+    let base_metrics = report_metrics(conn, base.id)?;
+    let head_metrics = report_metrics(conn, head.id)?;
+    let mut head_alerts = report_alerts(conn, head.id)?;
+
+    let keys = base_metrics
+        .keys()
+        .chain(head_metrics.keys())
+        .copied()
+        .collect::<HashSet<_>>();
+    let benchmarks = schema::benchmark::table
+        .filter(schema::benchmark::id.eq_any(keys.iter().map(|(benchmark_id, _)| *benchmark_id)))
+        .load::<QueryBenchmark>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .map(|query_benchmark| Ok((query_benchmark.id, query_benchmark.into_json(conn)?)))
+        .collect::<Result<HashMap<_, _>, ApiError>>()?;
+    let metric_kinds = schema::metric_kind::table
+        .filter(
+            schema::metric_kind::id.eq_any(keys.iter().map(|(_, metric_kind_id)| *metric_kind_id)),
+        )
+        .load::<QueryMetricKind>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .map(|query_metric_kind| Ok((query_metric_kind.id, query_metric_kind.into_json(conn)?)))
+        .collect::<Result<HashMap<_, _>, ApiError>>()?;
+
+    let mut results = Vec::with_capacity(keys.len());
+    for key in keys {
+        let (benchmark_id, metric_kind_id) = key;
+        let (Some(benchmark), Some(metric_kind)) =
+            (benchmarks.get(&benchmark_id), metric_kinds.get(&metric_kind_id)) else {
+            continue;
+        };
+        let base = base_metrics.get(&key).copied();
+        let head = head_metrics.get(&key).copied();
+        let (delta, percent) = change(base, head);
+        results.push(JsonCompareResult {
+            benchmark: benchmark.clone(),
+            metric_kind: metric_kind.clone(),
+            base: base.map(Into::into),
+            head: head.map(Into::into),
+            delta: delta.map(Into::into),
+            percent: percent.map(Into::into),
+            alerts: head_alerts.remove(&key).unwrap_or_default(),
+        });
+    }
+    // Order the results by benchmark and then metric kind name
+    results.sort_by(|a, b| {
+        (a.benchmark.name.as_ref(), a.metric_kind.name.as_ref())
+            .cmp(&(b.benchmark.name.as_ref(), b.metric_kind.name.as_ref()))
+    });
+
+    Ok(JsonCompare {
+        project: query_project.into_json(conn)?,
+        base: compare_report(conn, base)?,
+        head: compare_report(conn, head)?,
+        results,
+    })
+}
+
+/// Find the report for either a report UUID or the most recent report for a git hash
+fn find_report(
+    conn: &mut DbConnection,
+    project_id: i32,
+    testbed_id: Option<i32>,
+    branch_id: Option<i32>,
+    commit: &str,
+) -> Result<QueryReport, ApiError> {
+    // Hyphenated report UUIDs are never valid git hashes
+    let hash = hash_prefix(commit).ok();
+    let report = if hash.is_some() {
+        None
+    } else {
+        Some(Uuid::from_str(commit).map_err(|_e| ApiError::CompareCommit(commit.into()))?)
+    };
+    let filtered = || {
+        let mut query = schema::report::table
+            .inner_join(
+                schema::testbed::table.on(schema::report::testbed_id.eq(schema::testbed::id)),
+            )
+            .inner_join(
+                schema::version::table.on(schema::report::version_id.eq(schema::version::id)),
+            )
+            .filter(schema::testbed::project_id.eq(project_id))
+            .into_boxed();
+        if let Some(hash) = &hash {
+            query = query.filter(schema::version::hash.like(hash.clone()));
+        }
+        if let Some(report) = report {
+            query = query.filter(schema::report::uuid.eq(report.to_string()));
+        }
+        if let Some(testbed_id) = testbed_id {
+            query = query.filter(schema::report::testbed_id.eq(testbed_id));
+        }
+        if let Some(branch_id) = branch_id {
+            query = query.filter(
+                schema::report::version_id.eq_any(
+                    schema::branch_version::table
+                        .filter(schema::branch_version::branch_id.eq(branch_id))
+                        .select(schema::branch_version::version_id),
+                ),
+            );
+        }
+        query
+    };
+
+    // A short hash prefix may match more than one version
+    if hash.is_some() {
+        let versions = filtered()
+            .select(schema::version::id)
+            .distinct()
+            .limit(2)
+            .load::<i32>(conn)
+            .map_err(api_error!())?;
+        if versions.len() > 1 {
+            return Err(ApiError::AmbiguousHash(commit.into()));
+        }
+    }
+
+    filtered()
+        .select((
+            schema::report::id,
+            schema::report::uuid,
+            schema::report::user_id,
+            schema::report::version_id,
+            schema::report::testbed_id,
+            schema::report::adapter,
+            schema::report::start_time,
+            schema::report::end_time,
+        ))
+        .order((
+            schema::report::start_time.desc(),
+            schema::report::end_time.desc(),
+        ))
+        .first::<QueryReport>(conn)
+        .map_err(api_error!())
+}
+
+/// The mean metric value across all iterations for each benchmark and metric kind in a report
+#[allow(clippy::cast_precision_loss, clippy::float_arithmetic)]
+fn report_metrics(
+    conn: &mut DbConnection,
+    report_id: i32,
+) -> Result<HashMap<(i32, i32), f64>, ApiError> {
+    let mut metrics: HashMap<(i32, i32), Vec<f64>> = HashMap::new();
+    for (benchmark_id, metric_kind_id, value) in schema::perf::table
+        .inner_join(schema::metric::table.on(schema::metric::perf_id.eq(schema::perf::id)))
+        .filter(schema::perf::report_id.eq(report_id))
+        .select((
+            schema::perf::benchmark_id,
+            schema::metric::metric_kind_id,
+            schema::metric::value,
+        ))
+        .load::<(i32, i32, f64)>(conn)
+        .map_err(api_error!())?
+    {
+        metrics
+            .entry((benchmark_id, metric_kind_id))
+            .or_default()
+            .push(value);
+    }
+
+    Ok(metrics
+        .into_iter()
+        .map(|(key, values)| (key, values.iter().sum::<f64>() / values.len() as f64))
+        .collect())
+}
+
+/// The alerts for each benchmark and metric kind in a report
+fn report_alerts(
+    conn: &mut DbConnection,
+    report_id: i32,
+) -> Result<HashMap<(i32, i32), Vec<Uuid>>, ApiError> {
+    let mut alerts: HashMap<(i32, i32), Vec<Uuid>> = HashMap::new();
+    for (benchmark_id, metric_kind_id, uuid) in schema::alert::table
+        .inner_join(schema::perf::table.on(schema::alert::perf_id.eq(schema::perf::id)))
+        .inner_join(
+            schema::threshold::table.on(schema::alert::threshold_id.eq(schema::threshold::id)),
+        )
+        .filter(schema::perf::report_id.eq(report_id))
+        .select((
+            schema::perf::benchmark_id,
+            schema::threshold::metric_kind_id,
+            schema::alert::uuid,
+        ))
+        .load::<(i32, i32, String)>(conn)
+        .map_err(api_error!())?
+    {
+        alerts
+            .entry((benchmark_id, metric_kind_id))
+            .or_default()
+            .push(Uuid::from_str(&uuid).map_err(api_error!())?);
+    }
+    Ok(alerts)
+}
+
+#[allow(clippy::float_arithmetic)]
+fn change(base: Option<f64>, head: Option<f64>) -> (Option<f64>, Option<f64>) {
+    let (Some(base), Some(head)) = (base, head) else {
+        return (None, None);
+    };
+    (Some(head - base), percent_change(base, head))
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn compare_report(
+    conn: &mut DbConnection,
+    query_report: QueryReport,
+) -> Result<JsonCompareReport, ApiError> {
+    let QueryReport {
+        uuid,
+        version_id,
+        testbed_id,
+        start_time,
+        end_time,
+        ..
+    } = query_report;
+    let query_version = schema::version::table
+        .filter(schema::version::id.eq(version_id))
+        .first::<QueryVersion>(conn)
+        .map_err(api_error!())?;
+    Ok(JsonCompareReport {
+        report: Uuid::from_str(&uuid).map_err(api_error!())?,
+        testbed: schema::testbed::table
+            .filter(schema::testbed::id.eq(testbed_id))
+            .first::<QueryTestbed>(conn)
+            .map_err(api_error!())?
+            .into_json(conn)?,
+        version_number: query_version.number as u32,
+        version_hash: query_version
+            .hash
+            .as_deref()
+            .map(FromStr::from_str)
+            .transpose()?,
+        start_time: to_date_time(start_time)?,
+        end_time: to_date_time(end_time)?,
+    })
+}
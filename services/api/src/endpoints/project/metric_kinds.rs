@@ -1,7 +1,10 @@
-use bencher_json::{JsonMetricKind, JsonNewMetricKind, ResourceId};
+use bencher_json::{
+    project::metric_kind::JsonMetricKindsSort, JsonDirection, JsonMetricKind, JsonNewMetricKind,
+    JsonPagination, ResourceId,
+};
 use bencher_rbac::project::Permission;
 use diesel::{expression_methods::BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -56,23 +59,25 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonMetricKindsSort>>,
 ) -> Result<ResponseOk<Vec<JsonMetricKind>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(METRIC_KIND_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
+        &pagination_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
@@ -80,21 +85,55 @@ async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonMetricKindsSort>,
     endpoint: Endpoint,
-) -> Result<Vec<JsonMetricKind>, ApiError> {
+) -> Result<(Vec<JsonMetricKind>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
-    Ok(schema::metric_kind::table
-        .filter(schema::metric_kind::project_id.eq(query_project.id))
-        .order((schema::metric_kind::name, schema::metric_kind::slug))
+    let filtered = || {
+        schema::metric_kind::table
+            .filter(schema::metric_kind::project_id.eq(query_project.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonMetricKindsSort::Name, JsonDirection::Asc) => filtered().order((
+            schema::metric_kind::name.asc(),
+            schema::metric_kind::slug.asc(),
+        )),
+        (JsonMetricKindsSort::Name, JsonDirection::Desc) => filtered().order((
+            schema::metric_kind::name.desc(),
+            schema::metric_kind::slug.desc(),
+        )),
+        (JsonMetricKindsSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::metric_kind::id.asc())
+        },
+        (JsonMetricKindsSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::metric_kind::id.desc())
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryMetricKind>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
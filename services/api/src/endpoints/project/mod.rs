@@ -3,61 +3,86 @@ use derive_more::Display;
 use crate::WordStr;
 
 pub mod alerts;
+pub mod audit;
 pub mod benchmarks;
 pub mod branches;
+pub mod compare;
 pub mod metric_kinds;
+pub mod notifications;
 pub mod perf;
 pub mod projects;
 pub mod reports;
 pub mod results;
 pub mod testbeds;
 pub mod thresholds;
+pub mod webhooks;
 
 #[derive(Debug, Display, Clone, Copy)]
 pub enum Resource {
     Alert,
+    AuditEvent,
     Benchmark,
     Branch,
+    Compare,
     MetricKind,
+    Notification,
     Perf,
     PerfImg,
+    PerfChangepoints,
     Project,
     Report,
+    ReportOutput,
     Result,
     Testbed,
     Threshold,
+    Webhook,
+    WebhookDelivery,
 }
 
 impl WordStr for Resource {
     fn singular(&self) -> &str {
         match self {
             Self::Alert => "alert",
+            Self::AuditEvent => "audit event",
             Self::Benchmark => "benchmark",
             Self::Branch => "branch",
+            Self::Compare => "commit comparison",
             Self::MetricKind => "metric kind",
+            Self::Notification => "notification",
             Self::Perf => "benchmark perf",
             Self::PerfImg => "benchmark perf image",
+            Self::PerfChangepoints => "benchmark perf changepoints",
             Self::Project => "project",
             Self::Report => "report",
+            Self::ReportOutput => "report output",
             Self::Result => "result",
             Self::Testbed => "testbed",
             Self::Threshold => "threshold",
+            Self::Webhook => "webhook",
+            Self::WebhookDelivery => "webhook delivery",
         }
     }
 
     fn plural(&self) -> &str {
         match self {
             Self::Alert => "alerts",
+            Self::AuditEvent => "audit events",
             Self::Benchmark => "benchmarks",
             Self::Branch => "branches",
+            Self::Compare => "commit comparisons",
             Self::MetricKind => "metric kinds",
+            Self::Notification => "notifications",
             Self::Perf => "benchmark perfs",
             Self::PerfImg => "benchmark perfs image",
+            Self::PerfChangepoints => "benchmark perfs changepoints",
             Self::Project => "projects",
             Self::Report => "reports",
+            Self::ReportOutput => "report outputs",
             Self::Result => "results",
             Self::Testbed => "testbeds",
             Self::Threshold => "thresholds",
+            Self::Webhook => "webhooks",
+            Self::WebhookDelivery => "webhook deliveries",
         }
     }
 }
@@ -0,0 +1,325 @@
+use bencher_json::{
+    project::notification::JsonNotificationsSort, JsonDirection, JsonNewNotification,
+    JsonNotification, JsonPagination, ResourceId,
+};
+use bencher_rbac::project::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{response_accepted, response_ok, ResponseAccepted, ResponseOk},
+        Endpoint, Method,
+    },
+    error::api_error,
+    model::audit::{AuditAction, InsertAuditEvent},
+    model::project::{
+        notification::{InsertNotification, QueryNotification},
+        QueryProject,
+    },
+    model::user::auth::AuthUser,
+    schema,
+    util::{
+        cors::{get_cors, CorsResponse},
+        error::into_json,
+    },
+    ApiError,
+};
+
+use super::Resource;
+
+const NOTIFICATION_RESOURCE: Resource = Resource::Notification;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DirPath {
+    pub project: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/notifications",
+    tags = ["projects", "notifications"]
+}]
+pub async fn dir_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<DirPath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/notifications",
+    tags = ["projects", "notifications"]
+}]
+pub async fn get_ls(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonNotificationsSort>>,
+) -> Result<ResponseOk<Vec<JsonNotification>>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(NOTIFICATION_RESOURCE, Method::GetLs);
+
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        &pagination_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json, total_count)
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: DirPath,
+    pagination_params: &JsonPagination<JsonNotificationsSort>,
+    endpoint: Endpoint,
+) -> Result<(Vec<JsonNotification>, i64), ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let filtered = || {
+        schema::notification::table
+            .filter(schema::notification::project_id.eq(query_project.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonNotificationsSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::notification::id.asc())
+        },
+        (JsonNotificationsSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::notification::id.desc())
+        },
+        (JsonNotificationsSort::Email, JsonDirection::Asc) => filtered().order((
+            schema::notification::email.asc(),
+            schema::notification::id.asc(),
+        )),
+        (JsonNotificationsSort::Email, JsonDirection::Desc) => filtered().order((
+            schema::notification::email.desc(),
+            schema::notification::id.desc(),
+        )),
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryNotification>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .filter_map(into_json!(endpoint, conn))
+        .collect();
+
+    Ok((json, total_count))
+}
+
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/notifications",
+    tags = ["projects", "notifications"]
+}]
+pub async fn post(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    body: TypedBody<JsonNewNotification>,
+) -> Result<ResponseAccepted<JsonNotification>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(NOTIFICATION_RESOURCE, Method::Post);
+
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: DirPath,
+    json_notification: JsonNewNotification,
+    auth_user: &AuthUser,
+) -> Result<JsonNotification, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    let insert_notification = InsertNotification::from_json(query_project.id, json_notification);
+
+    diesel::insert_into(schema::notification::table)
+        .values(&insert_notification)
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::NotificationCreate,
+    )?
+    .resource(&insert_notification.uuid)
+    .details(&serde_json::json!({ "email": insert_notification.email }))?
+    .insert(conn)?;
+
+    schema::notification::table
+        .filter(schema::notification::uuid.eq(&insert_notification.uuid))
+        .first::<QueryNotification>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OnePath {
+    pub project: ResourceId,
+    pub notification: Uuid,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/notifications/{notification}",
+    tags = ["projects", "notifications"]
+}]
+pub async fn one_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OnePath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/notifications/{notification}",
+    tags = ["projects", "notifications"]
+}]
+pub async fn get_one(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseOk<JsonNotification>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(NOTIFICATION_RESOURCE, Method::GetOne);
+
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json)
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonNotification, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    schema::notification::table
+        .filter(schema::notification::project_id.eq(query_project.id))
+        .filter(schema::notification::uuid.eq(path_params.notification.to_string()))
+        .first::<QueryNotification>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/notifications/{notification}",
+    tags = ["projects", "notifications"]
+}]
+pub async fn delete(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseAccepted<JsonNotification>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(NOTIFICATION_RESOURCE, Method::Delete);
+
+    let json = delete_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonNotification, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_notification = schema::notification::table
+        .filter(schema::notification::project_id.eq(query_project.id))
+        .filter(schema::notification::uuid.eq(path_params.notification.to_string()))
+        .first::<QueryNotification>(conn)
+        .map_err(api_error!())?;
+    let notification_id = query_notification.id;
+    let json_notification = query_notification.into_json(conn)?;
+
+    diesel::delete(
+        schema::notification::table.filter(schema::notification::id.eq(notification_id)),
+    )
+    .execute(conn)
+    .map_err(api_error!())?;
+
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::NotificationDelete,
+    )?
+    .resource(path_params.notification)
+    .insert(conn)?;
+
+    Ok(json_notification)
+}
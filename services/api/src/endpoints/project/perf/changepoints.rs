@@ -0,0 +1,135 @@
+use bencher_json::{
+    project::perf::{
+        JsonChangepoint, JsonPerfChangepointMetrics, JsonPerfMetrics, JsonPerfQueryParams,
+    },
+    JsonPerf, JsonPerfChangepoints, JsonPerfQuery,
+};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{pub_response_ok, response_ok, ResponseOk},
+        Endpoint, Method,
+    },
+    model::user::auth::AuthUser,
+    util::{
+        changepoint::{changepoints, Changepoint},
+        cors::{get_cors, CorsResponse},
+    },
+    ApiError,
+};
+
+use super::{DirPath, Resource};
+
+const PERF_CHANGEPOINTS_RESOURCE: Resource = Resource::PerfChangepoints;
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/perf/changepoints",
+    tags = ["projects", "perf"]
+}]
+pub async fn options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<DirPath>,
+    _query_params: Query<JsonPerfQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/perf/changepoints",
+    tags = ["projects", "perf"]
+}]
+pub async fn get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    query_params: Query<JsonPerfQueryParams>,
+) -> Result<ResponseOk<JsonPerfChangepoints>, HttpError> {
+    // Second round of marshaling
+    let json_perf_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(ApiError::from)?;
+
+    let auth_user = AuthUser::new(&rqctx).await.ok();
+    let endpoint = Endpoint::new(PERF_CHANGEPOINTS_RESOURCE, Method::GetLs);
+
+    let json = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        json_perf_query,
+        auth_user.as_ref(),
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    if auth_user.is_some() {
+        response_ok!(endpoint, json)
+    } else {
+        pub_response_ok!(endpoint, json)
+    }
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: DirPath,
+    json_perf_query: JsonPerfQuery,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonPerfChangepoints, ApiError> {
+    let JsonPerf {
+        project,
+        metric_kind,
+        start_time,
+        end_time,
+        results,
+    } = super::get_inner(context, path_params, json_perf_query, auth_user).await?;
+
+    Ok(JsonPerfChangepoints {
+        project,
+        metric_kind,
+        start_time,
+        end_time,
+        results: results.into_iter().map(changepoint_metrics).collect(),
+    })
+}
+
+fn changepoint_metrics(perf_metrics: JsonPerfMetrics) -> JsonPerfChangepointMetrics {
+    let JsonPerfMetrics {
+        branch,
+        testbed,
+        benchmark,
+        metrics,
+    } = perf_metrics;
+
+    let data = metrics
+        .iter()
+        .map(|perf_metric| perf_metric.metric.value.into())
+        .collect::<Vec<f64>>();
+    let changepoints = changepoints(&data)
+        .into_iter()
+        .filter_map(
+            |Changepoint {
+                 index,
+                 before_mean,
+                 after_mean,
+             }| {
+                Some(JsonChangepoint {
+                    before: metrics.get(index.checked_sub(1)?)?.clone(),
+                    after: metrics.get(index)?.clone(),
+                    before_mean: before_mean.into(),
+                    after_mean: after_mean.into(),
+                })
+            },
+        )
+        .collect();
+
+    JsonPerfChangepointMetrics {
+        branch,
+        testbed,
+        benchmark,
+        changepoints,
+    }
+}
@@ -28,6 +28,7 @@ use crate::{
     ApiError,
 };
 
+pub mod changepoints;
 pub mod img;
 
 use super::Resource;
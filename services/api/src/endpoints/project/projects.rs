@@ -1,17 +1,22 @@
-use bencher_json::{project::JsonProjects, JsonProject, ResourceId};
+use bencher_json::{
+    project::{JsonProjects, JsonProjectsSort, JsonUpdateProject},
+    JsonDirection, JsonPagination, JsonProject, ResourceId,
+};
+use bencher_rbac::project::Permission;
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
     context::ApiContext,
     endpoints::{
-        endpoint::{pub_response_ok, response_ok, ResponseOk},
+        endpoint::{pub_response_ok, response_accepted, response_ok, ResponseAccepted, ResponseOk},
         Endpoint, Method,
     },
     error::api_error,
     model::{
+        audit::{AuditAction, InsertAuditEvent},
         project::{visibility::Visibility, QueryProject},
         user::auth::AuthUser,
     },
@@ -35,6 +40,7 @@ const PROJECT_RESOURCE: Resource = Resource::Project;
 }]
 pub async fn dir_options(
     _rqctx: RequestContext<ApiContext>,
+    _pagination_params: Query<JsonPagination<JsonProjectsSort>>,
     _query_params: Query<JsonProjects>,
 ) -> Result<CorsResponse, HttpError> {
     Ok(get_cors::<ApiContext>())
@@ -47,14 +53,16 @@ pub async fn dir_options(
 }]
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
+    pagination_params: Query<JsonPagination<JsonProjectsSort>>,
     query_params: Query<JsonProjects>,
 ) -> Result<ResponseOk<Vec<JsonProject>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(PROJECT_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
+        &pagination_params.into_inner(),
         query_params.into_inner(),
         endpoint,
     )
@@ -62,42 +70,76 @@ pub async fn get_ls(
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
 async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
+    pagination_params: &JsonPagination<JsonProjectsSort>,
     json_projects: JsonProjects,
     endpoint: Endpoint,
-) -> Result<Vec<JsonProject>, ApiError> {
+) -> Result<(Vec<JsonProject>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
-    let mut query = schema::project::table.into_boxed();
-
     // All users should just see the public projects if the query is for public projects
-    if let Some(true) = json_projects.public {
-        query = query.filter(schema::project::visibility.eq(Visibility::Public as i32));
+    let public = matches!(json_projects.public, Some(true));
+    let projects = if public {
+        None
     } else if let Some(auth_user) = auth_user {
-        if !auth_user.is_admin(&context.rbac) {
-            let projects =
-                auth_user.projects(&context.rbac, bencher_rbac::project::Permission::View);
-            query = query.filter(schema::project::id.eq_any(projects));
-        }
+        (!auth_user.is_admin(&context.rbac))
+            .then(|| auth_user.projects(&context.rbac, bencher_rbac::project::Permission::View))
     } else {
         return Err(ApiError::PrivateProjects);
-    }
+    };
+
+    let filtered = || {
+        let mut query = schema::project::table.into_boxed();
+        if public {
+            query = query.filter(schema::project::visibility.eq(Visibility::Public as i32));
+        }
+        if let Some(projects) = &projects {
+            query = query.filter(schema::project::id.eq_any(projects.clone()));
+        }
+        query
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
 
-    Ok(query
-        .order((schema::project::name, schema::project::slug))
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonProjectsSort::Name, JsonDirection::Asc) => {
+            filtered().order((schema::project::name.asc(), schema::project::slug.asc()))
+        },
+        (JsonProjectsSort::Name, JsonDirection::Desc) => {
+            filtered().order((schema::project::name.desc(), schema::project::slug.desc()))
+        },
+        (JsonProjectsSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::project::id.asc())
+        },
+        (JsonProjectsSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::project::id.desc())
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryProject>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -155,3 +197,71 @@ async fn get_one_inner(
     QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?
         .into_json(conn)
 }
+
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}",
+    tags = ["projects"]
+}]
+pub async fn patch(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+    body: TypedBody<JsonUpdateProject>,
+) -> Result<ResponseAccepted<JsonProject>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(PROJECT_RESOURCE, Method::Patch);
+
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    json_update: JsonUpdateProject,
+    auth_user: &AuthUser,
+) -> Result<JsonProject, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    let JsonUpdateProject { auto_metric_kinds } = json_update;
+    if let Some(auto_metric_kinds) = auto_metric_kinds {
+        diesel::update(schema::project::table.filter(schema::project::id.eq(query_project.id)))
+            .set(schema::project::auto_metric_kinds.eq(auto_metric_kinds))
+            .execute(conn)
+            .map_err(api_error!())?;
+    }
+
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::ProjectUpdate,
+    )?
+    .resource(&query_project.uuid)
+    .details(&serde_json::json!({
+        "auto_metric_kinds": auto_metric_kinds
+    }))?
+    .insert(conn)?;
+
+    schema::project::table
+        .filter(schema::project::id.eq(query_project.id))
+        .first::<QueryProject>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
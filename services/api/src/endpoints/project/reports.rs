@@ -1,26 +1,40 @@
-use bencher_json::{JsonNewReport, JsonReport, ResourceId};
+use bencher_json::{
+    project::report::{JsonAdapter, JsonReportOutput, JsonReports, JsonReportsSort},
+    JsonDirection, JsonNewReport, JsonPagination, JsonReport, ResourceId,
+};
 use bencher_rbac::project::Permission;
 use diesel::{
     expression_methods::BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl,
+    TextExpressionMethods,
 };
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use tracing::warn;
 use uuid::Uuid;
 
 use crate::{
-    context::ApiContext,
+    context::{ApiContext, DbConnection},
     endpoints::{
         endpoint::{pub_response_ok, response_accepted, response_ok, ResponseAccepted, ResponseOk},
         Endpoint, Method,
     },
     error::api_error,
     model::project::{
-        report::{results::ReportResults, InsertReport, QueryReport},
-        version::InsertVersion,
+        branch::QueryBranch,
+        notification::notify_alerts,
+        report::{
+            fingerprint::InsertReportFingerprint,
+            output::{InsertReportOutput, QueryReportOutput},
+            results::ReportResults,
+            InsertReport, QueryReport,
+        },
+        testbed::QueryTestbed,
+        version::{hash_prefix, InsertVersion},
+        webhook::{dispatch, WebhookEvent},
         QueryProject,
     },
-    model::user::auth::AuthUser,
+    model::user::{auth::AuthUser, QueryUser},
     schema,
     util::{
         cors::{get_cors, CorsResponse},
@@ -33,6 +47,7 @@ use crate::{
 use super::Resource;
 
 const REPORT_RESOURCE: Resource = Resource::Report;
+const REPORT_OUTPUT_RESOURCE: Resource = Resource::ReportOutput;
 
 #[derive(Deserialize, JsonSchema)]
 pub struct DirPath {
@@ -48,6 +63,8 @@ pub struct DirPath {
 pub async fn dir_options(
     _rqctx: RequestContext<ApiContext>,
     _path_params: Path<DirPath>,
+    _pagination_params: Query<JsonPagination<JsonReportsSort>>,
+    _query_params: Query<JsonReports>,
 ) -> Result<CorsResponse, HttpError> {
     Ok(get_cors::<ApiContext>())
 }
@@ -60,23 +77,27 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonReportsSort>>,
+    query_params: Query<JsonReports>,
 ) -> Result<ResponseOk<Vec<JsonReport>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(REPORT_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
+        &pagination_params.into_inner(),
+        &query_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
@@ -84,35 +105,120 @@ async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonReportsSort>,
+    json_reports: &JsonReports,
     endpoint: Endpoint,
-) -> Result<Vec<JsonReport>, ApiError> {
+) -> Result<(Vec<JsonReport>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
-    Ok(schema::report::table
-        .left_join(schema::testbed::table.on(schema::report::testbed_id.eq(schema::testbed::id)))
-        .filter(schema::testbed::project_id.eq(query_project.id))
-        .select((
-            schema::report::id,
-            schema::report::uuid,
-            schema::report::user_id,
-            schema::report::version_id,
-            schema::report::testbed_id,
-            schema::report::adapter,
-            schema::report::start_time,
-            schema::report::end_time,
-        ))
-        .order((
+    let hash = json_reports.hash.as_deref().map(hash_prefix).transpose()?;
+    let branch_id = json_reports
+        .branch
+        .as_ref()
+        .map(|branch| QueryBranch::from_resource_id(conn, query_project.id, branch))
+        .transpose()?
+        .map(|branch| branch.id);
+    let testbed_id = json_reports
+        .testbed
+        .as_ref()
+        .map(|testbed| QueryTestbed::from_resource_id(conn, query_project.id, testbed))
+        .transpose()?
+        .map(|testbed| testbed.id);
+    let user_id = json_reports
+        .user
+        .as_ref()
+        .map(|user| QueryUser::from_resource_id(conn, user))
+        .transpose()?
+        .map(|user| user.id);
+
+    let filtered = || {
+        let mut query = schema::report::table
+            .left_join(
+                schema::testbed::table.on(schema::report::testbed_id.eq(schema::testbed::id)),
+            )
+            .inner_join(
+                schema::version::table.on(schema::report::version_id.eq(schema::version::id)),
+            )
+            .filter(schema::testbed::project_id.eq(query_project.id))
+            .select((
+                schema::report::id,
+                schema::report::uuid,
+                schema::report::user_id,
+                schema::report::version_id,
+                schema::report::testbed_id,
+                schema::report::adapter,
+                schema::report::start_time,
+                schema::report::end_time,
+            ))
+            .into_boxed();
+        if let Some(hash) = &hash {
+            query = query.filter(schema::version::hash.like(hash.clone()));
+        }
+        if let Some(branch_id) = branch_id {
+            query = query.filter(
+                schema::report::version_id.eq_any(
+                    schema::branch_version::table
+                        .filter(schema::branch_version::branch_id.eq(branch_id))
+                        .select(schema::branch_version::version_id),
+                ),
+            );
+        }
+        if let Some(testbed_id) = testbed_id {
+            query = query.filter(schema::report::testbed_id.eq(testbed_id));
+        }
+        if let Some(user_id) = user_id {
+            query = query.filter(schema::report::user_id.eq(user_id));
+        }
+        if let Some(start_time) = json_reports.start_time {
+            query = query.filter(schema::report::start_time.ge(start_time.timestamp_nanos()));
+        }
+        if let Some(end_time) = json_reports.end_time {
+            query = query.filter(schema::report::end_time.le(end_time.timestamp_nanos()));
+        }
+        query
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    // Most recent reports first by default
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Desc),
+    ) {
+        (JsonReportsSort::DateTime, JsonDirection::Asc) => filtered().order((
+            schema::report::start_time.asc(),
+            schema::report::end_time.asc(),
+        )),
+        (JsonReportsSort::DateTime, JsonDirection::Desc) => filtered().order((
             schema::report::start_time.desc(),
             schema::report::end_time.desc(),
-        ))
+        )),
+        (JsonReportsSort::Version, JsonDirection::Asc) => filtered().order((
+            schema::version::number.asc(),
+            schema::report::start_time.asc(),
+        )),
+        (JsonReportsSort::Version, JsonDirection::Desc) => filtered().order((
+            schema::version::number.desc(),
+            schema::report::start_time.desc(),
+        )),
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryReport>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -165,12 +271,12 @@ async fn post_inner(
     )?;
 
     // Verify that the user is allowed
-    QueryProject::is_allowed_id(
+    let query_project = QueryProject::is_allowed_id(
         conn,
         &context.rbac,
         project_id,
         auth_user,
-        Permission::Create,
+        Permission::CreateReport,
     )?;
 
     // Check to see if the project is public or private
@@ -203,8 +309,13 @@ async fn post_inner(
         InsertVersion::increment(conn, branch_id, None)?
     };
 
-    let json_settings = json_report.settings.take().unwrap_or_default();
-    let adapter = json_settings.adapter.unwrap_or_default();
+    let mut json_settings = json_report.settings.take().unwrap_or_default();
+    // An adapter config always uses the custom adapter
+    let adapter = if json_settings.adapter_config.is_some() {
+        JsonAdapter::Custom
+    } else {
+        json_settings.adapter.unwrap_or_default()
+    };
 
     // Create a new report and add it to the database
     let insert_report =
@@ -220,11 +331,32 @@ async fn post_inner(
         .first::<QueryReport>(conn)
         .map_err(api_error!())?;
 
+    // Keep the raw output before processing the results, so that a failed parse can be debugged
+    if let Some(output) = json_report.output.take() {
+        InsertReportOutput::insert(conn, query_report.id, output)?;
+    }
+
+    if let Some(fingerprint) = json_report.fingerprint.take() {
+        if InsertReportFingerprint::insert(conn, testbed_id, query_report.id, &fingerprint)? {
+            warn!(
+                "Testbed {testbed_id} fingerprint changed for report {}",
+                query_report.uuid
+            );
+        }
+    }
+
     #[cfg(feature = "plus")]
     let mut usage = 0;
 
     // Process and record the report results
-    let mut report_results = ReportResults::new(project_id, branch_id, testbed_id, query_report.id);
+    let mut report_results = ReportResults::new(
+        project_id,
+        branch_id,
+        testbed_id,
+        query_report.id,
+        query_project.auto_metric_kinds,
+        json_settings.metric_kinds.take().unwrap_or_default(),
+    );
     let processed_report = report_results.process(
         conn,
         json_report
@@ -248,7 +380,26 @@ async fn post_inner(
     // until after the metrics usage has been checked
     processed_report?;
 
-    query_report.into_json(conn)
+    // Don't fail the report if the alert notifications fail to send
+    if let Err(e) = notify_alerts(context, conn, &report_results, json_report.hash) {
+        tracing::error!("Failed to send alert notifications: {e}");
+    }
+
+    let mut json_report = query_report.into_json(conn)?;
+    if !report_results.skipped_metric_kinds.is_empty() {
+        warn!(
+            "Skipped metrics for unknown metric kinds in report {}: {:?}",
+            json_report.uuid, report_results.skipped_metric_kinds
+        );
+    }
+    json_report.skipped_metric_kinds = report_results.skipped_metric_kinds;
+
+    // Don't fail the report if the webhooks fail to dispatch
+    if let Err(e) = dispatch(conn, project_id, WebhookEvent::Report, &json_report) {
+        tracing::error!("Failed to dispatch report webhooks: {e}");
+    }
+
+    Ok(json_report)
 }
 
 #[cfg(feature = "plus")]
@@ -372,12 +523,20 @@ async fn get_one_inner(
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
+    query_report(conn, query_project.id, path_params.report_uuid)?.into_json(conn)
+}
+
+fn query_report(
+    conn: &mut DbConnection,
+    project_id: i32,
+    report_uuid: Uuid,
+) -> Result<QueryReport, ApiError> {
     schema::report::table
         .left_join(schema::testbed::table.on(schema::report::testbed_id.eq(schema::testbed::id)))
         .filter(
             schema::testbed::project_id
-                .eq(query_project.id)
-                .and(schema::report::uuid.eq(path_params.report_uuid.to_string())),
+                .eq(project_id)
+                .and(schema::report::uuid.eq(report_uuid.to_string())),
         )
         .select((
             schema::report::id,
@@ -390,6 +549,57 @@ async fn get_one_inner(
             schema::report::end_time,
         ))
         .first::<QueryReport>(conn)
-        .map_err(api_error!())?
-        .into_json(conn)
+        .map_err(api_error!())
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/reports/{report_uuid}/output",
+    tags = ["projects", "reports"]
+}]
+pub async fn output_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OnePath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/reports/{report_uuid}/output",
+    tags = ["projects", "reports"]
+}]
+pub async fn get_output(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseOk<Vec<JsonReportOutput>>, HttpError> {
+    // The raw output may contain secrets, so it is never public
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(REPORT_OUTPUT_RESOURCE, Method::GetLs);
+
+    let json = get_output_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json)
+}
+
+async fn get_output_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<Vec<JsonReportOutput>, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let query_report = query_report(conn, query_project.id, path_params.report_uuid)?;
+    QueryReportOutput::get_json(conn, query_report.id)
 }
@@ -1,7 +1,10 @@
-use bencher_json::{JsonNewTestbed, JsonTestbed, ResourceId};
+use bencher_json::{
+    project::testbed::JsonTestbedsSort, JsonDirection, JsonNewTestbed, JsonPagination, JsonTestbed,
+    ResourceId,
+};
 use bencher_rbac::project::Permission;
 use diesel::{expression_methods::BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -56,23 +59,25 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonTestbedsSort>>,
 ) -> Result<ResponseOk<Vec<JsonTestbed>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(TESTBED_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
+        &pagination_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
@@ -80,21 +85,53 @@ async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonTestbedsSort>,
     endpoint: Endpoint,
-) -> Result<Vec<JsonTestbed>, ApiError> {
+) -> Result<(Vec<JsonTestbed>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
-    Ok(schema::testbed::table
-        .filter(schema::testbed::project_id.eq(query_project.id))
-        .order((schema::testbed::name, schema::testbed::slug))
+    let filtered = || {
+        schema::testbed::table
+            .filter(schema::testbed::project_id.eq(query_project.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonTestbedsSort::Name, JsonDirection::Asc) => {
+            filtered().order((schema::testbed::name.asc(), schema::testbed::slug.asc()))
+        },
+        (JsonTestbedsSort::Name, JsonDirection::Desc) => {
+            filtered().order((schema::testbed::name.desc(), schema::testbed::slug.desc()))
+        },
+        (JsonTestbedsSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::testbed::id.asc())
+        },
+        (JsonTestbedsSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::testbed::id.desc())
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryTestbed>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -1,12 +1,12 @@
 use bencher_json::{
-    project::threshold::{JsonNewThreshold, JsonThreshold},
-    ResourceId,
+    project::threshold::{JsonNewThreshold, JsonThreshold, JsonThresholdsSort},
+    JsonDirection, JsonPagination, ResourceId,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
     expression_methods::BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl,
 };
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use uuid::Uuid;
@@ -18,8 +18,10 @@ use crate::{
         Endpoint, Method,
     },
     error::api_error,
+    model::audit::{AuditAction, InsertAuditEvent},
     model::project::{
         threshold::{InsertThreshold, QueryThreshold},
+        webhook::{dispatch, WebhookEvent},
         QueryProject,
     },
     model::user::auth::AuthUser,
@@ -62,23 +64,25 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonThresholdsSort>>,
 ) -> Result<ResponseOk<Vec<JsonThreshold>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await.ok();
     let endpoint = Endpoint::new(THRESHOLD_RESOURCE, Method::GetLs);
 
-    let json = get_ls_inner(
+    let (json, total_count) = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
+        &pagination_params.into_inner(),
         endpoint,
     )
     .await
     .map_err(|e| endpoint.err(e))?;
 
     if auth_user.is_some() {
-        response_ok!(endpoint, json)
+        response_ok!(endpoint, json, total_count)
     } else {
-        pub_response_ok!(endpoint, json)
+        pub_response_ok!(endpoint, json, total_count)
     }
 }
 
@@ -86,30 +90,64 @@ async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonThresholdsSort>,
     endpoint: Endpoint,
-) -> Result<Vec<JsonThreshold>, ApiError> {
+) -> Result<(Vec<JsonThreshold>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_project =
         QueryProject::is_allowed_public(conn, &context.rbac, &path_params.project, auth_user)?;
 
-    Ok(schema::threshold::table
-        .left_join(schema::testbed::table.on(schema::threshold::testbed_id.eq(schema::testbed::id)))
-        .filter(schema::testbed::project_id.eq(query_project.id))
-        .select((
-            schema::threshold::id,
-            schema::threshold::uuid,
-            schema::threshold::branch_id,
-            schema::threshold::testbed_id,
-            schema::threshold::metric_kind_id,
-            schema::threshold::statistic_id,
-        ))
-        .order(schema::threshold::id)
+    let filtered = || {
+        schema::threshold::table
+            .left_join(
+                schema::testbed::table.on(schema::threshold::testbed_id.eq(schema::testbed::id)),
+            )
+            .filter(schema::testbed::project_id.eq(query_project.id))
+            .select((
+                schema::threshold::id,
+                schema::threshold::uuid,
+                schema::threshold::branch_id,
+                schema::threshold::testbed_id,
+                schema::threshold::metric_kind_id,
+                schema::threshold::statistic_id,
+            ))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonThresholdsSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::threshold::id.asc())
+        },
+        (JsonThresholdsSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::threshold::id.desc())
+        },
+        (JsonThresholdsSort::Testbed, JsonDirection::Asc) => {
+            filtered().order((schema::testbed::name.asc(), schema::threshold::id.asc()))
+        },
+        (JsonThresholdsSort::Testbed, JsonDirection::Desc) => {
+            filtered().order((schema::testbed::name.desc(), schema::threshold::id.desc()))
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryThreshold>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -163,7 +201,7 @@ async fn post_inner(
         &context.rbac,
         project_id,
         auth_user,
-        Permission::Create,
+        Permission::ManageThreshold,
     )?;
 
     let insert_threshold =
@@ -173,11 +211,22 @@ async fn post_inner(
         .execute(conn)
         .map_err(api_error!())?;
 
-    schema::threshold::table
+    InsertAuditEvent::project(conn, auth_user.id, project_id, AuditAction::ThresholdCreate)?
+        .resource(&insert_threshold.uuid)
+        .insert(conn)?;
+
+    let json_threshold = schema::threshold::table
         .filter(schema::threshold::uuid.eq(&insert_threshold.uuid))
         .first::<QueryThreshold>(conn)
         .map_err(api_error!())?
-        .into_json(conn)
+        .into_json(conn)?;
+
+    // Don't fail the threshold if the webhooks fail to dispatch
+    if let Err(e) = dispatch(conn, project_id, WebhookEvent::Threshold, &json_threshold) {
+        tracing::error!("Failed to dispatch threshold webhooks: {e}");
+    }
+
+    Ok(json_threshold)
 }
 
 #[derive(Deserialize, JsonSchema)]
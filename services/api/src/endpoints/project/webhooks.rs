@@ -0,0 +1,549 @@
+use bencher_json::{
+    project::webhook::{
+        JsonUpdateWebhook, JsonWebhookDeliveriesSort, JsonWebhookDelivery, JsonWebhooksSort,
+    },
+    JsonDirection, JsonNewWebhook, JsonPagination, JsonWebhook, ResourceId,
+};
+use bencher_rbac::project::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    context::{ApiContext, Webhook},
+    endpoints::{
+        endpoint::{response_accepted, response_ok, ResponseAccepted, ResponseOk},
+        Endpoint, Method,
+    },
+    error::api_error,
+    model::audit::{AuditAction, InsertAuditEvent},
+    model::project::{
+        webhook::{delivery::QueryWebhookDelivery, InsertWebhook, QueryWebhook, WebhookEvent},
+        QueryProject,
+    },
+    model::user::auth::AuthUser,
+    schema,
+    util::{
+        cors::{get_cors, CorsResponse},
+        error::into_json,
+    },
+    ApiError,
+};
+
+use super::Resource;
+
+const WEBHOOK_RESOURCE: Resource = Resource::Webhook;
+const WEBHOOK_DELIVERY_RESOURCE: Resource = Resource::WebhookDelivery;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DirPath {
+    pub project: ResourceId,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn dir_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<DirPath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn get_ls(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonWebhooksSort>>,
+) -> Result<ResponseOk<Vec<JsonWebhook>>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(WEBHOOK_RESOURCE, Method::GetLs);
+
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        &pagination_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json, total_count)
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: DirPath,
+    pagination_params: &JsonPagination<JsonWebhooksSort>,
+    endpoint: Endpoint,
+) -> Result<(Vec<JsonWebhook>, i64), ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let filtered = || {
+        schema::webhook::table
+            .filter(schema::webhook::project_id.eq(query_project.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonWebhooksSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::webhook::id.asc())
+        },
+        (JsonWebhooksSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::webhook::id.desc())
+        },
+        (JsonWebhooksSort::Url, JsonDirection::Asc) => {
+            filtered().order((schema::webhook::url.asc(), schema::webhook::id.asc()))
+        },
+        (JsonWebhooksSort::Url, JsonDirection::Desc) => {
+            filtered().order((schema::webhook::url.desc(), schema::webhook::id.desc()))
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhook>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .filter_map(into_json!(endpoint, conn))
+        .collect();
+
+    Ok((json, total_count))
+}
+
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn post(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<DirPath>,
+    body: TypedBody<JsonNewWebhook>,
+) -> Result<ResponseAccepted<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(WEBHOOK_RESOURCE, Method::Post);
+
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: DirPath,
+    json_webhook: JsonNewWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, ApiError> {
+    Webhook::validate_url(
+        json_webhook.url.as_ref(),
+        context.allow_private_destinations,
+    )
+    .await?;
+
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    // The secret itself is not recorded
+    let details = serde_json::json!({
+        "url": json_webhook.url,
+        "secret": json_webhook.secret.is_some(),
+        "events": json_webhook.events,
+    });
+    let insert_webhook = InsertWebhook::from_json(query_project.id, json_webhook);
+
+    diesel::insert_into(schema::webhook::table)
+        .values(&insert_webhook)
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::WebhookCreate,
+    )?
+    .resource(&insert_webhook.uuid)
+    .details(&details)?
+    .insert(conn)?;
+
+    schema::webhook::table
+        .filter(schema::webhook::uuid.eq(&insert_webhook.uuid))
+        .first::<QueryWebhook>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OnePath {
+    pub project: ResourceId,
+    pub webhook: Uuid,
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn one_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OnePath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn get_one(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(WEBHOOK_RESOURCE, Method::GetOne);
+
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json)
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    schema::webhook::table
+        .filter(schema::webhook::project_id.eq(query_project.id))
+        .filter(schema::webhook::uuid.eq(path_params.webhook.to_string()))
+        .first::<QueryWebhook>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn patch(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+    body: TypedBody<JsonUpdateWebhook>,
+) -> Result<ResponseAccepted<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(WEBHOOK_RESOURCE, Method::Patch);
+
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    json_update: JsonUpdateWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, ApiError> {
+    if let Some(url) = &json_update.url {
+        Webhook::validate_url(url.as_ref(), context.allow_private_destinations).await?;
+    }
+
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    let webhook_id = schema::webhook::table
+        .filter(schema::webhook::project_id.eq(query_project.id))
+        .filter(schema::webhook::uuid.eq(path_params.webhook.to_string()))
+        .select(schema::webhook::id)
+        .first::<i32>(conn)
+        .map_err(api_error!())?;
+
+    let JsonUpdateWebhook {
+        url,
+        secret,
+        events,
+    } = json_update;
+
+    // The secret itself is not recorded
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::WebhookUpdate,
+    )?
+    .resource(path_params.webhook)
+    .details(&serde_json::json!({
+        "url": url,
+        "secret": secret.is_some(),
+        "events": events,
+    }))?
+    .insert(conn)?;
+
+    let query = schema::webhook::table.filter(schema::webhook::id.eq(webhook_id));
+    if let Some(url) = url {
+        diesel::update(query)
+            .set(schema::webhook::url.eq(String::from(url)))
+            .execute(conn)
+            .map_err(api_error!())?;
+    }
+    if let Some(secret) = secret {
+        diesel::update(query)
+            .set(schema::webhook::secret.eq(String::from(secret)))
+            .execute(conn)
+            .map_err(api_error!())?;
+    }
+    if let Some(events) = events {
+        diesel::update(query)
+            .set(schema::webhook::events.eq(WebhookEvent::to_bits(&events)))
+            .execute(conn)
+            .map_err(api_error!())?;
+    }
+
+    schema::webhook::table
+        .filter(schema::webhook::id.eq(webhook_id))
+        .first::<QueryWebhook>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
+
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn delete(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseAccepted<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(WEBHOOK_RESOURCE, Method::Delete);
+
+    let json = delete_inner(rqctx.context(), path_params.into_inner(), &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_webhook = schema::webhook::table
+        .filter(schema::webhook::project_id.eq(query_project.id))
+        .filter(schema::webhook::uuid.eq(path_params.webhook.to_string()))
+        .first::<QueryWebhook>(conn)
+        .map_err(api_error!())?;
+    let webhook_id = query_webhook.id;
+    let json_webhook = query_webhook.into_json(conn)?;
+
+    // Remove the delivery log before the webhook itself
+    diesel::delete(
+        schema::webhook_delivery::table.filter(schema::webhook_delivery::webhook_id.eq(webhook_id)),
+    )
+    .execute(conn)
+    .map_err(api_error!())?;
+    diesel::delete(schema::webhook::table.filter(schema::webhook::id.eq(webhook_id)))
+        .execute(conn)
+        .map_err(api_error!())?;
+
+    InsertAuditEvent::project(
+        conn,
+        auth_user.id,
+        query_project.id,
+        AuditAction::WebhookDelete,
+    )?
+    .resource(path_params.webhook)
+    .insert(conn)?;
+
+    Ok(json_webhook)
+}
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn deliveries_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OnePath>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn get_deliveries(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+    pagination_params: Query<JsonPagination<JsonWebhookDeliveriesSort>>,
+) -> Result<ResponseOk<Vec<JsonWebhookDelivery>>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(WEBHOOK_DELIVERY_RESOURCE, Method::GetLs);
+
+    let (json, total_count) = get_deliveries_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        &pagination_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json, total_count)
+}
+
+async fn get_deliveries_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: OnePath,
+    pagination_params: &JsonPagination<JsonWebhookDeliveriesSort>,
+    endpoint: Endpoint,
+) -> Result<(Vec<JsonWebhookDelivery>, i64), ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_project = QueryProject::is_allowed_resource_id(
+        conn,
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let webhook_id = schema::webhook::table
+        .filter(schema::webhook::project_id.eq(query_project.id))
+        .filter(schema::webhook::uuid.eq(path_params.webhook.to_string()))
+        .select(schema::webhook::id)
+        .first::<i32>(conn)
+        .map_err(api_error!())?;
+
+    let filtered = || {
+        schema::webhook_delivery::table
+            .filter(schema::webhook_delivery::webhook_id.eq(webhook_id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
+
+    // Most recent deliveries first by default
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Desc),
+    ) {
+        (JsonWebhookDeliveriesSort::Created, JsonDirection::Asc) => {
+            filtered().order(schema::webhook_delivery::id.asc())
+        },
+        (JsonWebhookDeliveriesSort::Created, JsonDirection::Desc) => {
+            filtered().order(schema::webhook_delivery::id.desc())
+        },
+        (JsonWebhookDeliveriesSort::Modified, JsonDirection::Asc) => filtered().order((
+            schema::webhook_delivery::modified.asc(),
+            schema::webhook_delivery::id.asc(),
+        )),
+        (JsonWebhookDeliveriesSort::Modified, JsonDirection::Desc) => filtered().order((
+            schema::webhook_delivery::modified.desc(),
+            schema::webhook_delivery::id.desc(),
+        )),
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhookDelivery>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .filter_map(into_json!(endpoint, conn))
+        .collect();
+
+    Ok((json, total_count))
+}
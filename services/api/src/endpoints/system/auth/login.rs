@@ -11,7 +11,9 @@ use crate::error::api_error;
 
 use crate::{
     context::{ApiContext, Body, ButtonBody, Message},
-    model::organization::organization_role::InsertOrganizationRole,
+    model::organization::{
+        organization_role::InsertOrganizationRole, service_account::QueryServiceAccount,
+    },
     model::user::QueryUser,
     schema,
     util::cors::{get_cors, CorsResponse},
@@ -65,6 +67,11 @@ async fn post_inner(context: &ApiContext, json_login: JsonLogin) -> Result<JsonE
         return Err(ApiError::Locked(query_user.id, query_user.email));
     }
 
+    // Service accounts only authenticate with API tokens
+    if QueryServiceAccount::from_user_id(conn, query_user.id)?.is_some() {
+        return Err(ApiError::ServiceAccountLogin(query_user.id));
+    }
+
     #[cfg(feature = "plus")]
     let plan = json_login.plan;
 
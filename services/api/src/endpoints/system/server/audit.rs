@@ -0,0 +1,81 @@
+use bencher_json::{
+    system::audit::{JsonAuditEvent, JsonAuditEvents, JsonAuditEventsSort},
+    JsonPagination,
+};
+use dropshot::{endpoint, HttpError, Query, RequestContext};
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{response_ok, ResponseOk},
+        Endpoint, Method,
+    },
+    model::{
+        audit::{AuditScope, QueryAuditEvent},
+        user::auth::AuthUser,
+    },
+    util::cors::{get_cors, CorsResponse},
+    ApiError,
+};
+
+use super::Resource;
+
+const AUDIT_RESOURCE: Resource = Resource::AuditEvent;
+
+#[allow(clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/server/audit",
+    tags = ["server", "audit"]
+}]
+pub async fn options(_rqctx: RequestContext<ApiContext>) -> Result<CorsResponse, HttpError> {
+    Ok(get_cors::<ApiContext>())
+}
+
+#[endpoint {
+    method = GET,
+    path =  "/v0/server/audit",
+    tags = ["server", "audit"]
+}]
+pub async fn get_ls(
+    rqctx: RequestContext<ApiContext>,
+    pagination_params: Query<JsonPagination<JsonAuditEventsSort>>,
+    query_params: Query<JsonAuditEvents>,
+) -> Result<ResponseOk<Vec<JsonAuditEvent>>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(AUDIT_RESOURCE, Method::GetLs);
+
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        &pagination_params.into_inner(),
+        &query_params.into_inner(),
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
+
+    response_ok!(endpoint, json, total_count)
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    pagination_params: &JsonPagination<JsonAuditEventsSort>,
+    json_audit_events: &JsonAuditEvents,
+    endpoint: Endpoint,
+) -> Result<(Vec<JsonAuditEvent>, i64), ApiError> {
+    if !auth_user.is_admin(&context.rbac) {
+        return Err(ApiError::Admin(auth_user.id));
+    }
+
+    let conn = &mut *context.conn().await;
+
+    QueryAuditEvent::get_ls(
+        conn,
+        AuditScope::Server,
+        json_audit_events,
+        pagination_params,
+        endpoint,
+    )
+}
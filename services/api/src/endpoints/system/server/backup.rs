@@ -17,7 +17,10 @@ use crate::{
         Endpoint, Method,
     },
     error::api_error,
-    model::user::auth::AuthUser,
+    model::{
+        audit::{AuditAction, InsertAuditEvent},
+        user::auth::AuthUser,
+    },
     util::cors::{get_cors, CorsResponse},
     ApiError,
 };
@@ -70,6 +73,13 @@ async fn post_inner(
         return Err(ApiError::Admin(auth_user.id));
     }
 
+    {
+        let conn = &mut *context.conn().await;
+        InsertAuditEvent::server(conn, auth_user.id, AuditAction::ServerBackup)?
+            .details(&json_backup)?
+            .insert(conn)?;
+    }
+
     // Create a database backup
     let (backup_file_path, backup_file_name) = backup_database(context).await?;
 
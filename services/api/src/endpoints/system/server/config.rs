@@ -8,7 +8,10 @@ use crate::{
         endpoint::{response_accepted, response_ok, ResponseAccepted, ResponseOk},
         Endpoint, Method,
     },
-    model::user::auth::AuthUser,
+    model::{
+        audit::{AuditAction, InsertAuditEvent},
+        user::auth::AuthUser,
+    },
     util::cors::{get_cors, CorsResponse},
     ApiError,
 };
@@ -89,6 +92,13 @@ async fn put_inner(
 
     let JsonUpdateConfig { config, delay } = json_update_config;
 
+    // The config is not recorded since it contains secrets
+    {
+        let conn = &mut *context.conn().await;
+        InsertAuditEvent::server(conn, auth_user.id, AuditAction::ServerConfigUpdate)?
+            .insert(conn)?;
+    }
+
     // todo() -> add validation here
     let config_str = serde_json::to_string(&config).map_err(ApiError::Serialize)?;
     std::env::set_var(BENCHER_CONFIG, &config_str);
@@ -2,6 +2,7 @@ use derive_more::Display;
 
 use crate::WordStr;
 
+pub mod audit;
 pub mod backup;
 pub mod config;
 pub mod ping;
@@ -10,6 +11,7 @@ pub mod version;
 
 #[derive(Debug, Display, Clone, Copy)]
 pub enum Resource {
+    AuditEvent,
     Ping,
     Version,
     Restart,
@@ -20,6 +22,7 @@ pub enum Resource {
 impl WordStr for Resource {
     fn singular(&self) -> &str {
         match self {
+            Self::AuditEvent => "audit event",
             Self::Ping => "ping",
             Self::Version => "version",
             Self::Restart => "restart",
@@ -30,6 +33,7 @@ impl WordStr for Resource {
 
     fn plural(&self) -> &str {
         match self {
+            Self::AuditEvent => "audit events",
             Self::Ping => "pings",
             Self::Version => "versions",
             Self::Restart => "restarts",
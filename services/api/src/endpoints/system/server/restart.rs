@@ -9,7 +9,10 @@ use crate::{
         endpoint::{response_accepted, ResponseAccepted},
         Endpoint, Method,
     },
-    model::user::auth::AuthUser,
+    model::{
+        audit::{AuditAction, InsertAuditEvent},
+        user::auth::AuthUser,
+    },
     util::cors::{get_cors, CorsResponse},
     ApiError,
 };
@@ -63,12 +66,15 @@ async fn post_inner(
         return Err(ApiError::Admin(auth_user.id));
     }
 
-    countdown(
-        context.restart_tx.clone(),
-        json_restart.delay.unwrap_or(DEFAULT_DELAY),
-        auth_user.id,
-    )
-    .await;
+    let delay = json_restart.delay.unwrap_or(DEFAULT_DELAY);
+    {
+        let conn = &mut *context.conn().await;
+        InsertAuditEvent::server(conn, auth_user.id, AuditAction::ServerRestart)?
+            .details(&serde_json::json!({ "delay": delay }))?
+            .insert(conn)?;
+    }
+
+    countdown(context.restart_tx.clone(), delay, auth_user.id).await;
 
     Ok(JsonEmpty {})
 }
@@ -1,6 +1,9 @@
-use bencher_json::{JsonNewToken, JsonToken, ResourceId};
+use bencher_json::{
+    user::token::JsonTokensSort, JsonDirection, JsonNewToken, JsonPagination, JsonToken, ResourceId,
+};
+use chrono::Utc;
 use diesel::{expression_methods::BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
-use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use uuid::Uuid;
@@ -13,10 +16,11 @@ use crate::{
     },
     error::api_error,
     model::{
+        audit::{AuditAction, InsertAuditEvent},
         user::QueryUser,
         user::{
             auth::AuthUser,
-            token::{same_user, InsertToken, QueryToken},
+            token::{same_token_user, InsertToken, QueryToken},
         },
     },
     schema,
@@ -57,38 +61,80 @@ pub async fn dir_options(
 pub async fn get_ls(
     rqctx: RequestContext<ApiContext>,
     path_params: Path<DirPath>,
+    pagination_params: Query<JsonPagination<JsonTokensSort>>,
 ) -> Result<ResponseOk<Vec<JsonToken>>, HttpError> {
     let auth_user = AuthUser::new(&rqctx).await?;
     let endpoint = Endpoint::new(TOKEN_RESOURCE, Method::GetLs);
 
     let context = rqctx.context();
     let path_params = path_params.into_inner();
-    let json = get_ls_inner(context, path_params, &auth_user, endpoint)
-        .await
-        .map_err(|e| endpoint.err(e))?;
+    let pagination_params = pagination_params.into_inner();
+    let (json, total_count) = get_ls_inner(
+        context,
+        path_params,
+        &pagination_params,
+        &auth_user,
+        endpoint,
+    )
+    .await
+    .map_err(|e| endpoint.err(e))?;
 
-    response_ok!(endpoint, json)
+    response_ok!(endpoint, json, total_count)
 }
 
 async fn get_ls_inner(
     context: &ApiContext,
     path_params: DirPath,
+    pagination_params: &JsonPagination<JsonTokensSort>,
     auth_user: &AuthUser,
     endpoint: Endpoint,
-) -> Result<Vec<JsonToken>, ApiError> {
+) -> Result<(Vec<JsonToken>, i64), ApiError> {
     let conn = &mut *context.conn().await;
 
     let query_user = QueryUser::from_resource_id(conn, &path_params.user)?;
-    same_user!(auth_user, context.rbac, query_user.id);
+    same_token_user(conn, &context.rbac, auth_user, query_user.id)?;
+
+    let filtered = || {
+        schema::token::table
+            .filter(schema::token::user_id.eq(query_user.id))
+            .into_boxed()
+    };
+
+    let total_count = filtered()
+        .count()
+        .get_result::<i64>(conn)
+        .map_err(api_error!())?;
 
-    Ok(schema::token::table
-        .filter(schema::token::user_id.eq(query_user.id))
-        .order((schema::token::creation, schema::token::expiration))
+    let query = match (
+        pagination_params.sort(),
+        pagination_params.direction(JsonDirection::Asc),
+    ) {
+        (JsonTokensSort::Creation, JsonDirection::Asc) => filtered().order((
+            schema::token::creation.asc(),
+            schema::token::expiration.asc(),
+        )),
+        (JsonTokensSort::Creation, JsonDirection::Desc) => filtered().order((
+            schema::token::creation.desc(),
+            schema::token::expiration.desc(),
+        )),
+        (JsonTokensSort::Name, JsonDirection::Asc) => {
+            filtered().order((schema::token::name.asc(), schema::token::creation.asc()))
+        },
+        (JsonTokensSort::Name, JsonDirection::Desc) => {
+            filtered().order((schema::token::name.desc(), schema::token::creation.desc()))
+        },
+    };
+
+    let json = query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
         .load::<QueryToken>(conn)
         .map_err(api_error!())?
         .into_iter()
         .filter_map(into_json!(endpoint, conn))
-        .collect())
+        .collect();
+
+    Ok((json, total_count))
 }
 
 #[endpoint {
@@ -135,6 +181,15 @@ async fn post_inner(
         .execute(conn)
         .map_err(api_error!())?;
 
+    InsertAuditEvent::user(
+        conn,
+        auth_user.id,
+        insert_token.user_id,
+        AuditAction::TokenCreate,
+    )?
+    .resource(&insert_token.uuid)
+    .insert(conn)?;
+
     schema::token::table
         .filter(schema::token::uuid.eq(&insert_token.uuid))
         .first::<QueryToken>(conn)
@@ -191,7 +246,7 @@ async fn get_one_inner(
     let conn = &mut *context.conn().await;
 
     let query_user = QueryUser::from_resource_id(conn, &path_params.user)?;
-    same_user!(auth_user, context.rbac, query_user.id);
+    same_token_user(conn, &context.rbac, auth_user, query_user.id)?;
 
     schema::token::table
         .filter(
@@ -204,3 +259,63 @@ async fn get_one_inner(
         .into_json(conn)
         .map_err(api_error!())
 }
+
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/users/{user}/tokens/{token}",
+    tags = ["users", "tokens"]
+}]
+pub async fn delete(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<OnePath>,
+) -> Result<ResponseAccepted<JsonToken>, HttpError> {
+    let auth_user = AuthUser::new(&rqctx).await?;
+    let endpoint = Endpoint::new(TOKEN_RESOURCE, Method::Delete);
+
+    let context = rqctx.context();
+    let path_params = path_params.into_inner();
+    let json = delete_inner(context, path_params, &auth_user)
+        .await
+        .map_err(|e| endpoint.err(e))?;
+
+    response_accepted!(endpoint, json)
+}
+
+/// Revoke a token.
+/// The token is kept so that its JWT is denied even though it has not expired.
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OnePath,
+    auth_user: &AuthUser,
+) -> Result<JsonToken, ApiError> {
+    let conn = &mut *context.conn().await;
+
+    let query_user = QueryUser::from_resource_id(conn, &path_params.user)?;
+    same_token_user(conn, &context.rbac, auth_user, query_user.id)?;
+
+    let query_token = schema::token::table
+        .filter(
+            schema::token::user_id
+                .eq(query_user.id)
+                .and(schema::token::uuid.eq(&path_params.token.to_string())),
+        )
+        .first::<QueryToken>(conn)
+        .map_err(api_error!())?;
+
+    if query_token.revoked.is_none() {
+        diesel::update(schema::token::table.filter(schema::token::id.eq(query_token.id)))
+            .set(schema::token::revoked.eq(Utc::now().timestamp()))
+            .execute(conn)
+            .map_err(api_error!())?;
+
+        InsertAuditEvent::user(conn, auth_user.id, query_user.id, AuditAction::TokenRevoke)?
+            .resource(&query_token.uuid)
+            .insert(conn)?;
+    }
+
+    schema::token::table
+        .filter(schema::token::id.eq(query_token.id))
+        .first::<QueryToken>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)
+}
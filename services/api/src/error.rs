@@ -36,8 +36,8 @@ pub enum ApiError {
     Url(#[from] url::ParseError),
     #[error("Failed to inline CSS: {0}")]
     CssInline(#[from] css_inline::InlineError),
-    #[error("Failed to run stats: {0}")]
-    Statrs(#[from] statrs::StatsError),
+    #[error("Failed to detect boundary: {0}")]
+    Boundary(#[from] bencher_boundary::BoundaryError),
     #[error("Failed to run adapter: {0}")]
     Adapter(#[from] bencher_adapter::AdapterError),
     #[error("Failed to find env var: {0}")]
@@ -84,6 +84,10 @@ pub enum ApiError {
     SameUser(i32, i32),
     #[error("User account locked: ID {0} email {1}")]
     Locked(i32, String),
+    #[error("API token has been revoked for user: {0}")]
+    RevokedToken(i32),
+    #[error("Scoped API token cannot be used to act as its user ({0})")]
+    ScopedToken(i32),
     #[error("Invitation email ({email}) is connected to user {email_user_id} which doesn't match {user_id}")]
     InviteEmail {
         user_id: i32,
@@ -196,14 +200,70 @@ pub enum ApiError {
     MailTls(mail_send::Error),
     #[error("Failed to send email: {0}")]
     MailSend(mail_send::Error),
+    #[error("Failed to sign webhook: {0}")]
+    WebhookSignature(hmac::digest::InvalidLength),
+    #[error("Failed to send webhook: {0}")]
+    WebhookSend(reqwest::Error),
+    #[error("Failed to send webhook to {0}: {1}")]
+    WebhookStatus(String, reqwest::StatusCode),
+    #[error("Webhook URL must resolve to a public address: {0}")]
+    WebhookDestination(String),
+    #[error("Failed to recognize webhook event integer: {0}")]
+    WebhookEvent(i32),
     #[error("User is not an admin: {0}")]
     Admin(i32),
     #[error("Failed to parse organization role: {0}")]
     OrganizationRole(String),
+    #[error("Failed to parse project role: {0}")]
+    ProjectRole(String),
+    #[error("Project ({project_id}) does not belong to the service account organization ({organization_id})")]
+    ServiceAccountProject {
+        organization_id: i32,
+        project_id: i32,
+    },
+    #[error(
+        "Project ({project_id}) does not belong to the member organization ({organization_id})"
+    )]
+    MemberProject {
+        organization_id: i32,
+        project_id: i32,
+    },
+    #[error("Service account project roles can only be set when it is created: {0}")]
+    ServiceAccountMember(i32),
+    #[error(
+        "Project role ({1}) is neither a built-in role nor a custom role of the organization ({0})"
+    )]
+    UnknownProjectRole(i32, String),
+    #[error("Custom role name is already used by a built-in role: {0}")]
+    BuiltInRole(String),
+    #[error("Custom role ({1}) is still assigned on a project in the organization ({0})")]
+    CustomRoleInUse(i32, String),
+    #[error("Service account can not login: {0}")]
+    ServiceAccountLogin(i32),
     #[error("Failed to recognize adapter integer: {0}")]
     AdapterInt(i32),
+    #[error("Invalid git hash prefix: {0}")]
+    GitHashPrefix(String),
+    #[error("Failed to parse as either a git hash or a report UUID: {0}")]
+    CompareCommit(String),
+    #[error("Git hash prefix matches more than one version: {0}")]
+    AmbiguousHash(String),
     #[error("Failed to load statistic kind: {0}")]
     StatisticKind(i32),
+    #[error("Failed to load statistic baseline: {0}")]
+    StatisticBaseline(i32),
+    #[error("Invalid statistic limit, expected a non-negative number: {0}")]
+    StatisticLimit(f64),
+    #[error("Percentage and absolute statistics require a lower limit, an upper limit, or both")]
+    StatisticLimitMissing,
+    #[error("Failed to load alert status: {0}")]
+    AlertStatus(i32),
+    #[error("Alert status can only be updated to acknowledged or dismissed: {0}")]
+    AlertStatusUpdate(uuid::Uuid),
+    #[error("Alert has already been resolved: {0}")]
+    AlertResolved(uuid::Uuid),
+    #[error("Failed to recognize audit action integer: {0}")]
+    AuditAction(i32),
     #[error("Failed to recognize visibility integer: {0}")]
     VisibilityInt(i32),
     #[error("Unexpected dimension: testbed")]
@@ -0,0 +1,455 @@
+use std::str::FromStr;
+
+use bencher_json::{
+    system::audit::{JsonAuditAction, JsonAuditEvent, JsonAuditEvents, JsonAuditEventsSort},
+    JsonDirection, JsonPagination,
+};
+use chrono::Utc;
+use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    context::DbConnection,
+    endpoints::Endpoint,
+    error::api_error,
+    model::{
+        organization::{service_account::QueryServiceAccount, QueryOrganization},
+        project::QueryProject,
+        user::{token::to_date_time, QueryUser},
+    },
+    schema,
+    schema::audit_event as audit_event_table,
+    util::error::into_json,
+    ApiError,
+};
+
+#[derive(Queryable)]
+pub struct QueryAuditEvent {
+    pub id: i32,
+    pub uuid: String,
+    pub user_id: i32,
+    pub organization_id: Option<i32>,
+    pub project_id: Option<i32>,
+    pub action: i32,
+    pub resource: Option<String>,
+    pub details: Option<String>,
+    pub creation: i64,
+}
+
+/// Where the audit events are listed from
+#[derive(Clone, Copy)]
+pub enum AuditScope {
+    Server,
+    Organization(i32),
+    Project(i32),
+}
+
+impl QueryAuditEvent {
+    pub fn get_ls(
+        conn: &mut DbConnection,
+        scope: AuditScope,
+        json_audit_events: &JsonAuditEvents,
+        pagination_params: &JsonPagination<JsonAuditEventsSort>,
+        endpoint: Endpoint,
+    ) -> Result<(Vec<JsonAuditEvent>, i64), ApiError> {
+        let user_id = json_audit_events
+            .user
+            .as_ref()
+            .map(|user| QueryUser::from_resource_id(conn, user))
+            .transpose()?
+            .map(|user| user.id);
+        let action = json_audit_events
+            .action
+            .map(|action| AuditAction::from(action) as i32);
+
+        let filtered = || {
+            let mut query = schema::audit_event::table.into_boxed();
+            match scope {
+                AuditScope::Server => {},
+                AuditScope::Organization(organization_id) => {
+                    query = query.filter(schema::audit_event::organization_id.eq(organization_id));
+                },
+                AuditScope::Project(project_id) => {
+                    query = query.filter(schema::audit_event::project_id.eq(project_id));
+                },
+            }
+            if let Some(user_id) = user_id {
+                query = query.filter(schema::audit_event::user_id.eq(user_id));
+            }
+            if let Some(action) = action {
+                query = query.filter(schema::audit_event::action.eq(action));
+            }
+            if let Some(start_time) = json_audit_events.start_time {
+                query = query.filter(schema::audit_event::creation.ge(start_time.timestamp()));
+            }
+            if let Some(end_time) = json_audit_events.end_time {
+                query = query.filter(schema::audit_event::creation.le(end_time.timestamp()));
+            }
+            query
+        };
+
+        let total_count = filtered()
+            .count()
+            .get_result::<i64>(conn)
+            .map_err(api_error!())?;
+
+        // Most recent events first by default
+        let query = match (
+            pagination_params.sort(),
+            pagination_params.direction(JsonDirection::Desc),
+        ) {
+            (JsonAuditEventsSort::Creation, JsonDirection::Asc) => filtered().order((
+                schema::audit_event::creation.asc(),
+                schema::audit_event::id.asc(),
+            )),
+            (JsonAuditEventsSort::Creation, JsonDirection::Desc) => filtered().order((
+                schema::audit_event::creation.desc(),
+                schema::audit_event::id.desc(),
+            )),
+            (JsonAuditEventsSort::Action, JsonDirection::Asc) => filtered().order((
+                schema::audit_event::action.asc(),
+                schema::audit_event::creation.asc(),
+                schema::audit_event::id.asc(),
+            )),
+            (JsonAuditEventsSort::Action, JsonDirection::Desc) => filtered().order((
+                schema::audit_event::action.desc(),
+                schema::audit_event::creation.desc(),
+                schema::audit_event::id.desc(),
+            )),
+        };
+
+        let json = query
+            .offset(pagination_params.offset())
+            .limit(pagination_params.limit())
+            .load::<QueryAuditEvent>(conn)
+            .map_err(api_error!())?
+            .into_iter()
+            .filter_map(into_json!(endpoint, conn))
+            .collect();
+
+        Ok((json, total_count))
+    }
+
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonAuditEvent, ApiError> {
+        let Self {
+            uuid,
+            user_id,
+            organization_id,
+            project_id,
+            action,
+            resource,
+            details,
+            creation,
+            ..
+        } = self;
+        Ok(JsonAuditEvent {
+            uuid: Uuid::from_str(&uuid).map_err(api_error!())?,
+            user: QueryUser::get_uuid(conn, user_id)?,
+            organization: organization_id
+                .map(|id| QueryOrganization::get_uuid(conn, id))
+                .transpose()?,
+            project: project_id
+                .map(|id| QueryProject::get_uuid(conn, id))
+                .transpose()?,
+            action: AuditAction::try_from(action)?.into(),
+            resource: resource
+                .as_deref()
+                .map(Uuid::from_str)
+                .transpose()
+                .map_err(api_error!())?,
+            details: details.as_deref().map(serde_json::from_str).transpose()?,
+            creation: to_date_time(creation)?,
+        })
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = audit_event_table)]
+pub struct InsertAuditEvent {
+    pub uuid: String,
+    pub user_id: i32,
+    pub organization_id: Option<i32>,
+    pub project_id: Option<i32>,
+    pub action: i32,
+    pub resource: Option<String>,
+    pub details: Option<String>,
+    pub creation: i64,
+}
+
+/// What an audit event is recorded for, which decides the audit logs that it is part of
+enum AuditSubject {
+    Server,
+    User(i32),
+    Organization(i32),
+    Project(i32),
+}
+
+impl InsertAuditEvent {
+    fn new(
+        conn: &mut DbConnection,
+        user_id: i32,
+        subject: AuditSubject,
+        action: AuditAction,
+    ) -> Result<Self, ApiError> {
+        let (organization_id, project_id) = match subject {
+            AuditSubject::Server => (None, None),
+            // The actions on a service account are also part of its organization audit log
+            AuditSubject::User(subject_user_id) => (
+                QueryServiceAccount::from_user_id(conn, subject_user_id)?
+                    .map(|service_account| service_account.organization_id),
+                None,
+            ),
+            AuditSubject::Organization(organization_id) => (Some(organization_id), None),
+            // The actions in a project are also part of its organization audit log
+            AuditSubject::Project(project_id) => (
+                Some(
+                    schema::project::table
+                        .filter(schema::project::id.eq(project_id))
+                        .select(schema::project::organization_id)
+                        .first::<i32>(conn)
+                        .map_err(api_error!())?,
+                ),
+                Some(project_id),
+            ),
+        };
+        Ok(Self {
+            uuid: Uuid::new_v4().to_string(),
+            user_id,
+            organization_id,
+            project_id,
+            action: action as i32,
+            resource: None,
+            details: None,
+            creation: Utc::now().timestamp(),
+        })
+    }
+
+    pub fn server(
+        conn: &mut DbConnection,
+        user_id: i32,
+        action: AuditAction,
+    ) -> Result<Self, ApiError> {
+        Self::new(conn, user_id, AuditSubject::Server, action)
+    }
+
+    /// An action on the resources of a user, such as its API tokens
+    pub fn user(
+        conn: &mut DbConnection,
+        user_id: i32,
+        subject_user_id: i32,
+        action: AuditAction,
+    ) -> Result<Self, ApiError> {
+        Self::new(conn, user_id, AuditSubject::User(subject_user_id), action)
+    }
+
+    pub fn organization(
+        conn: &mut DbConnection,
+        user_id: i32,
+        organization_id: i32,
+        action: AuditAction,
+    ) -> Result<Self, ApiError> {
+        Self::new(
+            conn,
+            user_id,
+            AuditSubject::Organization(organization_id),
+            action,
+        )
+    }
+
+    pub fn project(
+        conn: &mut DbConnection,
+        user_id: i32,
+        project_id: i32,
+        action: AuditAction,
+    ) -> Result<Self, ApiError> {
+        Self::new(conn, user_id, AuditSubject::Project(project_id), action)
+    }
+
+    #[must_use]
+    pub fn resource(mut self, uuid: impl ToString) -> Self {
+        self.resource = Some(uuid.to_string());
+        self
+    }
+
+    pub fn details<T>(mut self, details: &T) -> Result<Self, ApiError>
+    where
+        T: Serialize,
+    {
+        self.details = Some(serde_json::to_string(details).map_err(ApiError::Serialize)?);
+        Ok(self)
+    }
+
+    pub fn insert(&self, conn: &mut DbConnection) -> Result<(), ApiError> {
+        diesel::insert_into(schema::audit_event::table)
+            .values(self)
+            .execute(conn)
+            .map_err(api_error!())?;
+        Ok(())
+    }
+}
+
+const SERVER_RESTART_INT: i32 = 0;
+const SERVER_BACKUP_INT: i32 = 1;
+const SERVER_CONFIG_UPDATE_INT: i32 = 2;
+const TOKEN_CREATE_INT: i32 = 3;
+const TOKEN_REVOKE_INT: i32 = 4;
+const MEMBER_INVITE_INT: i32 = 5;
+const MEMBER_ROLE_UPDATE_INT: i32 = 6;
+const MEMBER_REMOVE_INT: i32 = 7;
+const SERVICE_ACCOUNT_CREATE_INT: i32 = 8;
+const SERVICE_ACCOUNT_DELETE_INT: i32 = 9;
+const PROJECT_CREATE_INT: i32 = 10;
+const BRANCH_CREATE_INT: i32 = 11;
+const THRESHOLD_CREATE_INT: i32 = 12;
+const ALERT_UPDATE_INT: i32 = 13;
+const NOTIFICATION_DELETE_INT: i32 = 14;
+const WEBHOOK_UPDATE_INT: i32 = 15;
+const WEBHOOK_DELETE_INT: i32 = 16;
+const ROLE_CREATE_INT: i32 = 17;
+const ROLE_DELETE_INT: i32 = 18;
+const PROJECT_UPDATE_INT: i32 = 19;
+const PROJECT_ROLE_UPDATE_INT: i32 = 20;
+const PROJECT_ROLE_REMOVE_INT: i32 = 21;
+const ORGANIZATION_CREATE_INT: i32 = 22;
+const PLAN_CREATE_INT: i32 = 23;
+const WEBHOOK_CREATE_INT: i32 = 24;
+const NOTIFICATION_CREATE_INT: i32 = 25;
+const ROLE_UPDATE_INT: i32 = 26;
+
+#[derive(Clone, Copy)]
+#[repr(i32)]
+pub enum AuditAction {
+    ServerRestart = SERVER_RESTART_INT,
+    ServerBackup = SERVER_BACKUP_INT,
+    ServerConfigUpdate = SERVER_CONFIG_UPDATE_INT,
+    TokenCreate = TOKEN_CREATE_INT,
+    TokenRevoke = TOKEN_REVOKE_INT,
+    MemberInvite = MEMBER_INVITE_INT,
+    MemberRoleUpdate = MEMBER_ROLE_UPDATE_INT,
+    MemberRemove = MEMBER_REMOVE_INT,
+    ServiceAccountCreate = SERVICE_ACCOUNT_CREATE_INT,
+    ServiceAccountDelete = SERVICE_ACCOUNT_DELETE_INT,
+    ProjectCreate = PROJECT_CREATE_INT,
+    BranchCreate = BRANCH_CREATE_INT,
+    ThresholdCreate = THRESHOLD_CREATE_INT,
+    AlertUpdate = ALERT_UPDATE_INT,
+    NotificationDelete = NOTIFICATION_DELETE_INT,
+    WebhookUpdate = WEBHOOK_UPDATE_INT,
+    WebhookDelete = WEBHOOK_DELETE_INT,
+    RoleCreate = ROLE_CREATE_INT,
+    RoleDelete = ROLE_DELETE_INT,
+    ProjectUpdate = PROJECT_UPDATE_INT,
+    ProjectRoleUpdate = PROJECT_ROLE_UPDATE_INT,
+    ProjectRoleRemove = PROJECT_ROLE_REMOVE_INT,
+    OrganizationCreate = ORGANIZATION_CREATE_INT,
+    PlanCreate = PLAN_CREATE_INT,
+    WebhookCreate = WEBHOOK_CREATE_INT,
+    NotificationCreate = NOTIFICATION_CREATE_INT,
+    RoleUpdate = ROLE_UPDATE_INT,
+}
+
+impl TryFrom<i32> for AuditAction {
+    type Error = ApiError;
+
+    fn try_from(action: i32) -> Result<Self, Self::Error> {
+        match action {
+            SERVER_RESTART_INT => Ok(Self::ServerRestart),
+            SERVER_BACKUP_INT => Ok(Self::ServerBackup),
+            SERVER_CONFIG_UPDATE_INT => Ok(Self::ServerConfigUpdate),
+            TOKEN_CREATE_INT => Ok(Self::TokenCreate),
+            TOKEN_REVOKE_INT => Ok(Self::TokenRevoke),
+            MEMBER_INVITE_INT => Ok(Self::MemberInvite),
+            MEMBER_ROLE_UPDATE_INT => Ok(Self::MemberRoleUpdate),
+            MEMBER_REMOVE_INT => Ok(Self::MemberRemove),
+            SERVICE_ACCOUNT_CREATE_INT => Ok(Self::ServiceAccountCreate),
+            SERVICE_ACCOUNT_DELETE_INT => Ok(Self::ServiceAccountDelete),
+            PROJECT_CREATE_INT => Ok(Self::ProjectCreate),
+            BRANCH_CREATE_INT => Ok(Self::BranchCreate),
+            THRESHOLD_CREATE_INT => Ok(Self::ThresholdCreate),
+            ALERT_UPDATE_INT => Ok(Self::AlertUpdate),
+            NOTIFICATION_DELETE_INT => Ok(Self::NotificationDelete),
+            WEBHOOK_UPDATE_INT => Ok(Self::WebhookUpdate),
+            WEBHOOK_DELETE_INT => Ok(Self::WebhookDelete),
+            ROLE_CREATE_INT => Ok(Self::RoleCreate),
+            ROLE_DELETE_INT => Ok(Self::RoleDelete),
+            PROJECT_UPDATE_INT => Ok(Self::ProjectUpdate),
+            PROJECT_ROLE_UPDATE_INT => Ok(Self::ProjectRoleUpdate),
+            PROJECT_ROLE_REMOVE_INT => Ok(Self::ProjectRoleRemove),
+            ORGANIZATION_CREATE_INT => Ok(Self::OrganizationCreate),
+            PLAN_CREATE_INT => Ok(Self::PlanCreate),
+            WEBHOOK_CREATE_INT => Ok(Self::WebhookCreate),
+            NOTIFICATION_CREATE_INT => Ok(Self::NotificationCreate),
+            ROLE_UPDATE_INT => Ok(Self::RoleUpdate),
+            _ => Err(ApiError::AuditAction(action)),
+        }
+    }
+}
+
+impl From<JsonAuditAction> for AuditAction {
+    fn from(action: JsonAuditAction) -> Self {
+        match action {
+            JsonAuditAction::ServerRestart => Self::ServerRestart,
+            JsonAuditAction::ServerBackup => Self::ServerBackup,
+            JsonAuditAction::ServerConfigUpdate => Self::ServerConfigUpdate,
+            JsonAuditAction::TokenCreate => Self::TokenCreate,
+            JsonAuditAction::TokenRevoke => Self::TokenRevoke,
+            JsonAuditAction::MemberInvite => Self::MemberInvite,
+            JsonAuditAction::MemberRoleUpdate => Self::MemberRoleUpdate,
+            JsonAuditAction::MemberRemove => Self::MemberRemove,
+            JsonAuditAction::ServiceAccountCreate => Self::ServiceAccountCreate,
+            JsonAuditAction::ServiceAccountDelete => Self::ServiceAccountDelete,
+            JsonAuditAction::ProjectCreate => Self::ProjectCreate,
+            JsonAuditAction::BranchCreate => Self::BranchCreate,
+            JsonAuditAction::ThresholdCreate => Self::ThresholdCreate,
+            JsonAuditAction::AlertUpdate => Self::AlertUpdate,
+            JsonAuditAction::NotificationDelete => Self::NotificationDelete,
+            JsonAuditAction::WebhookUpdate => Self::WebhookUpdate,
+            JsonAuditAction::WebhookDelete => Self::WebhookDelete,
+            JsonAuditAction::RoleCreate => Self::RoleCreate,
+            JsonAuditAction::RoleDelete => Self::RoleDelete,
+            JsonAuditAction::ProjectUpdate => Self::ProjectUpdate,
+            JsonAuditAction::ProjectRoleUpdate => Self::ProjectRoleUpdate,
+            JsonAuditAction::ProjectRoleRemove => Self::ProjectRoleRemove,
+            JsonAuditAction::OrganizationCreate => Self::OrganizationCreate,
+            JsonAuditAction::PlanCreate => Self::PlanCreate,
+            JsonAuditAction::WebhookCreate => Self::WebhookCreate,
+            JsonAuditAction::NotificationCreate => Self::NotificationCreate,
+            JsonAuditAction::RoleUpdate => Self::RoleUpdate,
+        }
+    }
+}
+
+impl From<AuditAction> for JsonAuditAction {
+    fn from(action: AuditAction) -> Self {
+        match action {
+            AuditAction::ServerRestart => Self::ServerRestart,
+            AuditAction::ServerBackup => Self::ServerBackup,
+            AuditAction::ServerConfigUpdate => Self::ServerConfigUpdate,
+            AuditAction::TokenCreate => Self::TokenCreate,
+            AuditAction::TokenRevoke => Self::TokenRevoke,
+            AuditAction::MemberInvite => Self::MemberInvite,
+            AuditAction::MemberRoleUpdate => Self::MemberRoleUpdate,
+            AuditAction::MemberRemove => Self::MemberRemove,
+            AuditAction::ServiceAccountCreate => Self::ServiceAccountCreate,
+            AuditAction::ServiceAccountDelete => Self::ServiceAccountDelete,
+            AuditAction::ProjectCreate => Self::ProjectCreate,
+            AuditAction::BranchCreate => Self::BranchCreate,
+            AuditAction::ThresholdCreate => Self::ThresholdCreate,
+            AuditAction::AlertUpdate => Self::AlertUpdate,
+            AuditAction::NotificationDelete => Self::NotificationDelete,
+            AuditAction::WebhookUpdate => Self::WebhookUpdate,
+            AuditAction::WebhookDelete => Self::WebhookDelete,
+            AuditAction::RoleCreate => Self::RoleCreate,
+            AuditAction::RoleDelete => Self::RoleDelete,
+            AuditAction::ProjectUpdate => Self::ProjectUpdate,
+            AuditAction::ProjectRoleUpdate => Self::ProjectRoleUpdate,
+            AuditAction::ProjectRoleRemove => Self::ProjectRoleRemove,
+            AuditAction::OrganizationCreate => Self::OrganizationCreate,
+            AuditAction::PlanCreate => Self::PlanCreate,
+            AuditAction::WebhookCreate => Self::WebhookCreate,
+            AuditAction::NotificationCreate => Self::NotificationCreate,
+            AuditAction::RoleUpdate => Self::RoleUpdate,
+        }
+    }
+}
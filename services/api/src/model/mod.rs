@@ -1,3 +1,4 @@
+pub mod audit;
 pub mod organization;
 pub mod project;
 pub mod user;
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use bencher_json::{
+    organization::role::{JsonRole, JsonRolePermission},
+    ResourceId, Slug,
+};
+use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
+use uuid::Uuid;
+
+use crate::{
+    context::DbConnection, error::api_error, model::user::token::to_date_time, schema,
+    schema::custom_role as custom_role_table, ApiError,
+};
+
+use super::QueryOrganization;
+
+#[derive(Queryable)]
+pub struct QueryCustomRole {
+    pub id: i32,
+    pub uuid: String,
+    pub organization_id: i32,
+    pub name: String,
+    pub permissions: String,
+    pub creation: i64,
+}
+
+impl QueryCustomRole {
+    /// Get a custom role for an organization by either its UUID or its name
+    pub fn from_resource_id(
+        conn: &mut DbConnection,
+        organization_id: i32,
+        role: &ResourceId,
+    ) -> Result<Self, ApiError> {
+        let query = schema::custom_role::table
+            .filter(schema::custom_role::organization_id.eq(organization_id))
+            .into_boxed();
+        if let Ok(uuid) = Uuid::from_str(role.as_ref()) {
+            query.filter(schema::custom_role::uuid.eq(uuid.to_string()))
+        } else {
+            query.filter(schema::custom_role::name.eq(role.as_ref()))
+        }
+        .first::<Self>(conn)
+        .map_err(api_error!())
+    }
+
+    /// Check that a custom role with the given name exists for an organization
+    pub fn exists(conn: &mut DbConnection, organization_id: i32, name: &str) -> bool {
+        schema::custom_role::table
+            .filter(schema::custom_role::organization_id.eq(organization_id))
+            .filter(schema::custom_role::name.eq(name))
+            .select(schema::custom_role::id)
+            .first::<i32>(conn)
+            .is_ok()
+    }
+
+    fn permissions(&self) -> Result<Vec<JsonRolePermission>, ApiError> {
+        serde_json::from_str(&self.permissions).map_err(ApiError::Deserialize)
+    }
+
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonRole, ApiError> {
+        let permissions = self.permissions()?;
+        let Self {
+            uuid,
+            organization_id,
+            name,
+            creation,
+            ..
+        } = self;
+        Ok(JsonRole {
+            uuid: Uuid::from_str(&uuid).map_err(api_error!())?,
+            organization: QueryOrganization::get_uuid(conn, organization_id)?,
+            name: Slug::from_str(&name).map_err(api_error!())?,
+            permissions,
+            creation: to_date_time(creation)?,
+        })
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = custom_role_table)]
+pub struct InsertCustomRole {
+    pub uuid: String,
+    pub organization_id: i32,
+    pub name: String,
+    pub permissions: String,
+    pub creation: i64,
+}
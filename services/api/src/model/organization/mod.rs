@@ -19,8 +19,10 @@ use crate::{
     ApiError,
 };
 
+pub mod custom_role;
 pub mod member;
 pub mod organization_role;
+pub mod service_account;
 
 #[derive(Insertable)]
 #[diesel(table_name = organization_table)]
@@ -0,0 +1,109 @@
+use std::str::FromStr;
+
+use bencher_json::organization::service_account::{JsonServiceAccount, JsonServiceAccountProject};
+use diesel::{
+    ExpressionMethods, Insertable, JoinOnDsl, OptionalExtension, QueryDsl, Queryable, RunQueryDsl,
+};
+use uuid::Uuid;
+
+use crate::{
+    context::DbConnection,
+    error::api_error,
+    model::user::{token::to_date_time, QueryUser},
+    schema::{self, service_account as service_account_table},
+    ApiError,
+};
+
+use super::QueryOrganization;
+
+/// The domain for service account emails, which are never used to send or receive email
+const SERVICE_ACCOUNT_EMAIL_DOMAIN: &str = "service-account.bencher.invalid";
+
+#[derive(Queryable)]
+pub struct QueryServiceAccount {
+    pub id: i32,
+    pub user_id: i32,
+    pub organization_id: i32,
+    pub creator_id: i32,
+    pub creation: i64,
+}
+
+impl QueryServiceAccount {
+    /// The service account for a user, if the user is a service account
+    pub fn from_user_id(conn: &mut DbConnection, user_id: i32) -> Result<Option<Self>, ApiError> {
+        schema::service_account::table
+            .filter(schema::service_account::user_id.eq(user_id))
+            .first::<Self>(conn)
+            .optional()
+            .map_err(api_error!())
+    }
+
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonServiceAccount, ApiError> {
+        let Self {
+            user_id,
+            organization_id,
+            creator_id,
+            creation,
+            ..
+        } = self;
+        let query_user = schema::user::table
+            .filter(schema::user::id.eq(user_id))
+            .first::<QueryUser>(conn)
+            .map_err(api_error!())?;
+
+        // A service account without a leader role only has the default member role
+        let role = schema::organization_role::table
+            .filter(schema::organization_role::user_id.eq(user_id))
+            .filter(schema::organization_role::organization_id.eq(organization_id))
+            .select(schema::organization_role::role)
+            .first::<String>(conn)
+            .optional()
+            .map_err(api_error!())?
+            .and_then(|role| role.parse().ok());
+
+        let projects = schema::project_role::table
+            .inner_join(
+                schema::project::table.on(schema::project_role::project_id.eq(schema::project::id)),
+            )
+            .filter(schema::project_role::user_id.eq(user_id))
+            .order(schema::project::name)
+            .select((schema::project::uuid, schema::project_role::role))
+            .load::<(String, String)>(conn)
+            .map_err(api_error!())?
+            .into_iter()
+            .map(|(uuid, role)| {
+                Ok(JsonServiceAccountProject {
+                    project: Uuid::from_str(&uuid).map_err(api_error!())?.into(),
+                    role: role.parse().map_err(ApiError::ProjectRole)?,
+                })
+            })
+            .collect::<Result<_, ApiError>>()?;
+
+        let json_user = query_user.into_json()?;
+        Ok(JsonServiceAccount {
+            uuid: json_user.uuid,
+            name: json_user.name,
+            slug: json_user.slug,
+            organization: QueryOrganization::get_uuid(conn, organization_id)?,
+            role,
+            projects,
+            creator: QueryUser::get_uuid(conn, creator_id)?,
+            creation: to_date_time(creation)?,
+            locked: json_user.locked,
+        })
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = service_account_table)]
+pub struct InsertServiceAccount {
+    pub user_id: i32,
+    pub organization_id: i32,
+    pub creator_id: i32,
+    pub creation: i64,
+}
+
+/// A placeholder email for a service account user, since users are unique by email
+pub fn service_account_email(user_uuid: &str) -> String {
+    format!("{user_uuid}@{SERVICE_ACCOUNT_EMAIL_DOMAIN}")
+}
@@ -28,6 +28,7 @@ pub mod benchmark;
 pub mod branch;
 pub mod metric;
 pub mod metric_kind;
+pub mod notification;
 pub mod perf;
 pub mod project_role;
 pub mod report;
@@ -35,6 +36,7 @@ pub mod testbed;
 pub mod threshold;
 pub mod version;
 pub mod visibility;
+pub mod webhook;
 
 #[derive(Insertable)]
 #[diesel(table_name = project_table)]
@@ -45,6 +47,7 @@ pub struct InsertProject {
     pub slug: String,
     pub url: Option<String>,
     pub visibility: i32,
+    pub auto_metric_kinds: bool,
 }
 
 impl InsertProject {
@@ -58,6 +61,7 @@ impl InsertProject {
             slug,
             url,
             visibility,
+            auto_metric_kinds,
         } = project;
         let slug = unwrap_slug!(conn, name.as_ref(), slug, project, QueryProject);
         Ok(Self {
@@ -67,6 +71,7 @@ impl InsertProject {
             slug,
             url: url.map(|u| u.to_string()),
             visibility: Visibility::from(visibility.unwrap_or_default()) as i32,
+            auto_metric_kinds: auto_metric_kinds.unwrap_or_default(),
         })
     }
 }
@@ -82,6 +87,7 @@ pub struct QueryProject {
     pub slug: String,
     pub url: Option<String>,
     pub visibility: i32,
+    pub auto_metric_kinds: bool,
 }
 
 impl QueryProject {
@@ -93,6 +99,7 @@ impl QueryProject {
             slug,
             url,
             visibility,
+            auto_metric_kinds,
             ..
         } = self;
         Ok(JsonProject {
@@ -102,6 +109,7 @@ impl QueryProject {
             slug: Slug::from_str(&slug).map_err(api_error!())?,
             url: ok_url(url.as_deref())?,
             visibility: Visibility::try_from(visibility)?.into(),
+            auto_metric_kinds,
         })
     }
 
@@ -0,0 +1,220 @@
+use std::str::FromStr;
+
+use bencher_json::{
+    project::notification::{JsonAlertNotification, JsonNotificationAlert},
+    Email, GitHash, JsonNewNotification, JsonNotification,
+};
+use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
+use uuid::Uuid;
+
+use super::{
+    benchmark::QueryBenchmark,
+    branch::QueryBranch,
+    report::{results::ReportResults, QueryReport},
+    testbed::QueryTestbed,
+    threshold::alert::QueryAlert,
+    webhook::{dispatch, WebhookEvent},
+    QueryProject,
+};
+use crate::{
+    context::{ApiContext, Body, ButtonBody, DbConnection, Message},
+    error::api_error,
+    schema,
+    schema::notification as notification_table,
+    ApiError,
+};
+
+#[derive(Queryable)]
+pub struct QueryNotification {
+    pub id: i32,
+    pub uuid: String,
+    pub project_id: i32,
+    pub email: String,
+}
+
+impl QueryNotification {
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonNotification, ApiError> {
+        let Self {
+            uuid,
+            project_id,
+            email,
+            ..
+        } = self;
+        Ok(JsonNotification {
+            uuid: Uuid::from_str(&uuid).map_err(api_error!())?,
+            project: QueryProject::get_uuid(conn, project_id)?,
+            email: Email::from_str(&email)?,
+        })
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = notification_table)]
+pub struct InsertNotification {
+    pub uuid: String,
+    pub project_id: i32,
+    pub email: String,
+}
+
+impl InsertNotification {
+    pub fn from_json(project_id: i32, notification: JsonNewNotification) -> Self {
+        let JsonNewNotification { email } = notification;
+        Self {
+            uuid: Uuid::new_v4().to_string(),
+            project_id,
+            email: email.into(),
+        }
+    }
+}
+
+/// Send a single message to each of the project notifications
+/// and dispatch a single alert event to the project webhooks
+/// for all of the new alerts generated by a report.
+/// The emails are sent in the background, so that the database connection is not held while sending.
+pub fn notify_alerts(
+    context: &ApiContext,
+    conn: &mut DbConnection,
+    report_results: &ReportResults,
+    hash: Option<GitHash>,
+) -> Result<(), ApiError> {
+    if report_results.alerts.is_empty() {
+        return Ok(());
+    }
+
+    let payload = alert_notification(conn, report_results, hash)?;
+    dispatch(
+        conn,
+        report_results.project_id,
+        WebhookEvent::Alert,
+        &payload,
+    )?;
+
+    let messages = schema::notification::table
+        .filter(schema::notification::project_id.eq(report_results.project_id))
+        .select(schema::notification::email)
+        .load::<String>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .map(|email| alert_message(context, &payload, email))
+        .collect::<Vec<_>>();
+    if messages.is_empty() {
+        return Ok(());
+    }
+
+    let messenger = context.messenger.clone();
+    tokio::spawn(async move {
+        for message in messages {
+            messenger.send(message).await;
+        }
+    });
+
+    Ok(())
+}
+
+fn alert_notification(
+    conn: &mut DbConnection,
+    report_results: &ReportResults,
+    hash: Option<GitHash>,
+) -> Result<JsonAlertNotification, ApiError> {
+    let project = schema::project::table
+        .filter(schema::project::id.eq(report_results.project_id))
+        .first::<QueryProject>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)?;
+    let branch = schema::branch::table
+        .filter(schema::branch::id.eq(report_results.branch_id))
+        .first::<QueryBranch>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)?;
+    let testbed = schema::testbed::table
+        .filter(schema::testbed::id.eq(report_results.testbed_id))
+        .first::<QueryTestbed>(conn)
+        .map_err(api_error!())?
+        .into_json(conn)?;
+
+    let mut alerts = Vec::with_capacity(report_results.alerts.len());
+    for alert_id in &report_results.alerts {
+        let query_alert = schema::alert::table
+            .filter(schema::alert::id.eq(alert_id))
+            .first::<QueryAlert>(conn)
+            .map_err(api_error!())?;
+        let benchmark = schema::perf::table
+            .filter(schema::perf::id.eq(query_alert.perf_id))
+            .inner_join(schema::benchmark::table)
+            .select(schema::benchmark::all_columns)
+            .first::<QueryBenchmark>(conn)
+            .map_err(api_error!())?
+            .into_json(conn)?;
+        alerts.push(JsonNotificationAlert {
+            alert: query_alert.into_json(conn)?,
+            benchmark,
+        });
+    }
+
+    Ok(JsonAlertNotification {
+        project,
+        report: QueryReport::get_uuid(conn, report_results.report_id)?,
+        branch,
+        testbed,
+        hash,
+        alerts,
+    })
+}
+
+fn alert_message(context: &ApiContext, payload: &JsonAlertNotification, email: String) -> Message {
+    let JsonAlertNotification {
+        project,
+        report,
+        branch,
+        testbed,
+        hash,
+        alerts,
+    } = payload;
+
+    let count = alerts.len();
+    let plural = if count == 1 { "" } else { "s" };
+    let subject = format!("{count} new Alert{plural} for {project}");
+    let hash = hash
+        .as_ref()
+        .map(|hash| format!(" at commit {hash}"))
+        .unwrap_or_default();
+    let benchmarks = alerts
+        .iter()
+        .map(|alert| alert.benchmark.name.as_ref())
+        .collect::<Vec<&str>>()
+        .join(", ");
+
+    let body = Body::Button(Box::new(ButtonBody {
+        title: subject.clone(),
+        preheader: "Click the provided link to view the Alerts.".into(),
+        greeting: "Ahoy!".into(),
+        pre_body: format!(
+            "A Report for the {branch} branch on the {testbed} testbed{hash} generated {count} new Alert{plural} for {project}: {benchmarks}",
+        ),
+        button_text: "View Alerts".into(),
+        button_url: context
+            .endpoint
+            .clone()
+            .join(&format!("/console/projects/{}/alerts", project.slug))
+            .map(Into::into)
+            .unwrap_or_default(),
+        clipboard_text: "Report UUID".into(),
+        clipboard_target: report.to_string(),
+        post_body: String::new(),
+        closing: "See you soon,".into(),
+        signature: "The Bencher Team".into(),
+        settings_url: context
+            .endpoint
+            .clone()
+            .join("/console/settings/email")
+            .map(Into::into)
+            .unwrap_or_default(),
+    }));
+
+    Message {
+        to_name: None,
+        to_email: email,
+        subject: Some(subject),
+        body: Some(body),
+    }
+}
@@ -4,9 +4,12 @@ use crate::ApiError;
 
 const MAGIC_INT: i32 = 0;
 const JSON_INT: i32 = 10;
+const CUSTOM_INT: i32 = 11;
 const RUST_INT: i32 = 20;
 const RUST_BENCH_INT: i32 = 21;
 const RUST_CRITERION_INT: i32 = 22;
+const RUST_CRITERION_JSON_INT: i32 = 23;
+const RUST_IAI_INT: i32 = 24;
 const CPP_INT: i32 = 30;
 const CPP_GOOGLE_INT: i32 = 31;
 const CPP_CATCH2_INT: i32 = 32;
@@ -24,14 +27,19 @@ const PYTHON_ASV_INT: i32 = 81;
 const PYTHON_PYTEST_INT: i32 = 82;
 const RUBY_INT: i32 = 90;
 const RUBY_BENCHMARK_INT: i32 = 91;
+const SHELL_INT: i32 = 100;
+const SHELL_HYPERFINE_INT: i32 = 101;
 
 #[repr(i32)]
 pub enum Adapter {
     Magic = MAGIC_INT,
     Json = JSON_INT,
+    Custom = CUSTOM_INT,
     Rust = RUST_INT,
     RustBench = RUST_BENCH_INT,
     RustCriterion = RUST_CRITERION_INT,
+    RustCriterionJson = RUST_CRITERION_JSON_INT,
+    RustIai = RUST_IAI_INT,
     Cpp = CPP_INT,
     CppGoogle = CPP_GOOGLE_INT,
     CppCatch2 = CPP_CATCH2_INT,
@@ -49,6 +57,8 @@ pub enum Adapter {
     PythonPytest = PYTHON_PYTEST_INT,
     Ruby = RUBY_INT,
     RubyBenchmark = RUBY_BENCHMARK_INT,
+    Shell = SHELL_INT,
+    ShellHyperfine = SHELL_HYPERFINE_INT,
 }
 
 impl TryFrom<i32> for Adapter {
@@ -58,9 +68,12 @@ impl TryFrom<i32> for Adapter {
         match adapter {
             MAGIC_INT => Ok(Self::Magic),
             JSON_INT => Ok(Self::Json),
+            CUSTOM_INT => Ok(Self::Custom),
             RUST_INT => Ok(Self::Rust),
             RUST_BENCH_INT => Ok(Self::RustBench),
             RUST_CRITERION_INT => Ok(Self::RustCriterion),
+            RUST_CRITERION_JSON_INT => Ok(Self::RustCriterionJson),
+            RUST_IAI_INT => Ok(Self::RustIai),
             CPP_INT => Ok(Self::Cpp),
             CPP_GOOGLE_INT => Ok(Self::CppGoogle),
             CPP_CATCH2_INT => Ok(Self::CppCatch2),
@@ -78,6 +91,8 @@ impl TryFrom<i32> for Adapter {
             PYTHON_PYTEST_INT => Ok(Self::PythonPytest),
             RUBY_INT => Ok(Self::Ruby),
             RUBY_BENCHMARK_INT => Ok(Self::RubyBenchmark),
+            SHELL_INT => Ok(Self::Shell),
+            SHELL_HYPERFINE_INT => Ok(Self::ShellHyperfine),
             _ => Err(ApiError::AdapterInt(adapter)),
         }
     }
@@ -88,9 +103,12 @@ impl From<JsonAdapter> for Adapter {
         match adapter {
             JsonAdapter::Magic => Self::Magic,
             JsonAdapter::Json => Self::Json,
+            JsonAdapter::Custom => Self::Custom,
             JsonAdapter::Rust => Self::Rust,
             JsonAdapter::RustBench => Self::RustBench,
             JsonAdapter::RustCriterion => Self::RustCriterion,
+            JsonAdapter::RustCriterionJson => Self::RustCriterionJson,
+            JsonAdapter::RustIai => Self::RustIai,
             JsonAdapter::Cpp => Self::Cpp,
             JsonAdapter::CppGoogle => Self::CppGoogle,
             JsonAdapter::CppCatch2 => Self::CppCatch2,
@@ -108,6 +126,8 @@ impl From<JsonAdapter> for Adapter {
             JsonAdapter::PythonPytest => Self::PythonPytest,
             JsonAdapter::Ruby => Self::Ruby,
             JsonAdapter::RubyBenchmark => Self::RubyBenchmark,
+            JsonAdapter::Shell => Self::Shell,
+            JsonAdapter::ShellHyperfine => Self::ShellHyperfine,
         }
     }
 }
@@ -117,9 +137,12 @@ impl From<Adapter> for JsonAdapter {
         match adapter {
             Adapter::Magic => Self::Magic,
             Adapter::Json => Self::Json,
+            Adapter::Custom => Self::Custom,
             Adapter::Rust => Self::Rust,
             Adapter::RustBench => Self::RustBench,
             Adapter::RustCriterion => Self::RustCriterion,
+            Adapter::RustCriterionJson => Self::RustCriterionJson,
+            Adapter::RustIai => Self::RustIai,
             Adapter::Cpp => Self::Cpp,
             Adapter::CppGoogle => Self::CppGoogle,
             Adapter::CppCatch2 => Self::CppCatch2,
@@ -137,6 +160,8 @@ impl From<Adapter> for JsonAdapter {
             Adapter::PythonPytest => Self::PythonPytest,
             Adapter::Ruby => Self::Ruby,
             Adapter::RubyBenchmark => Self::RubyBenchmark,
+            Adapter::Shell => Self::Shell,
+            Adapter::ShellHyperfine => Self::ShellHyperfine,
         }
     }
 }
@@ -0,0 +1,84 @@
+use bencher_json::project::testbed::JsonFingerprint;
+use diesel::{
+    ExpressionMethods, Insertable, JoinOnDsl, OptionalExtension, QueryDsl, Queryable, RunQueryDsl,
+};
+
+use crate::{
+    context::DbConnection, error::api_error, schema,
+    schema::report_fingerprint as report_fingerprint_table, ApiError,
+};
+
+#[derive(Queryable)]
+pub struct QueryReportFingerprint {
+    pub id: i32,
+    pub report_id: i32,
+    pub fingerprint: String,
+    pub changed: bool,
+}
+
+impl QueryReportFingerprint {
+    /// The fingerprint for a report and whether it changed from the previous one for the testbed
+    pub fn get_json(
+        conn: &mut DbConnection,
+        report_id: i32,
+    ) -> Result<Option<(JsonFingerprint, bool)>, ApiError> {
+        schema::report_fingerprint::table
+            .filter(schema::report_fingerprint::report_id.eq(report_id))
+            .first::<Self>(conn)
+            .optional()
+            .map_err(api_error!())?
+            .map(|query_fingerprint| {
+                Ok((
+                    serde_json::from_str(&query_fingerprint.fingerprint)?,
+                    query_fingerprint.changed,
+                ))
+            })
+            .transpose()
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = report_fingerprint_table)]
+pub struct InsertReportFingerprint {
+    pub report_id: i32,
+    pub fingerprint: String,
+    pub changed: bool,
+}
+
+impl InsertReportFingerprint {
+    /// Insert the fingerprint for a report, comparing it to the most recent previous fingerprint for the testbed
+    pub fn insert(
+        conn: &mut DbConnection,
+        testbed_id: i32,
+        report_id: i32,
+        fingerprint: &JsonFingerprint,
+    ) -> Result<bool, ApiError> {
+        let previous = schema::report_fingerprint::table
+            .inner_join(
+                schema::report::table
+                    .on(schema::report_fingerprint::report_id.eq(schema::report::id)),
+            )
+            .filter(schema::report::testbed_id.eq(testbed_id))
+            .filter(schema::report::id.ne(report_id))
+            .order((schema::report::start_time.desc(), schema::report::id.desc()))
+            .select(schema::report_fingerprint::fingerprint)
+            .first::<String>(conn)
+            .optional()
+            .map_err(api_error!())?
+            .map(|previous| serde_json::from_str::<JsonFingerprint>(&previous))
+            .transpose()?;
+        let changed = previous.map_or(false, |previous| &previous != fingerprint);
+
+        let insert_fingerprint = Self {
+            report_id,
+            fingerprint: serde_json::to_string(fingerprint)?,
+            changed,
+        };
+        diesel::insert_into(schema::report_fingerprint::table)
+            .values(&insert_fingerprint)
+            .execute(conn)
+            .map_err(api_error!())?;
+
+        Ok(changed)
+    }
+}
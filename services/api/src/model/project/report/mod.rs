@@ -1,16 +1,16 @@
 use std::str::FromStr;
 
 use bencher_json::{
-    project::report::{JsonAdapter, JsonReportAlerts, JsonReportResults},
+    project::report::{JsonAdapter, JsonReportAlert, JsonReportAlerts, JsonReportResults},
     JsonNewReport, JsonReport,
 };
 use chrono::{DateTime, TimeZone, Utc};
 use diesel::{ExpressionMethods, Insertable, JoinOnDsl, QueryDsl, Queryable, RunQueryDsl};
 use uuid::Uuid;
 
-use self::adapter::Adapter;
+use self::{adapter::Adapter, fingerprint::QueryReportFingerprint};
 
-use super::{testbed::QueryTestbed, version::QueryVersion};
+use super::{testbed::QueryTestbed, threshold::alert::AlertStatus, version::QueryVersion};
 use crate::{
     context::DbConnection,
     error::api_error,
@@ -22,6 +22,8 @@ use crate::{
 };
 
 mod adapter;
+pub mod fingerprint;
+pub mod output;
 pub mod results;
 
 #[derive(Queryable)]
@@ -51,6 +53,10 @@ impl QueryReport {
     pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonReport, ApiError> {
         let results = self.get_results(conn)?;
         let alerts = self.get_alerts(conn)?;
+        let (fingerprint, fingerprint_changed) = QueryReportFingerprint::get_json(conn, self.id)?
+            .map_or((None, false), |(fingerprint, changed)| {
+                (Some(fingerprint), changed)
+            });
         let Self {
             uuid,
             user_id,
@@ -71,6 +77,9 @@ impl QueryReport {
             end_time: to_date_time(end_time)?,
             results,
             alerts,
+            fingerprint,
+            fingerprint_changed,
+            skipped_metric_kinds: Vec::new(),
         })
     }
 
@@ -95,13 +104,19 @@ impl QueryReport {
         Ok(schema::alert::table
             .left_join(schema::perf::table.on(schema::perf::id.eq(schema::alert::perf_id)))
             .filter(schema::perf::report_id.eq(self.id))
-            .select(schema::alert::uuid)
+            .select((schema::alert::uuid, schema::alert::status))
             .order(schema::alert::id)
-            .load::<String>(conn)
+            .load::<(String, i32)>(conn)
             .map_err(api_error!())?
-            .iter()
-            .filter_map(|uuid| {
-                database_map("QueryReport::get_alerts", Uuid::from_str(uuid)).map(Into::into)
+            .into_iter()
+            .filter_map(|(uuid, status)| {
+                let uuid = database_map("QueryReport::get_alerts", Uuid::from_str(&uuid))?;
+                let status =
+                    database_map("QueryReport::get_alerts", AlertStatus::try_from(status))?;
+                Some(JsonReportAlert {
+                    uuid,
+                    status: status.into(),
+                })
             })
             .collect())
     }
@@ -0,0 +1,76 @@
+use bencher_json::project::report::JsonReportOutput;
+use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
+
+use crate::{
+    context::DbConnection, error::api_error, schema, schema::report_output as report_output_table,
+    ApiError,
+};
+
+#[derive(Queryable)]
+pub struct QueryReportOutput {
+    pub id: i32,
+    pub report_id: i32,
+    pub iteration: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl QueryReportOutput {
+    /// The raw output for each iteration of a report, in iteration order
+    pub fn get_json(
+        conn: &mut DbConnection,
+        report_id: i32,
+    ) -> Result<Vec<JsonReportOutput>, ApiError> {
+        Ok(schema::report_output::table
+            .filter(schema::report_output::report_id.eq(report_id))
+            .order(schema::report_output::iteration)
+            .load::<Self>(conn)
+            .map_err(api_error!())?
+            .into_iter()
+            .map(Self::into_json)
+            .collect())
+    }
+
+    pub fn into_json(self) -> JsonReportOutput {
+        let Self { stdout, stderr, .. } = self;
+        JsonReportOutput { stdout, stderr }
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = report_output_table)]
+pub struct InsertReportOutput {
+    pub report_id: i32,
+    pub iteration: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl InsertReportOutput {
+    pub fn insert(
+        conn: &mut DbConnection,
+        report_id: i32,
+        output: Vec<JsonReportOutput>,
+    ) -> Result<(), ApiError> {
+        let insert_outputs = output
+            .into_iter()
+            .enumerate()
+            .map(|(iteration, JsonReportOutput { stdout, stderr })| {
+                let JsonReportOutput { stdout, stderr } = JsonReportOutput::new(stdout, stderr);
+                Ok(Self {
+                    report_id,
+                    iteration: i32::try_from(iteration).map_err(api_error!())?,
+                    stdout,
+                    stderr,
+                })
+            })
+            .collect::<Result<Vec<_>, ApiError>>()?;
+
+        diesel::insert_into(schema::report_output::table)
+            .values(&insert_outputs)
+            .execute(conn)
+            .map_err(api_error!())?;
+
+        Ok(())
+    }
+}
@@ -16,11 +16,14 @@ impl MetricsData {
         branch_id: i32,
         testbed_id: i32,
         metric_kind_id: i32,
+        perf_id: i32,
         benchmark_id: i32,
         statistic: &QueryStatistic,
     ) -> Result<Self, ApiError> {
         let mut query = schema::metric::table
             .filter(schema::metric::metric_kind_id.eq(metric_kind_id))
+            // The datum being checked has already been stored, so leave it out of its own baseline
+            .filter(schema::metric::perf_id.ne(perf_id))
             .inner_join(schema::perf::table.on(schema::metric::perf_id.eq(schema::perf::id)))
             .left_join(
                 schema::benchmark::table.on(schema::perf::benchmark_id.eq(schema::benchmark::id)),
@@ -1,15 +1,16 @@
-use bencher_json::JsonMetric;
-use diesel::RunQueryDsl;
-use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+use bencher_boundary::{Boundary, Detection};
+use bencher_json::{project::threshold::JsonNewStatistic, JsonMetric};
+use chrono::Utc;
+use diesel::{
+    dsl::{Eq, Filter, Select},
+    ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+};
 use uuid::Uuid;
 
 use crate::{
     context::DbConnection,
     error::api_error,
-    model::project::threshold::{
-        alert::{InsertAlert, Side},
-        statistic::StatisticKind,
-    },
+    model::project::threshold::alert::{AlertStatus, InsertAlert, QueryAlert, Side},
     schema, ApiError,
 };
 
@@ -25,6 +26,7 @@ pub struct Detector {
     pub testbed_id: i32,
     pub metric_kind_id: i32,
     pub threshold: MetricsThreshold,
+    pub statistic: JsonNewStatistic,
 }
 
 impl Detector {
@@ -44,82 +46,47 @@ impl Detector {
             return Ok(None);
         };
 
+        let statistic = threshold.statistic.clone().into_json()?.into();
+
         Ok(Some(Self {
             branch_id,
             testbed_id,
             metric_kind_id,
             threshold,
+            statistic,
         }))
     }
 
-    #[allow(
-        clippy::arithmetic_side_effects,
-        clippy::cast_possible_truncation,
-        clippy::cast_precision_loss,
-        clippy::cast_sign_loss,
-        clippy::float_arithmetic,
-        clippy::integer_arithmetic
-    )]
     pub fn detect(
         &self,
         conn: &mut DbConnection,
         perf_id: i32,
         benchmark_id: i32,
         metric: JsonMetric,
-    ) -> Result<(), ApiError> {
+    ) -> Result<Option<i32>, ApiError> {
         // Query the historical population/sample data for the benchmark
         let metrics_data = MetricsData::new(
             conn,
             self.branch_id,
             self.testbed_id,
             self.metric_kind_id,
+            perf_id,
             benchmark_id,
             &self.threshold.statistic,
         )?;
 
-        // If there is a min sample size, then check to see if it is met.
-        // Otherwise, simply return.
-        if let Some(min_sample_size) = self.threshold.statistic.min_sample_size {
-            if metrics_data.data.len() < min_sample_size as usize {
-                return Ok(());
-            }
-        }
-
-        let data = &metrics_data.data;
-        let datum = metric.value.into();
-        if let Some(mean) = mean(data) {
-            if let Some(std_dev) = std_deviation(mean, data) {
-                let (abs_datum, side, boundary) = if datum < mean {
-                    if let Some(left_side) = self.threshold.statistic.left_side {
-                        (mean * 2.0 - datum, Side::Left, left_side)
-                    } else {
-                        return Ok(());
-                    }
-                } else if let Some(right_side) = self.threshold.statistic.right_side {
-                    (datum, Side::Right, right_side)
-                } else {
-                    return Ok(());
-                };
-
-                let percentile = match self.threshold.statistic.test.try_into()? {
-                    StatisticKind::Z => {
-                        let normal = Normal::new(mean, std_dev).map_err(api_error!())?;
-                        normal.cdf(abs_datum)
-                    },
-                    StatisticKind::T => {
-                        let students_t = StudentsT::new(mean, std_dev, (data.len() - 1) as f64)
-                            .map_err(api_error!())?;
-                        students_t.cdf(abs_datum)
-                    },
-                };
-
-                if percentile > f64::from(boundary) {
-                    self.alert(conn, perf_id, side, boundary, percentile)?;
-                }
-            }
+        // Only a datum that was checked and is back inside of the threshold
+        // resolves any outstanding alerts.
+        // Otherwise, return the ID of any new active alert so that it can be notified about.
+        match Boundary::detect(&self.statistic, &metrics_data.data, metric.value.into())? {
+            Detection::Unchecked => Ok(None),
+            Detection::Inside => self.resolve(conn, benchmark_id).map(|_| None),
+            Detection::Outlier(Boundary {
+                side,
+                limit,
+                outlier,
+            }) => self.alert(conn, perf_id, benchmark_id, side.into(), limit, outlier),
         }
-
-        Ok(())
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -127,10 +94,28 @@ impl Detector {
         &self,
         conn: &mut DbConnection,
         perf_id: i32,
+        benchmark_id: i32,
         side: Side,
         boundary: f32,
         outlier: f64,
-    ) -> Result<(), ApiError> {
+    ) -> Result<Option<i32>, ApiError> {
+        // An ongoing regression that has already been acknowledged or dismissed
+        // carries that status forward, so only new regressions are active.
+        let (status, status_user_id, status_time) = schema::alert::table
+            .filter(schema::alert::threshold_id.eq(self.threshold.id))
+            .filter(schema::alert::perf_id.eq_any(benchmark_perf(benchmark_id)))
+            .filter(schema::alert::status.ne(AlertStatus::Resolved as i32))
+            .order(schema::alert::id.desc())
+            .select((
+                schema::alert::status,
+                schema::alert::status_user_id,
+                schema::alert::status_time,
+            ))
+            .first::<(i32, Option<i32>, Option<i64>)>(conn)
+            .optional()
+            .map_err(api_error!())?
+            .unwrap_or((AlertStatus::Active as i32, None, None));
+
         let insert_alert = InsertAlert {
             uuid: Uuid::new_v4().to_string(),
             perf_id,
@@ -139,6 +124,9 @@ impl Detector {
             side: side.into(),
             boundary,
             outlier: outlier as f32,
+            status,
+            status_user_id,
+            status_time,
         };
 
         diesel::insert_into(schema::alert::table)
@@ -146,31 +134,176 @@ impl Detector {
             .execute(conn)
             .map_err(api_error!())?;
 
+        if status != AlertStatus::Active as i32 {
+            return Ok(None);
+        }
+        QueryAlert::get_id(conn, &insert_alert.uuid).map(Some)
+    }
+
+    fn resolve(&self, conn: &mut DbConnection, benchmark_id: i32) -> Result<(), ApiError> {
+        diesel::update(
+            schema::alert::table
+                .filter(schema::alert::threshold_id.eq(self.threshold.id))
+                .filter(schema::alert::perf_id.eq_any(benchmark_perf(benchmark_id)))
+                .filter(schema::alert::status.ne(AlertStatus::Resolved as i32)),
+        )
+        .set((
+            schema::alert::status.eq(AlertStatus::Resolved as i32),
+            schema::alert::status_user_id.eq(None::<i32>),
+            schema::alert::status_time.eq(Utc::now().timestamp_nanos()),
+        ))
+        .execute(conn)
+        .map_err(api_error!())?;
+
         Ok(())
     }
 }
 
-fn std_deviation(mean: f64, data: &[f64]) -> Option<f64> {
-    variance(mean, data).map(f64::sqrt)
+fn benchmark_perf(
+    benchmark_id: i32,
+) -> Filter<Select<schema::perf::table, schema::perf::id>, Eq<schema::perf::benchmark_id, i32>> {
+    schema::perf::table
+        .select(schema::perf::id)
+        .filter(schema::perf::benchmark_id.eq(benchmark_id))
 }
 
-#[allow(clippy::cast_precision_loss, clippy::float_arithmetic)]
-fn variance(mean: f64, data: &[f64]) -> Option<f64> {
-    if data.is_empty() {
-        return None;
+#[cfg(test)]
+mod test {
+    use bencher_json::JsonMetric;
+    use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
+
+    use crate::{
+        config::config_tx::run_migrations, context::DbConnection,
+        model::project::threshold::alert::AlertStatus, schema,
+    };
+
+    use super::Detector;
+
+    const BENCHMARK_ID: i32 = 1;
+
+    fn setup_conn() -> DbConnection {
+        let mut conn = DbConnection::establish(":memory:").unwrap();
+        run_migrations(&mut conn).unwrap();
+        for query in [
+            "INSERT INTO organization (id, uuid, name, slug) VALUES (1, 'org-uuid', 'Org', 'org')",
+            "INSERT INTO project (id, uuid, organization_id, name, slug, visibility) VALUES (1, 'project-uuid', 1, 'Project', 'project', 0)",
+            "INSERT INTO user (id, uuid, name, slug, email, admin, locked) VALUES (1, 'user-uuid', 'User', 'user', 'user@example.com', false, false)",
+            "INSERT INTO branch (id, uuid, project_id, name, slug) VALUES (1, 'branch-uuid', 1, 'main', 'main')",
+            "INSERT INTO testbed (id, uuid, project_id, name, slug) VALUES (1, 'testbed-uuid', 1, 'localhost', 'localhost')",
+            "INSERT INTO metric_kind (id, uuid, project_id, name, slug, units) VALUES (1, 'metric-kind-uuid', 1, 'Latency', 'latency', 'nanoseconds (ns)')",
+            "INSERT INTO benchmark (id, uuid, project_id, name) VALUES (1, 'benchmark-uuid', 1, 'bench')",
+            // A percentage test with a 10% upper limit
+            "INSERT INTO statistic (id, uuid, test, upper_limit) VALUES (1, '00000000-0000-0000-0000-000000000001', 2, 10.0)",
+            "INSERT INTO threshold (id, uuid, branch_id, testbed_id, metric_kind_id, statistic_id) VALUES (1, 'threshold-uuid', 1, 1, 1, 1)",
+        ] {
+            diesel::sql_query(query).execute(&mut conn).unwrap();
+        }
+        conn
     }
-    Some(
-        data.iter()
-            .map(|value| (*value - mean).powi(2))
-            .sum::<f64>()
-            / data.len() as f64,
-    )
-}
 
-#[allow(clippy::cast_precision_loss, clippy::float_arithmetic)]
-fn mean(data: &[f64]) -> Option<f64> {
-    if data.is_empty() {
-        return None;
+    // Store a metric the same way that a report does before it is checked
+    fn store_metric(conn: &mut DbConnection, id: i32, value: f64) {
+        for query in [
+            format!("INSERT INTO version (id, uuid, number) VALUES ({id}, 'version-{id}', {id})"),
+            format!("INSERT INTO branch_version (id, branch_id, version_id) VALUES ({id}, 1, {id})"),
+            format!("INSERT INTO report (id, uuid, user_id, version_id, testbed_id, adapter, start_time, end_time) VALUES ({id}, 'report-{id}', 1, {id}, 1, 0, {id}, {id})"),
+            format!("INSERT INTO perf (id, uuid, report_id, iteration, benchmark_id) VALUES ({id}, 'perf-{id}', {id}, 0, {BENCHMARK_ID})"),
+            format!("INSERT INTO metric (id, uuid, perf_id, metric_kind_id, value) VALUES ({id}, 'metric-{id}', {id}, 1, {value})"),
+        ] {
+            diesel::sql_query(query).execute(conn).unwrap();
+        }
+    }
+
+    fn metric(value: f64) -> JsonMetric {
+        JsonMetric {
+            value: value.into(),
+            lower_bound: None,
+            upper_bound: None,
+        }
+    }
+
+    #[test]
+    fn test_detect_history() {
+        let mut conn = setup_conn();
+        let detector = Detector::new(&mut conn, 1, 1, 1).unwrap().unwrap();
+
+        store_metric(&mut conn, 1, 100.0);
+        store_metric(&mut conn, 2, 120.0);
+        // The stored datum is left out of its own baseline, so the change is the full 20%
+        let alert_id = detector
+            .detect(&mut conn, 2, BENCHMARK_ID, metric(120.0))
+            .unwrap()
+            .unwrap();
+        let outlier: f32 = schema::alert::table
+            .filter(schema::alert::id.eq(alert_id))
+            .select(schema::alert::outlier)
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(outlier, 20.0);
+
+        // Against a baseline of 100 and 120, a 5% increase is within the limit
+        store_metric(&mut conn, 3, 115.5);
+        assert_eq!(
+            detector
+                .detect(&mut conn, 3, BENCHMARK_ID, metric(115.5))
+                .unwrap(),
+            None
+        );
+    }
+
+    fn alert_status(conn: &mut DbConnection, alert_id: i32) -> i32 {
+        schema::alert::table
+            .filter(schema::alert::id.eq(alert_id))
+            .select(schema::alert::status)
+            .first(conn)
+            .unwrap()
+    }
+
+    fn min_sample_size(conn: &mut DbConnection, min_sample_size: Option<i64>) -> Detector {
+        diesel::update(schema::statistic::table)
+            .set(schema::statistic::min_sample_size.eq(min_sample_size))
+            .execute(conn)
+            .unwrap();
+        Detector::new(conn, 1, 1, 1).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_detect_min_sample_size() {
+        let mut conn = setup_conn();
+        let detector = Detector::new(&mut conn, 1, 1, 1).unwrap().unwrap();
+        store_metric(&mut conn, 1, 100.0);
+        store_metric(&mut conn, 2, 120.0);
+        let alert_id = detector
+            .detect(&mut conn, 2, BENCHMARK_ID, metric(120.0))
+            .unwrap()
+            .unwrap();
+
+        // A datum that is not checked because the min sample size is not met leaves the alert alone
+        let detector = min_sample_size(&mut conn, Some(10));
+        store_metric(&mut conn, 3, 100.0);
+        assert_eq!(
+            detector
+                .detect(&mut conn, 3, BENCHMARK_ID, metric(100.0))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            alert_status(&mut conn, alert_id),
+            AlertStatus::Active as i32
+        );
+
+        // A datum that is checked and inside of the threshold resolves the alert
+        let detector = min_sample_size(&mut conn, None);
+        store_metric(&mut conn, 4, 110.0);
+        assert_eq!(
+            detector
+                .detect(&mut conn, 4, BENCHMARK_ID, metric(110.0))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            alert_status(&mut conn, alert_id),
+            AlertStatus::Resolved as i32
+        );
     }
-    Some(data.iter().sum::<f64>() / data.len() as f64)
 }
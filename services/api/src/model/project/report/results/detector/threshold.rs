@@ -38,6 +38,9 @@ impl MetricsThreshold {
                 schema::statistic::window,
                 schema::statistic::left_side,
                 schema::statistic::right_side,
+                schema::statistic::baseline,
+                schema::statistic::lower_limit,
+                schema::statistic::upper_limit,
             ))
             .first::<(
                 i32,
@@ -49,6 +52,9 @@ impl MetricsThreshold {
                 Option<i64>,
                 Option<f32>,
                 Option<f32>,
+                Option<i32>,
+                Option<f64>,
+                Option<f64>,
             )>(conn)
             .map(
                 |(
@@ -61,6 +67,9 @@ impl MetricsThreshold {
                     window,
                     left_side,
                     right_side,
+                    baseline,
+                    lower_limit,
+                    upper_limit,
                 )| {
                     let statistic = QueryStatistic {
                         id: statistic_id,
@@ -71,6 +80,9 @@ impl MetricsThreshold {
                         window,
                         left_side,
                         right_side,
+                        baseline,
+                        lower_limit,
+                        upper_limit,
                     };
                     Self {
                         id: threshold_id,
@@ -2,11 +2,11 @@ use std::collections::HashMap;
 
 use bencher_adapter::{
     results::{adapter_metrics::AdapterMetrics, MetricKind},
-    AdapterResults, AdapterResultsArray, Settings as AdapterSettings,
+    AdapterCustom, AdapterResults, AdapterResultsArray, Settings as AdapterSettings,
 };
 use bencher_json::{
     project::report::{JsonAdapter, JsonReportSettings},
-    BenchmarkName,
+    BenchmarkName, JsonNewMetricKind, Slug,
 };
 use diesel::RunQueryDsl;
 
@@ -16,7 +16,7 @@ use crate::{
     model::project::{
         benchmark::QueryBenchmark,
         metric::InsertMetric,
-        metric_kind::QueryMetricKind,
+        metric_kind::{InsertMetricKind, QueryMetricKind},
         perf::{InsertPerf, QueryPerf},
     },
     schema, ApiError,
@@ -35,12 +35,27 @@ pub struct ReportResults {
     pub testbed_id: i32,
     pub report_id: i32,
     pub benchmark_cache: HashMap<BenchmarkName, i32>,
-    pub metric_kind_cache: HashMap<MetricKind, i32>,
+    pub metric_kind_cache: HashMap<MetricKind, Option<i32>>,
     pub detector_cache: HashMap<MetricKindId, Option<Detector>>,
+    /// Create any missing custom metric kind the first time that it is reported
+    pub auto_metric_kinds: bool,
+    /// Metric kinds to create the first time that they are reported, keyed by slug
+    pub new_metric_kinds: HashMap<String, JsonNewMetricKind>,
+    /// New active alerts to notify about
+    pub alerts: Vec<i32>,
+    /// Metric kinds that do not exist and could not be created, so their metrics were skipped
+    pub skipped_metric_kinds: Vec<MetricKind>,
 }
 
 impl ReportResults {
-    pub fn new(project_id: i32, branch_id: i32, testbed_id: i32, report_id: i32) -> Self {
+    pub fn new(
+        project_id: i32,
+        branch_id: i32,
+        testbed_id: i32,
+        report_id: i32,
+        auto_metric_kinds: bool,
+        new_metric_kinds: Vec<JsonNewMetricKind>,
+    ) -> Self {
         Self {
             project_id,
             branch_id,
@@ -49,6 +64,19 @@ impl ReportResults {
             benchmark_cache: HashMap::new(),
             metric_kind_cache: HashMap::new(),
             detector_cache: HashMap::new(),
+            auto_metric_kinds,
+            new_metric_kinds: new_metric_kinds
+                .into_iter()
+                .map(|metric_kind| {
+                    let slug = metric_kind.slug.as_ref().map_or_else(
+                        || slug::slugify(metric_kind.name.as_ref()),
+                        ToString::to_string,
+                    );
+                    (slug, metric_kind)
+                })
+                .collect(),
+            alerts: Vec::new(),
+            skipped_metric_kinds: Vec::new(),
         }
     }
 
@@ -61,7 +89,12 @@ impl ReportResults {
         #[cfg(feature = "plus")] usage: &mut u64,
     ) -> Result<(), ApiError> {
         let adapter_settings = AdapterSettings::new(settings.average);
-        let results_array = AdapterResultsArray::new(results_array, adapter, adapter_settings)?;
+        let results_array = if let Some(adapter_config) = &settings.adapter_config {
+            let adapter_custom = AdapterCustom::try_from(adapter_config)?;
+            AdapterResultsArray::new(results_array, &adapter_custom, adapter_settings)?
+        } else {
+            AdapterResultsArray::new(results_array, &adapter, adapter_settings)?
+        };
 
         if let Some(fold) = settings.fold {
             let results = results_array.fold(fold);
@@ -126,7 +159,10 @@ impl ReportResults {
         let perf_id = QueryPerf::get_id(conn, &insert_perf.uuid)?;
 
         for (metric_kind_key, metric) in metrics.inner {
-            let metric_kind_id = self.metric_kind_id(conn, metric_kind_key)?;
+            // Metric kinds that do not exist and can not be created are skipped
+            let Some(metric_kind_id) = self.metric_kind_id(conn, metric_kind_key)? else {
+                continue;
+            };
 
             let insert_metric = InsertMetric::from_json(perf_id, metric_kind_id, metric);
             diesel::insert_into(schema::metric::table)
@@ -143,7 +179,9 @@ impl ReportResults {
             // Ignored benchmarks do not get checked against the threshold even if one exists
             if !ignore_benchmark {
                 if let Some(detector) = self.detector(conn, metric_kind_id)? {
-                    detector.detect(conn, perf_id, benchmark_id, metric)?;
+                    if let Some(alert_id) = detector.detect(conn, perf_id, benchmark_id, metric)? {
+                        self.alerts.push(alert_id);
+                    }
                 }
             }
         }
@@ -163,13 +201,57 @@ impl ReportResults {
         &mut self,
         conn: &mut DbConnection,
         metric_kind_key: MetricKind,
-    ) -> Result<i32, ApiError> {
+    ) -> Result<Option<i32>, ApiError> {
         Ok(
             if let Some(id) = self.metric_kind_cache.get(&metric_kind_key) {
                 *id
             } else {
-                let metric_kind_id =
-                    QueryMetricKind::from_resource_id(conn, self.project_id, &metric_kind_key)?.id;
+                let metric_kind_id = match QueryMetricKind::from_resource_id(
+                    conn,
+                    self.project_id,
+                    &metric_kind_key,
+                ) {
+                    Ok(query_metric_kind) => Some(query_metric_kind.id),
+                    // Any new metric kinds in the report settings and any missing built-in metric kinds
+                    // are created the first time that they are reported.
+                    // If the project allows it, then any other custom metric kind is created as well.
+                    Err(ApiError::Query(diesel::result::Error::NotFound)) => {
+                        let Some(json_metric_kind) = self
+                            .new_metric_kinds
+                            .remove(metric_kind_key.as_ref())
+                            .or_else(|| JsonNewMetricKind::builtin(metric_kind_key.as_ref()))
+                            .or_else(|| {
+                                self.auto_metric_kinds
+                                    .then(|| JsonNewMetricKind::from_slug(metric_kind_key.as_ref()))
+                                    .flatten()
+                            }) else {
+                            self.skipped_metric_kinds.push(metric_kind_key.clone());
+                            self.metric_kind_cache.insert(metric_kind_key, None);
+                            return Ok(None);
+                        };
+                        // The metric kind is always stored with the slug that it was reported with,
+                        // so that it is found by the next report
+                        let insert_metric_kind = InsertMetricKind::from_json_inner(
+                            conn,
+                            self.project_id,
+                            JsonNewMetricKind {
+                                slug: Some(
+                                    metric_kind_key
+                                        .as_ref()
+                                        .parse::<Slug>()
+                                        .map_err(api_error!())?,
+                                ),
+                                ..json_metric_kind
+                            },
+                        );
+                        diesel::insert_into(schema::metric_kind::table)
+                            .values(&insert_metric_kind)
+                            .execute(conn)
+                            .map_err(api_error!())?;
+                        Some(QueryMetricKind::get_id(conn, &insert_metric_kind.uuid)?)
+                    },
+                    Err(e) => return Err(e),
+                };
 
                 self.metric_kind_cache
                     .insert(metric_kind_key, metric_kind_id);
@@ -195,3 +277,147 @@ impl ReportResults {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use bencher_json::JsonNewMetricKind;
+    use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
+
+    use crate::{config::config_tx::run_migrations, context::DbConnection, schema};
+
+    use super::ReportResults;
+
+    const PROJECT_ID: i32 = 1;
+
+    fn setup_conn() -> DbConnection {
+        let mut conn = DbConnection::establish(":memory:").unwrap();
+        run_migrations(&mut conn).unwrap();
+        diesel::sql_query(
+            "INSERT INTO organization (id, uuid, name, slug) VALUES (1, 'org-uuid', 'Org', 'org')",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        diesel::sql_query(
+            "INSERT INTO project (id, uuid, organization_id, name, slug, visibility) VALUES (1, 'project-uuid', 1, 'Project', 'project', 0)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        conn
+    }
+
+    fn report_results(
+        auto_metric_kinds: bool,
+        new_metric_kinds: Vec<JsonNewMetricKind>,
+    ) -> ReportResults {
+        ReportResults::new(PROJECT_ID, 1, 1, 1, auto_metric_kinds, new_metric_kinds)
+    }
+
+    fn stored_slug(conn: &mut DbConnection, id: i32) -> String {
+        schema::metric_kind::table
+            .filter(schema::metric_kind::id.eq(id))
+            .select(schema::metric_kind::slug)
+            .first(conn)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_metric_kind_builtin() {
+        let mut conn = setup_conn();
+        // Built-in metric kinds are created without the project switch
+        for slug in [
+            "bytes",
+            "allocations",
+            "instructions",
+            "l1-accesses",
+            "l2-accesses",
+            "ram-accesses",
+            "estimated-cycles",
+        ] {
+            let id = report_results(false, Vec::new())
+                .metric_kind_id(&mut conn, slug.parse().unwrap())
+                .unwrap()
+                .unwrap();
+            assert_eq!(stored_slug(&mut conn, id), slug);
+        }
+    }
+
+    #[test]
+    fn test_metric_kind_unknown() {
+        let mut conn = setup_conn();
+        let mut skipped = report_results(false, Vec::new());
+        assert_eq!(
+            skipped
+                .metric_kind_id(&mut conn, "widgets".parse().unwrap())
+                .unwrap(),
+            None
+        );
+        // The skipped metric kind is only recorded once
+        assert_eq!(
+            skipped
+                .metric_kind_id(&mut conn, "widgets".parse().unwrap())
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            skipped.skipped_metric_kinds,
+            vec!["widgets".parse().unwrap()]
+        );
+
+        let id = report_results(true, Vec::new())
+            .metric_kind_id(&mut conn, "widgets".parse().unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored_slug(&mut conn, id), "widgets");
+
+        // Once created, the metric kind is found without the project switch
+        assert_eq!(
+            report_results(false, Vec::new())
+                .metric_kind_id(&mut conn, "widgets".parse().unwrap())
+                .unwrap(),
+            Some(id)
+        );
+    }
+
+    #[test]
+    fn test_metric_kind_declared() {
+        let mut conn = setup_conn();
+        // An existing metric kind whose slug the declared name also slugifies to
+        let id = report_results(true, Vec::new())
+            .metric_kind_id(&mut conn, "widgets".parse().unwrap())
+            .unwrap()
+            .unwrap();
+        let json_metric_kind = JsonNewMetricKind {
+            name: "Widgets".parse().unwrap(),
+            slug: Some("widgets-per-op".parse().unwrap()),
+            units: "widgets".parse().unwrap(),
+        };
+
+        let declared_id = report_results(false, vec![json_metric_kind])
+            .metric_kind_id(&mut conn, "widgets-per-op".parse().unwrap())
+            .unwrap()
+            .unwrap();
+        // The declared metric kind is stored with the reported slug, not a deduplicated one
+        assert_ne!(id, declared_id);
+        assert_eq!(stored_slug(&mut conn, declared_id), "widgets-per-op");
+
+        // The next report finds the stored metric kind without declaring it again
+        let next_id = report_results(false, Vec::new())
+            .metric_kind_id(&mut conn, "widgets-per-op".parse().unwrap())
+            .unwrap();
+        assert_eq!(Some(declared_id), next_id);
+    }
+
+    #[test]
+    fn test_metric_kind_query_error() {
+        let mut conn = setup_conn();
+        diesel::sql_query("DROP TABLE metric_kind")
+            .execute(&mut conn)
+            .unwrap();
+        // A database error is not mistaken for a missing metric kind
+        let mut results = report_results(true, Vec::new());
+        assert!(results
+            .metric_kind_id(&mut conn, "bytes".parse().unwrap())
+            .is_err());
+        assert!(results.skipped_metric_kinds.is_empty());
+    }
+}
@@ -1,13 +1,27 @@
 use std::str::FromStr;
 
-use bencher_json::project::alert::{JsonAlert, JsonSide};
+use bencher_json::project::{
+    alert::{JsonAlert, JsonAlertStatus, JsonSide},
+    threshold::JsonStatisticKind,
+};
 use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
 use uuid::Uuid;
 
-use super::{statistic::QueryStatistic, QueryThreshold};
+use super::{
+    statistic::{QueryStatistic, StatisticKind},
+    QueryThreshold,
+};
 use crate::{
-    context::DbConnection, error::api_error, model::project::perf::QueryPerf, schema,
-    schema::alert as alert_table, util::query::fn_get_id, ApiError,
+    context::DbConnection,
+    error::api_error,
+    model::{
+        project::{perf::QueryPerf, report::to_date_time},
+        user::QueryUser,
+    },
+    schema,
+    schema::alert as alert_table,
+    util::query::fn_get_id,
+    ApiError,
 };
 
 #[derive(Queryable)]
@@ -20,6 +34,9 @@ pub struct QueryAlert {
     pub side: bool,
     pub boundary: f32,
     pub outlier: f32,
+    pub status: i32,
+    pub status_user_id: Option<i32>,
+    pub status_time: Option<i64>,
 }
 
 impl QueryAlert {
@@ -43,16 +60,29 @@ impl QueryAlert {
             side,
             boundary,
             outlier,
+            status,
+            status_user_id,
+            status_time,
             ..
         } = self;
+        let query_statistic = schema::statistic::table
+            .filter(schema::statistic::id.eq(statistic_id))
+            .first::<QueryStatistic>(conn)
+            .map_err(api_error!())?;
         Ok(JsonAlert {
             uuid: Uuid::from_str(&uuid).map_err(api_error!())?,
             perf: QueryPerf::get_uuid(conn, perf_id)?,
             threshold: QueryThreshold::get_uuid(conn, threshold_id)?,
-            statistic: QueryStatistic::get_uuid(conn, statistic_id)?,
+            statistic: Uuid::from_str(&query_statistic.uuid).map_err(api_error!())?,
             side: Side::from(side).into(),
             boundary: boundary.into(),
             outlier: outlier.into(),
+            units: JsonStatisticKind::from(StatisticKind::try_from(query_statistic.test)?).into(),
+            status: AlertStatus::try_from(status)?.into(),
+            status_user: status_user_id
+                .map(|id| QueryUser::get_uuid(conn, id))
+                .transpose()?,
+            status_time: status_time.map(to_date_time).transpose()?,
         })
     }
 }
@@ -81,6 +111,15 @@ impl From<Side> for bool {
     }
 }
 
+impl From<JsonSide> for Side {
+    fn from(side: JsonSide) -> Self {
+        match side {
+            JsonSide::Left => Self::Left,
+            JsonSide::Right => Self::Right,
+        }
+    }
+}
+
 impl From<Side> for JsonSide {
     fn from(side: Side) -> Self {
         match side {
@@ -90,6 +129,51 @@ impl From<Side> for JsonSide {
     }
 }
 
+#[derive(Clone, Copy, Default)]
+pub enum AlertStatus {
+    #[default]
+    Active = 0,
+    Acknowledged = 1,
+    Dismissed = 2,
+    Resolved = 3,
+}
+
+impl TryFrom<i32> for AlertStatus {
+    type Error = ApiError;
+
+    fn try_from(status: i32) -> Result<Self, Self::Error> {
+        match status {
+            0 => Ok(Self::Active),
+            1 => Ok(Self::Acknowledged),
+            2 => Ok(Self::Dismissed),
+            3 => Ok(Self::Resolved),
+            _ => Err(ApiError::AlertStatus(status)),
+        }
+    }
+}
+
+impl From<JsonAlertStatus> for AlertStatus {
+    fn from(status: JsonAlertStatus) -> Self {
+        match status {
+            JsonAlertStatus::Active => Self::Active,
+            JsonAlertStatus::Acknowledged => Self::Acknowledged,
+            JsonAlertStatus::Dismissed => Self::Dismissed,
+            JsonAlertStatus::Resolved => Self::Resolved,
+        }
+    }
+}
+
+impl From<AlertStatus> for JsonAlertStatus {
+    fn from(status: AlertStatus) -> Self {
+        match status {
+            AlertStatus::Active => Self::Active,
+            AlertStatus::Acknowledged => Self::Acknowledged,
+            AlertStatus::Dismissed => Self::Dismissed,
+            AlertStatus::Resolved => Self::Resolved,
+        }
+    }
+}
+
 #[derive(Insertable)]
 #[diesel(table_name = alert_table)]
 pub struct InsertAlert {
@@ -100,4 +184,7 @@ pub struct InsertAlert {
     pub side: bool,
     pub boundary: f32,
     pub outlier: f32,
+    pub status: i32,
+    pub status_user_id: Option<i32>,
+    pub status_time: Option<i64>,
 }
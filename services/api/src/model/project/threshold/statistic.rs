@@ -1,7 +1,11 @@
 use std::str::FromStr;
 
-use bencher_json::project::threshold::{JsonNewStatistic, JsonStatistic, JsonStatisticKind};
+use bencher_json::project::{
+    report::JsonAverage,
+    threshold::{JsonNewStatistic, JsonStatistic, JsonStatisticKind},
+};
 use diesel::{ExpressionMethods, Insertable, QueryDsl, RunQueryDsl};
+use ordered_float::OrderedFloat;
 use uuid::Uuid;
 
 use crate::{
@@ -19,6 +23,9 @@ pub struct QueryStatistic {
     pub window: Option<i64>,
     pub left_side: Option<f32>,
     pub right_side: Option<f32>,
+    pub baseline: Option<i32>,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
 }
 
 impl QueryStatistic {
@@ -43,6 +50,9 @@ impl QueryStatistic {
             window,
             left_side,
             right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
             ..
         } = self;
         Ok(JsonStatistic {
@@ -53,6 +63,12 @@ impl QueryStatistic {
             window: window.map(|w| w as u32),
             left_side: left_side.map(Into::into),
             right_side: right_side.map(Into::into),
+            baseline: baseline
+                .map(Baseline::try_from)
+                .transpose()?
+                .map(Into::into),
+            lower_limit: lower_limit.map(Into::into),
+            upper_limit: upper_limit.map(Into::into),
         })
     }
 }
@@ -61,6 +77,10 @@ impl QueryStatistic {
 pub enum StatisticKind {
     Z = 0,
     T = 1,
+    Percentage = 2,
+    Absolute = 3,
+    Mad = 4,
+    Iqr = 5,
 }
 
 impl TryFrom<i32> for StatisticKind {
@@ -70,6 +90,10 @@ impl TryFrom<i32> for StatisticKind {
         match kind {
             0 => Ok(Self::Z),
             1 => Ok(Self::T),
+            2 => Ok(Self::Percentage),
+            3 => Ok(Self::Absolute),
+            4 => Ok(Self::Mad),
+            5 => Ok(Self::Iqr),
             _ => Err(ApiError::StatisticKind(kind)),
         }
     }
@@ -80,6 +104,10 @@ impl From<JsonStatisticKind> for StatisticKind {
         match kind {
             JsonStatisticKind::Z => Self::Z,
             JsonStatisticKind::T => Self::T,
+            JsonStatisticKind::Percentage => Self::Percentage,
+            JsonStatisticKind::Absolute => Self::Absolute,
+            JsonStatisticKind::Mad => Self::Mad,
+            JsonStatisticKind::Iqr => Self::Iqr,
         }
     }
 }
@@ -89,6 +117,47 @@ impl From<StatisticKind> for JsonStatisticKind {
         match kind {
             StatisticKind::Z => Self::Z,
             StatisticKind::T => Self::T,
+            StatisticKind::Percentage => Self::Percentage,
+            StatisticKind::Absolute => Self::Absolute,
+            StatisticKind::Mad => Self::Mad,
+            StatisticKind::Iqr => Self::Iqr,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum Baseline {
+    #[default]
+    Mean = 0,
+    Median = 1,
+}
+
+impl TryFrom<i32> for Baseline {
+    type Error = ApiError;
+
+    fn try_from(baseline: i32) -> Result<Self, Self::Error> {
+        match baseline {
+            0 => Ok(Self::Mean),
+            1 => Ok(Self::Median),
+            _ => Err(ApiError::StatisticBaseline(baseline)),
+        }
+    }
+}
+
+impl From<JsonAverage> for Baseline {
+    fn from(average: JsonAverage) -> Self {
+        match average {
+            JsonAverage::Mean => Self::Mean,
+            JsonAverage::Median => Self::Median,
+        }
+    }
+}
+
+impl From<Baseline> for JsonAverage {
+    fn from(baseline: Baseline) -> Self {
+        match baseline {
+            Baseline::Mean => Self::Mean,
+            Baseline::Median => Self::Median,
         }
     }
 }
@@ -103,6 +172,9 @@ pub struct InsertStatistic {
     pub window: Option<i64>,
     pub left_side: Option<f32>,
     pub right_side: Option<f32>,
+    pub baseline: Option<i32>,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
 }
 
 impl From<QueryStatistic> for InsertStatistic {
@@ -114,6 +186,9 @@ impl From<QueryStatistic> for InsertStatistic {
             window,
             left_side,
             right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
             ..
         } = query_statistic;
         Self {
@@ -124,6 +199,9 @@ impl From<QueryStatistic> for InsertStatistic {
             window,
             left_side,
             right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
         }
     }
 }
@@ -137,7 +215,18 @@ impl InsertStatistic {
             window,
             left_side,
             right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
         } = json_statistic;
+        if matches!(
+            test,
+            JsonStatisticKind::Percentage | JsonStatisticKind::Absolute
+        ) && lower_limit.is_none()
+            && upper_limit.is_none()
+        {
+            return Err(ApiError::StatisticLimitMissing);
+        }
         Ok(Self {
             uuid: Uuid::new_v4().to_string(),
             test: StatisticKind::from(test) as i32,
@@ -146,6 +235,18 @@ impl InsertStatistic {
             window: window.map(Into::into),
             left_side: left_side.map(Into::into),
             right_side: right_side.map(Into::into),
+            baseline: baseline.map(|b| Baseline::from(b) as i32),
+            lower_limit: validate_limit(lower_limit)?,
+            upper_limit: validate_limit(upper_limit)?,
         })
     }
 }
+
+fn validate_limit(limit: Option<OrderedFloat<f64>>) -> Result<Option<f64>, ApiError> {
+    match limit.map(OrderedFloat::into_inner) {
+        Some(limit) if !limit.is_finite() || limit.is_sign_negative() => {
+            Err(ApiError::StatisticLimit(limit))
+        },
+        limit => Ok(limit),
+    }
+}
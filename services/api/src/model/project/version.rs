@@ -38,6 +38,16 @@ impl QueryVersion {
     }
 }
 
+/// An abbreviated git hash is matched as a prefix of the full hash,
+/// so return it as a `LIKE` pattern.
+pub fn hash_prefix(hash: &str) -> Result<String, ApiError> {
+    if !hash.is_empty() && hash.len() <= 40 && hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(format!("{hash}%"))
+    } else {
+        Err(ApiError::GitHashPrefix(hash.into()))
+    }
+}
+
 #[derive(Insertable)]
 #[diesel(table_name = version_table)]
 pub struct InsertVersion {
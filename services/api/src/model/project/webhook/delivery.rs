@@ -0,0 +1,206 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use bencher_json::project::webhook::{JsonWebhookDelivery, JsonWebhookEvent};
+use chrono::Utc;
+use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use super::{QueryWebhook, WebhookEvent};
+use crate::{
+    context::{DbConnection, Webhook},
+    error::api_error,
+    model::project::report::to_date_time,
+    schema,
+    schema::webhook_delivery as webhook_delivery_table,
+    ApiError,
+};
+
+#[derive(Queryable)]
+pub struct QueryWebhookDelivery {
+    pub id: i32,
+    pub uuid: String,
+    pub webhook_id: i32,
+    pub event: i32,
+    pub payload: String,
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub success: bool,
+    pub created: i64,
+    pub modified: i64,
+    pub next_attempt: Option<i64>,
+}
+
+impl QueryWebhookDelivery {
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonWebhookDelivery, ApiError> {
+        let Self {
+            uuid,
+            webhook_id,
+            event,
+            payload,
+            attempts,
+            status_code,
+            error,
+            success,
+            created,
+            modified,
+            ..
+        } = self;
+        Ok(JsonWebhookDelivery {
+            uuid: Uuid::from_str(&uuid).map_err(api_error!())?,
+            webhook: QueryWebhook::get_uuid(conn, webhook_id)?,
+            event: WebhookEvent::try_from(event)?.into(),
+            payload: serde_json::from_str(&payload)?,
+            attempts: u32::try_from(attempts).map_err(api_error!())?,
+            status_code: status_code
+                .map(u16::try_from)
+                .transpose()
+                .map_err(api_error!())?,
+            error,
+            success,
+            created: to_date_time(created)?,
+            modified: to_date_time(modified)?,
+        })
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = webhook_delivery_table)]
+pub struct InsertWebhookDelivery {
+    pub uuid: String,
+    pub webhook_id: i32,
+    pub event: i32,
+    pub payload: String,
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub success: bool,
+    pub created: i64,
+    pub modified: i64,
+    pub next_attempt: Option<i64>,
+}
+
+/// How often to check the delivery log for pending deliveries
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// The max number of pending deliveries to attempt at once
+const BATCH_SIZE: i64 = 16;
+
+/// Attempt pending deliveries in the background until they succeed or run out of attempts.
+/// The delivery log is the queue, so pending deliveries are retried even after a server restart.
+/// The worker stops once the server drops its database connection.
+pub fn spawn_worker(connection: &Arc<Mutex<DbConnection>>, allow_private_destinations: bool) {
+    let connection = Arc::downgrade(connection);
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            let Some(connection) = connection.upgrade() else {
+                return;
+            };
+            if let Err(e) = attempt_pending(&connection, allow_private_destinations).await {
+                tracing::error!("Failed to attempt pending webhook deliveries: {e}");
+            }
+        }
+    });
+}
+
+async fn attempt_pending(
+    connection: &Arc<Mutex<DbConnection>>,
+    allow_private_destinations: bool,
+) -> Result<(), ApiError> {
+    let now = Utc::now().timestamp_nanos();
+    let pending = {
+        let conn = &mut *connection.lock().await;
+        let pending = schema::webhook_delivery::table
+            .inner_join(schema::webhook::table)
+            .filter(schema::webhook_delivery::next_attempt.le(now))
+            .order(schema::webhook_delivery::next_attempt.asc())
+            .limit(BATCH_SIZE)
+            .load::<(QueryWebhookDelivery, QueryWebhook)>(conn)
+            .map_err(api_error!())?;
+        // Claim the pending deliveries until well after they would time out,
+        // so that they are neither attempted twice at once nor lost if the server stops mid-attempt.
+        let claimed = pending.iter().map(|(delivery, _)| delivery.id);
+        diesel::update(
+            schema::webhook_delivery::table.filter(schema::webhook_delivery::id.eq_any(claimed)),
+        )
+        .set(schema::webhook_delivery::next_attempt.eq(now + nanos(Webhook::TIMEOUT * 2)))
+        .execute(conn)
+        .map_err(api_error!())?;
+        pending
+    };
+
+    for (query_delivery, query_webhook) in pending {
+        let webhook = match query_webhook.webhook(allow_private_destinations) {
+            Ok(webhook) => webhook,
+            Err(e) => {
+                tracing::error!("Failed to deliver webhook {}: {e}", query_delivery.uuid);
+                continue;
+            },
+        };
+        let connection = connection.clone();
+        tokio::spawn(async move { attempt(&connection, webhook, query_delivery).await });
+    }
+
+    Ok(())
+}
+
+/// Make the next attempt of the delivery, recording its outcome in the delivery log
+/// and scheduling another attempt with backoff if it failed.
+async fn attempt(
+    connection: &Mutex<DbConnection>,
+    webhook: Webhook,
+    query_delivery: QueryWebhookDelivery,
+) {
+    let QueryWebhookDelivery {
+        uuid,
+        event,
+        payload,
+        attempts,
+        ..
+    } = query_delivery;
+    let event = match WebhookEvent::try_from(event) {
+        Ok(event) => JsonWebhookEvent::from(event).to_string(),
+        Err(e) => {
+            tracing::error!("Failed to deliver webhook {uuid}: {e}");
+            return;
+        },
+    };
+    let attempt = u32::try_from(attempts)
+        .unwrap_or_default()
+        .saturating_add(1);
+
+    let (status_code, error, success) = match webhook.post(&event, Some(&uuid), &payload).await {
+        Ok(status) => (Some(i32::from(status.as_u16())), None, status.is_success()),
+        Err(e) => (None, Some(e.to_string()), false),
+    };
+    if !success {
+        tracing::error!(
+            "Failed to deliver webhook {event} to {} (attempt {attempt})",
+            webhook.url
+        );
+    }
+
+    let now = Utc::now().timestamp_nanos();
+    let next_attempt = (!success && attempt < Webhook::MAX_ATTEMPTS)
+        .then(|| now + nanos(Webhook::backoff(attempt)));
+    let logged = diesel::update(
+        schema::webhook_delivery::table.filter(schema::webhook_delivery::uuid.eq(&uuid)),
+    )
+    .set((
+        schema::webhook_delivery::attempts.eq(i32::try_from(attempt).unwrap_or(i32::MAX)),
+        schema::webhook_delivery::status_code.eq(status_code),
+        schema::webhook_delivery::error.eq(error),
+        schema::webhook_delivery::success.eq(success),
+        schema::webhook_delivery::modified.eq(now),
+        schema::webhook_delivery::next_attempt.eq(next_attempt),
+    ))
+    .execute(&mut *connection.lock().await);
+    if let Err(e) = logged {
+        tracing::error!("Failed to log webhook delivery {uuid}: {e}");
+    }
+}
+
+fn nanos(duration: Duration) -> i64 {
+    i64::try_from(duration.as_nanos()).unwrap_or(i64::MAX)
+}
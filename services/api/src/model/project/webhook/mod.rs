@@ -0,0 +1,210 @@
+use std::str::FromStr;
+
+use bencher_json::{project::webhook::JsonWebhookEvent, JsonNewWebhook, JsonWebhook, Secret, Url};
+use chrono::Utc;
+use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
+use serde::Serialize;
+use uuid::Uuid;
+
+use self::delivery::InsertWebhookDelivery;
+use super::QueryProject;
+use crate::{
+    context::{DbConnection, Webhook},
+    error::api_error,
+    schema,
+    schema::webhook as webhook_table,
+    util::query::fn_get_id,
+    ApiError,
+};
+
+pub mod delivery;
+
+#[derive(Queryable)]
+pub struct QueryWebhook {
+    pub id: i32,
+    pub uuid: String,
+    pub project_id: i32,
+    pub url: String,
+    pub secret: Option<String>,
+    pub events: i32,
+}
+
+impl QueryWebhook {
+    fn_get_id!(webhook);
+
+    pub fn get_uuid(conn: &mut DbConnection, id: i32) -> Result<Uuid, ApiError> {
+        let uuid: String = schema::webhook::table
+            .filter(schema::webhook::id.eq(id))
+            .select(schema::webhook::uuid)
+            .first(conn)
+            .map_err(api_error!())?;
+        Uuid::from_str(&uuid).map_err(api_error!())
+    }
+
+    pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonWebhook, ApiError> {
+        let Self {
+            uuid,
+            project_id,
+            url,
+            secret,
+            events,
+            ..
+        } = self;
+        Ok(JsonWebhook {
+            uuid: Uuid::from_str(&uuid).map_err(api_error!())?,
+            project: QueryProject::get_uuid(conn, project_id)?,
+            url: Url::from_str(&url)?,
+            signed: secret.is_some(),
+            events: WebhookEvent::from_bits(events),
+        })
+    }
+
+    pub fn webhook(&self, allow_private_destinations: bool) -> Result<Webhook, ApiError> {
+        Ok(Webhook {
+            url: self.url.clone(),
+            secret: self.secret.as_deref().map(Secret::from_str).transpose()?,
+            allow_private_destinations,
+        })
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = webhook_table)]
+pub struct InsertWebhook {
+    pub uuid: String,
+    pub project_id: i32,
+    pub url: String,
+    pub secret: Option<String>,
+    pub events: i32,
+}
+
+impl InsertWebhook {
+    pub fn from_json(project_id: i32, webhook: JsonNewWebhook) -> Self {
+        let JsonNewWebhook {
+            url,
+            secret,
+            events,
+        } = webhook;
+        Self {
+            uuid: Uuid::new_v4().to_string(),
+            project_id,
+            url: url.into(),
+            secret: secret.map(Into::into),
+            events: events
+                .as_deref()
+                .map_or(WebhookEvent::ALL, WebhookEvent::to_bits),
+        }
+    }
+}
+
+/// Webhook events are stored as bit flags
+#[derive(Clone, Copy)]
+pub enum WebhookEvent {
+    Report = 1,
+    Branch = 2,
+    Threshold = 4,
+    Alert = 8,
+}
+
+impl WebhookEvent {
+    const ALL: i32 =
+        Self::Report as i32 | Self::Branch as i32 | Self::Threshold as i32 | Self::Alert as i32;
+    const EVENTS: [Self; 4] = [Self::Report, Self::Branch, Self::Threshold, Self::Alert];
+
+    pub fn to_bits(events: &[JsonWebhookEvent]) -> i32 {
+        events
+            .iter()
+            .fold(0, |bits, event| bits | Self::from(*event) as i32)
+    }
+
+    fn from_bits(bits: i32) -> Vec<JsonWebhookEvent> {
+        Self::EVENTS
+            .into_iter()
+            .filter(|event| bits & *event as i32 != 0)
+            .map(Into::into)
+            .collect()
+    }
+}
+
+impl TryFrom<i32> for WebhookEvent {
+    type Error = ApiError;
+
+    fn try_from(event: i32) -> Result<Self, Self::Error> {
+        match event {
+            1 => Ok(Self::Report),
+            2 => Ok(Self::Branch),
+            4 => Ok(Self::Threshold),
+            8 => Ok(Self::Alert),
+            _ => Err(ApiError::WebhookEvent(event)),
+        }
+    }
+}
+
+impl From<JsonWebhookEvent> for WebhookEvent {
+    fn from(event: JsonWebhookEvent) -> Self {
+        match event {
+            JsonWebhookEvent::Report => Self::Report,
+            JsonWebhookEvent::Branch => Self::Branch,
+            JsonWebhookEvent::Threshold => Self::Threshold,
+            JsonWebhookEvent::Alert => Self::Alert,
+        }
+    }
+}
+
+impl From<WebhookEvent> for JsonWebhookEvent {
+    fn from(event: WebhookEvent) -> Self {
+        match event {
+            WebhookEvent::Report => Self::Report,
+            WebhookEvent::Branch => Self::Branch,
+            WebhookEvent::Threshold => Self::Threshold,
+            WebhookEvent::Alert => Self::Alert,
+        }
+    }
+}
+
+/// Queue the event for every project webhook subscribed to it.
+/// Each delivery is attempted and retried with backoff from the delivery log in the background.
+pub fn dispatch<T>(
+    conn: &mut DbConnection,
+    project_id: i32,
+    event: WebhookEvent,
+    json: &T,
+) -> Result<(), ApiError>
+where
+    T: Serialize,
+{
+    let webhooks = schema::webhook::table
+        .filter(schema::webhook::project_id.eq(project_id))
+        .load::<QueryWebhook>(conn)
+        .map_err(api_error!())?
+        .into_iter()
+        .filter(|webhook| webhook.events & event as i32 != 0)
+        .collect::<Vec<_>>();
+    if webhooks.is_empty() {
+        return Ok(());
+    }
+
+    let payload = serde_json::to_string(json)?;
+    let now = Utc::now().timestamp_nanos();
+    for query_webhook in webhooks {
+        let insert_delivery = InsertWebhookDelivery {
+            uuid: Uuid::new_v4().to_string(),
+            webhook_id: query_webhook.id,
+            event: event as i32,
+            payload: payload.clone(),
+            attempts: 0,
+            status_code: None,
+            error: None,
+            success: false,
+            created: now,
+            modified: now,
+            next_attempt: Some(now),
+        };
+        diesel::insert_into(schema::webhook_delivery::table)
+            .values(&insert_delivery)
+            .execute(conn)
+            .map_err(api_error!())?;
+    }
+
+    Ok(())
+}
@@ -1,13 +1,20 @@
+use std::str::FromStr;
+
 use bencher_rbac::{
-    user::{OrganizationRoles, ProjectRoles},
+    user::{OrganizationRoles, ProjectCustomRoles, ProjectRoles, ProjectScopes},
     Organization, Project, Server, User as RbacUser,
 };
 
-use bencher_json::Jwt;
-use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
+use bencher_json::{organization::role::JsonRolePermission, user::token::JsonTokenScope, Jwt};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, JoinOnDsl, NullableExpressionMethods,
+    OptionalExtension, QueryDsl, RunQueryDsl,
+};
 use dropshot::RequestContext;
 use oso::{PolarValue, ToPolar};
 
+use uuid::Uuid;
+
 use crate::{
     context::{ApiContext, DbConnection, Rbac},
     schema,
@@ -104,13 +111,32 @@ impl AuthUser {
             return Err(ApiError::Locked(user_id, token_data.claims.email().into()));
         }
 
+        // Any JWT that is not an API token in the token table is unscoped and can not be revoked
+        let (scopes, revoked) = schema::token::table
+            .filter(schema::token::user_id.eq(user_id))
+            .filter(schema::token::jwt.eq(jwt.as_ref()))
+            .select((schema::token::scopes, schema::token::revoked))
+            .first::<(Option<String>, Option<i64>)>(conn)
+            .optional()
+            .map_err(map_auth_header_error!(INVALID_JWT))?
+            .unwrap_or_default();
+        if revoked.is_some() {
+            return Err(ApiError::RevokedToken(user_id));
+        }
+
         let (org_ids, org_roles) = Self::organization_roles(conn, user_id)?;
-        let (proj_ids, proj_roles) = Self::project_roles(conn, user_id)?;
+        let (proj_ids, proj_roles, custom_roles) = Self::project_roles(conn, user_id)?;
         let rbac = RbacUser {
             admin,
             locked,
             organizations: org_roles,
             projects: proj_roles,
+            custom_roles,
+            scoped: scopes.is_some(),
+            scopes: scopes
+                .map(|scopes| Self::project_scopes(conn, &scopes))
+                .transpose()?
+                .unwrap_or_default(),
         };
 
         Ok(Self {
@@ -156,40 +182,95 @@ impl AuthUser {
     fn project_roles(
         conn: &mut DbConnection,
         user_id: i32,
-    ) -> Result<(Vec<ProjectId>, ProjectRoles), ApiError> {
+    ) -> Result<(Vec<ProjectId>, ProjectRoles, ProjectCustomRoles), ApiError> {
+        // Any role that is not built-in is a custom role defined by the project's organization
         let roles = schema::project_role::table
             .filter(schema::project_role::user_id.eq(user_id))
             .inner_join(
                 schema::project::table.on(schema::project_role::project_id.eq(schema::project::id)),
             )
+            .left_join(
+                schema::custom_role::table.on(schema::custom_role::organization_id
+                    .eq(schema::project::organization_id)
+                    .and(schema::custom_role::name.eq(schema::project_role::role))),
+            )
             .order(schema::project_role::project_id)
             .select((
                 schema::project::organization_id,
                 schema::project_role::project_id,
                 schema::project_role::role,
+                schema::custom_role::permissions.nullable(),
             ))
-            .load::<(i32, i32, String)>(conn)
+            .load::<(i32, i32, String, Option<String>)>(conn)
             .map_err(map_auth_header_error!(INVALID_JWT))?;
 
         let ids = roles
             .iter()
-            .map(|(org_id, id, _)| ProjectId {
+            .map(|(org_id, id, _, _)| ProjectId {
                 id: *id,
                 organization_id: *org_id,
             })
             .collect();
-        let roles = roles
+        let mut project_roles = ProjectRoles::new();
+        let mut custom_roles = ProjectCustomRoles::new();
+        for (_, id, role, permissions) in roles {
+            if let Some(permissions) = permissions {
+                match serde_json::from_str::<Vec<JsonRolePermission>>(&permissions) {
+                    Ok(permissions) => {
+                        custom_roles.insert(
+                            id.to_string(),
+                            permissions.into_iter().map(Into::into).collect(),
+                        );
+                    },
+                    Err(e) => debug_error!("Failed to parse custom role \"{role}\": {e}"),
+                }
+            } else {
+                match role.parse() {
+                    Ok(role) => {
+                        project_roles.insert(id.to_string(), role);
+                    },
+                    Err(e) => debug_error!("Failed to parse project role \"{role}\": {e}"),
+                }
+            }
+        }
+
+        Ok((ids, project_roles, custom_roles))
+    }
+
+    fn project_scopes(conn: &mut DbConnection, scopes: &str) -> Result<ProjectScopes, ApiError> {
+        let scopes: Vec<JsonTokenScope> =
+            serde_json::from_str(scopes).map_err(map_auth_header_error!(INVALID_JWT))?;
+
+        Ok(scopes
             .into_iter()
-            .filter_map(|(_, id, role)| match role.parse() {
-                Ok(role) => Some((id.to_string(), role)),
-                Err(e) => {
-                    debug_error!("Failed to parse project role \"{role}\": {e}");
-                    None
+            .filter_map(
+                |JsonTokenScope {
+                     project,
+                     permissions,
+                 }| {
+                    // The scope project is always stored as a UUID
+                    let id = Uuid::from_str(project.as_ref())
+                        .map_err(ApiError::from)
+                        .and_then(|uuid| {
+                            schema::project::table
+                                .filter(schema::project::uuid.eq(uuid.to_string()))
+                                .select(schema::project::id)
+                                .first::<i32>(conn)
+                                .map_err(ApiError::from)
+                        });
+                    match id {
+                        Ok(id) => Some((
+                            id.to_string(),
+                            permissions.into_iter().map(Into::into).collect(),
+                        )),
+                        Err(e) => {
+                            debug_error!("Failed to find token scope project \"{project}\": {e}");
+                            None
+                        },
+                    }
                 },
-            })
-            .collect();
-
-        Ok((ids, roles))
+            )
+            .collect())
     }
 
     pub fn is_admin(&self, rbac: &Rbac) -> bool {
@@ -200,6 +281,10 @@ impl AuthUser {
         )
     }
 
+    pub fn is_scoped(&self) -> bool {
+        self.rbac.scoped
+    }
+
     pub fn organizations(
         &self,
         rbac: &Rbac,
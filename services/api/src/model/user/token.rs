@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
-use bencher_json::{JsonNewToken, JsonToken, Jwt, NonEmpty, ResourceId};
+use bencher_json::{
+    user::token::JsonTokenScope, JsonNewToken, JsonToken, Jwt, NonEmpty, ResourceId,
+};
 use chrono::{DateTime, TimeZone, Utc};
 use diesel::{ExpressionMethods, Insertable, QueryDsl, Queryable, RunQueryDsl};
 use uuid::Uuid;
@@ -8,6 +10,10 @@ use uuid::Uuid;
 use crate::{
     context::{DbConnection, Rbac, SecretKey},
     error::api_error,
+    model::{
+        organization::{service_account::QueryServiceAccount, QueryOrganization},
+        project::QueryProject,
+    },
     schema,
     schema::token as token_table,
     util::query::fn_get_id,
@@ -18,6 +24,10 @@ use super::{auth::AuthUser, QueryUser};
 
 macro_rules! same_user {
     ($auth_user:ident, $rbac:expr, $user_id:expr) => {
+        // A scoped API token can only act on its projects, never on behalf of its user
+        if $auth_user.is_scoped() {
+            return Err(crate::error::ApiError::ScopedToken($auth_user.id));
+        }
         if !($auth_user.is_admin(&$rbac) || $auth_user.id == $user_id) {
             return Err(crate::error::ApiError::SameUser($auth_user.id, $user_id));
         }
@@ -26,6 +36,30 @@ macro_rules! same_user {
 
 pub(crate) use same_user;
 
+/// The API tokens for a user can be managed by that user,
+/// and the API tokens for a service account can also be managed by anyone who can create roles in its organization.
+pub fn same_token_user(
+    conn: &mut DbConnection,
+    rbac: &Rbac,
+    auth_user: &AuthUser,
+    user_id: i32,
+) -> Result<(), ApiError> {
+    if !(auth_user.is_scoped() || auth_user.is_admin(rbac) || auth_user.id == user_id) {
+        if let Some(query_service_account) = QueryServiceAccount::from_user_id(conn, user_id)? {
+            return QueryOrganization::is_allowed_id(
+                conn,
+                rbac,
+                query_service_account.organization_id,
+                auth_user,
+                bencher_rbac::organization::Permission::CreateRole,
+            )
+            .map(|_| ());
+        }
+    }
+    same_user!(auth_user, rbac, user_id);
+    Ok(())
+}
+
 #[derive(Queryable)]
 pub struct QueryToken {
     pub id: i32,
@@ -35,6 +69,8 @@ pub struct QueryToken {
     pub jwt: String,
     pub creation: i64,
     pub expiration: i64,
+    pub scopes: Option<String>,
+    pub revoked: Option<i64>,
 }
 
 impl QueryToken {
@@ -57,6 +93,8 @@ impl QueryToken {
             jwt,
             creation,
             expiration,
+            scopes,
+            revoked,
             ..
         } = self;
         Ok(JsonToken {
@@ -66,6 +104,8 @@ impl QueryToken {
             token: Jwt::from_str(&jwt).map_err(api_error!())?,
             creation: to_date_time(creation)?,
             expiration: to_date_time(expiration)?,
+            scopes: scopes.as_deref().map(serde_json::from_str).transpose()?,
+            revoked: revoked.map(to_date_time).transpose()?,
         })
     }
 }
@@ -85,6 +125,7 @@ pub struct InsertToken {
     pub jwt: String,
     pub creation: i64,
     pub expiration: i64,
+    pub scopes: Option<String>,
 }
 
 impl InsertToken {
@@ -97,10 +138,10 @@ impl InsertToken {
         token: JsonNewToken,
         auth_user: &AuthUser,
     ) -> Result<Self, ApiError> {
-        let JsonNewToken { name, ttl } = token;
+        let JsonNewToken { name, ttl, scopes } = token;
 
         let query_user = QueryUser::from_resource_id(conn, user)?;
-        same_user!(auth_user, rbac, query_user.id);
+        same_token_user(conn, rbac, auth_user, query_user.id)?;
 
         // TODO Custom max TTL
         let max_ttl = u32::MAX;
@@ -121,6 +162,29 @@ impl InsertToken {
 
         let token_data = secret_key.validate_api_key(&jwt.as_ref().parse()?)?;
 
+        // Store the scopes by project UUID, so that they still refer to the same projects if their slugs change
+        let scopes = scopes
+            .map(|scopes| {
+                scopes
+                    .into_iter()
+                    .map(
+                        |JsonTokenScope {
+                             project,
+                             permissions,
+                         }| {
+                            let query_project = QueryProject::from_resource_id(conn, &project)?;
+                            Ok(JsonTokenScope {
+                                project: Uuid::from_str(&query_project.uuid)?.into(),
+                                permissions,
+                            })
+                        },
+                    )
+                    .collect::<Result<Vec<_>, ApiError>>()
+            })
+            .transpose()?
+            .map(|scopes| serde_json::to_string(&scopes))
+            .transpose()?;
+
         Ok(Self {
             uuid: Uuid::new_v4().to_string(),
             user_id: query_user.id,
@@ -128,6 +192,7 @@ impl InsertToken {
             jwt: jwt.to_string(),
             creation: token_data.claims.iat as i64,
             expiration: token_data.claims.exp as i64,
+            scopes,
         })
     }
 }
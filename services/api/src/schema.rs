@@ -10,6 +10,23 @@ diesel::table! {
         side -> Bool,
         boundary -> Float,
         outlier -> Float,
+        status -> Integer,
+        status_user_id -> Nullable<Integer>,
+        status_time -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    audit_event (id) {
+        id -> Integer,
+        uuid -> Text,
+        user_id -> Integer,
+        organization_id -> Nullable<Integer>,
+        project_id -> Nullable<Integer>,
+        action -> Integer,
+        resource -> Nullable<Text>,
+        details -> Nullable<Text>,
+        creation -> BigInt,
     }
 }
 
@@ -40,6 +57,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    custom_role (id) {
+        id -> Integer,
+        uuid -> Text,
+        organization_id -> Integer,
+        name -> Text,
+        permissions -> Text,
+        creation -> BigInt,
+    }
+}
+
 diesel::table! {
     metric (id) {
         id -> Integer,
@@ -63,6 +91,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    notification (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        email -> Text,
+    }
+}
+
 diesel::table! {
     organization (id) {
         id -> Integer,
@@ -102,6 +139,7 @@ diesel::table! {
         slug -> Text,
         url -> Nullable<Text>,
         visibility -> Integer,
+        auto_metric_kinds -> Bool,
     }
 }
 
@@ -127,6 +165,35 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    report_fingerprint (id) {
+        id -> Integer,
+        report_id -> Integer,
+        fingerprint -> Text,
+        changed -> Bool,
+    }
+}
+
+diesel::table! {
+    report_output (id) {
+        id -> Integer,
+        report_id -> Integer,
+        iteration -> Integer,
+        stdout -> Text,
+        stderr -> Text,
+    }
+}
+
+diesel::table! {
+    service_account (id) {
+        id -> Integer,
+        user_id -> Integer,
+        organization_id -> Integer,
+        creator_id -> Integer,
+        creation -> BigInt,
+    }
+}
+
 diesel::table! {
     statistic (id) {
         id -> Integer,
@@ -137,6 +204,9 @@ diesel::table! {
         window -> Nullable<BigInt>,
         left_side -> Nullable<Float>,
         right_side -> Nullable<Float>,
+        baseline -> Nullable<Integer>,
+        lower_limit -> Nullable<Double>,
+        upper_limit -> Nullable<Double>,
     }
 }
 
@@ -170,6 +240,8 @@ diesel::table! {
         jwt -> Text,
         creation -> BigInt,
         expiration -> BigInt,
+        scopes -> Nullable<Text>,
+        revoked -> Nullable<BigInt>,
     }
 }
 
@@ -194,16 +266,50 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        url -> Text,
+        secret -> Nullable<Text>,
+        events -> Integer,
+    }
+}
+
+diesel::table! {
+    webhook_delivery (id) {
+        id -> Integer,
+        uuid -> Text,
+        webhook_id -> Integer,
+        event -> Integer,
+        payload -> Text,
+        attempts -> Integer,
+        status_code -> Nullable<Integer>,
+        error -> Nullable<Text>,
+        success -> Bool,
+        created -> BigInt,
+        modified -> BigInt,
+        next_attempt -> Nullable<BigInt>,
+    }
+}
+
 diesel::joinable!(alert -> perf (perf_id));
 diesel::joinable!(alert -> statistic (statistic_id));
 diesel::joinable!(alert -> threshold (threshold_id));
+diesel::joinable!(alert -> user (status_user_id));
+diesel::joinable!(audit_event -> organization (organization_id));
+diesel::joinable!(audit_event -> project (project_id));
+diesel::joinable!(audit_event -> user (user_id));
 diesel::joinable!(benchmark -> project (project_id));
 diesel::joinable!(branch -> project (project_id));
 diesel::joinable!(branch_version -> branch (branch_id));
 diesel::joinable!(branch_version -> version (version_id));
+diesel::joinable!(custom_role -> organization (organization_id));
 diesel::joinable!(metric -> metric_kind (metric_kind_id));
 diesel::joinable!(metric -> perf (perf_id));
 diesel::joinable!(metric_kind -> project (project_id));
+diesel::joinable!(notification -> project (project_id));
 diesel::joinable!(organization_role -> organization (organization_id));
 diesel::joinable!(organization_role -> user (user_id));
 diesel::joinable!(perf -> benchmark (benchmark_id));
@@ -214,30 +320,44 @@ diesel::joinable!(project_role -> user (user_id));
 diesel::joinable!(report -> testbed (testbed_id));
 diesel::joinable!(report -> user (user_id));
 diesel::joinable!(report -> version (version_id));
+diesel::joinable!(report_fingerprint -> report (report_id));
+diesel::joinable!(report_output -> report (report_id));
+diesel::joinable!(service_account -> organization (organization_id));
+diesel::joinable!(service_account -> user (user_id));
 diesel::joinable!(testbed -> project (project_id));
 diesel::joinable!(threshold -> branch (branch_id));
 diesel::joinable!(threshold -> metric_kind (metric_kind_id));
 diesel::joinable!(threshold -> statistic (statistic_id));
 diesel::joinable!(threshold -> testbed (testbed_id));
 diesel::joinable!(token -> user (user_id));
+diesel::joinable!(webhook -> project (project_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     alert,
+    audit_event,
     benchmark,
     branch,
     branch_version,
+    custom_role,
     metric,
     metric_kind,
+    notification,
     organization,
     organization_role,
     perf,
     project,
     project_role,
     report,
+    report_fingerprint,
+    report_output,
+    service_account,
     statistic,
     testbed,
     threshold,
     token,
     user,
     version,
+    webhook,
+    webhook_delivery,
 );
@@ -0,0 +1,231 @@
+// Offline change point detection for shifts in the mean
+// using Pruned Exact Linear Time (PELT): https://arxiv.org/abs/1101.1438
+
+// Segments must have at least this many metrics
+const MIN_SEGMENT_LEN: usize = 2;
+// Modified Bayesian Information Criterion (MBIC) penalty multiplier for each added change point
+const PENALTY_MULTIPLIER: f64 = 3.0;
+// Scale factor to make the median absolute deviation a consistent estimator of the standard deviation
+const MAD_SCALE: f64 = 1.4826;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Changepoint {
+    /// The index of the first datum after the shift
+    pub index: usize,
+    pub before_mean: f64,
+    pub after_mean: f64,
+}
+
+#[allow(
+    clippy::arithmetic_side_effects,
+    clippy::float_arithmetic,
+    clippy::indexing_slicing,
+    clippy::integer_arithmetic
+)]
+pub fn changepoints(data: &[f64]) -> Vec<Changepoint> {
+    let n = data.len();
+    if n < MIN_SEGMENT_LEN * 2 {
+        return Vec::new();
+    }
+    // A running median removes any single outlier,
+    // so it is never considered a shift on its own.
+    let filtered = running_median(data);
+    let Some(std_dev) = noise_std_dev(&filtered) else {
+        return Vec::new();
+    };
+
+    // Prefix sums of the data and the squared data, normalized by the noise,
+    // so the cost of any segment can be found in constant time.
+    let mut sum = Vec::with_capacity(n + 1);
+    let mut sum_squared = Vec::with_capacity(n + 1);
+    sum.push(0.0);
+    sum_squared.push(0.0);
+    for (i, datum) in filtered.iter().enumerate() {
+        let normalized = datum / std_dev;
+        sum.push(sum[i] + normalized);
+        sum_squared.push(sum_squared[i] + normalized * normalized);
+    }
+    let cost = |start: usize, end: usize| {
+        let len = (end - start) as f64;
+        let segment_sum = sum[end] - sum[start];
+        sum_squared[end] - sum_squared[start] - segment_sum * segment_sum / len
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let penalty = PENALTY_MULTIPLIER * (n as f64).ln();
+    let mut optimal = vec![f64::INFINITY; n + 1];
+    optimal[0] = -penalty;
+    let mut previous = vec![0; n + 1];
+    let mut candidates = vec![0];
+
+    for end in MIN_SEGMENT_LEN..=n {
+        let mut costs = Vec::with_capacity(candidates.len());
+        for &start in &candidates {
+            if end - start >= MIN_SEGMENT_LEN {
+                let total = optimal[start] + cost(start, end) + penalty;
+                if total < optimal[end] {
+                    optimal[end] = total;
+                    previous[end] = start;
+                }
+                costs.push((start, optimal[start] + cost(start, end)));
+            }
+        }
+        // Prune any candidate that can never be optimal going forward
+        candidates.retain(|start| {
+            costs
+                .iter()
+                .find(|(s, _)| s == start)
+                .map_or(true, |(_, c)| *c <= optimal[end])
+        });
+        if optimal[end].is_finite() {
+            candidates.push(end);
+        }
+    }
+
+    let mut indices = Vec::new();
+    let mut end = n;
+    while end > 0 {
+        let start = previous[end];
+        if start > 0 {
+            indices.push(start);
+        }
+        end = start;
+    }
+    indices.reverse();
+
+    let mut boundaries = Vec::with_capacity(indices.len() + 2);
+    boundaries.push(0);
+    boundaries.extend(&indices);
+    boundaries.push(n);
+    let means = boundaries
+        .windows(2)
+        .map(|window| mean(&data[window[0]..window[1]]))
+        .collect::<Vec<_>>();
+
+    indices
+        .into_iter()
+        .zip(means.windows(2))
+        .map(|(index, means)| Changepoint {
+            index,
+            before_mean: means[0],
+            after_mean: means[1],
+        })
+        .collect()
+}
+
+// The median of each datum and its neighbors, keeping the first and last data as is.
+// Unlike a running mean, this preserves the edges of any shift.
+#[allow(clippy::indexing_slicing)]
+fn running_median(data: &[f64]) -> Vec<f64> {
+    let mut filtered = data.to_vec();
+    for (i, window) in data.windows(3).enumerate() {
+        if let Some(median) = median(window) {
+            filtered[i + 1] = median;
+        }
+    }
+    filtered
+}
+
+// Estimate the standard deviation of the noise from the differences between consecutive data.
+// This is robust to the shifts themselves, which only add a few large differences.
+// Fall back to the standard deviation of the data if there is no variation between most consecutive data.
+#[allow(clippy::float_arithmetic, clippy::indexing_slicing)]
+fn noise_std_dev(data: &[f64]) -> Option<f64> {
+    let differences = data
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .collect::<Vec<_>>();
+    let center = median(&differences)?;
+    let deviations = differences
+        .iter()
+        .map(|difference| (difference - center).abs())
+        .collect::<Vec<_>>();
+    let std_dev = MAD_SCALE * median(&deviations)? / std::f64::consts::SQRT_2;
+    if std_dev > 0.0 {
+        return Some(std_dev);
+    }
+    let mean = mean(data);
+    #[allow(clippy::cast_precision_loss)]
+    let variance = data.iter().map(|datum| (datum - mean).powi(2)).sum::<f64>() / data.len() as f64;
+    let std_dev = variance.sqrt();
+    (std_dev > 0.0).then_some(std_dev)
+}
+
+#[allow(clippy::cast_precision_loss, clippy::float_arithmetic)]
+fn mean(data: &[f64]) -> f64 {
+    data.iter().sum::<f64>() / data.len() as f64
+}
+
+#[allow(clippy::float_arithmetic, clippy::integer_arithmetic)]
+fn median(data: &[f64]) -> Option<f64> {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        Some((sorted.get(middle.checked_sub(1)?)? + sorted.get(middle)?) / 2.0)
+    } else {
+        sorted.get(middle).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{changepoints, Changepoint};
+
+    const NOISE: [f64; 10] = [0.3, -0.2, 0.1, -0.4, 0.2, 0.0, -0.1, 0.4, -0.3, 0.1];
+
+    fn noisy(mean: f64) -> impl Iterator<Item = f64> {
+        NOISE.into_iter().map(move |noise| mean + noise)
+    }
+
+    #[test]
+    fn test_changepoints_none() {
+        assert_eq!(changepoints(&[]), Vec::new());
+        assert_eq!(changepoints(&[1.0, 2.0, 3.0]), Vec::new());
+        assert_eq!(changepoints(&[5.0; 20]), Vec::new());
+        let data = noisy(10.0).chain(noisy(10.0)).collect::<Vec<_>>();
+        assert_eq!(changepoints(&data), Vec::new());
+    }
+
+    #[test]
+    fn test_changepoints_step() {
+        let data = noisy(10.0).chain(noisy(20.0)).collect::<Vec<_>>();
+        let [Changepoint {
+            index,
+            before_mean,
+            after_mean,
+        }] = changepoints(&data)[..] else {
+            panic!("Expected a single change point");
+        };
+        assert_eq!(index, 10);
+        assert!((before_mean - 10.01).abs() < f64::EPSILON * 100.0);
+        assert!((after_mean - 20.01).abs() < f64::EPSILON * 100.0);
+    }
+
+    #[test]
+    fn test_changepoints_two_steps() {
+        let data = noisy(10.0)
+            .chain(noisy(20.0))
+            .chain(noisy(10.0))
+            .collect::<Vec<_>>();
+        let indices = changepoints(&data)
+            .into_iter()
+            .map(|changepoint| changepoint.index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![10, 20]);
+    }
+
+    #[test]
+    fn test_changepoints_single_outlier() {
+        let mut data = noisy(10.0).chain(noisy(10.0)).collect::<Vec<_>>();
+        data[10] = 100.0;
+        assert_eq!(changepoints(&data), Vec::new());
+        data[11] = 100.0;
+        data[12] = 100.0;
+        let indices = changepoints(&data)
+            .into_iter()
+            .map(|changepoint| changepoint.index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![10, 13]);
+    }
+}
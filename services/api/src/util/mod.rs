@@ -1,3 +1,4 @@
+pub mod changepoint;
 pub mod cors;
 pub mod error;
 pub mod headers;
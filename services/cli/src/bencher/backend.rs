@@ -1,11 +1,18 @@
 use std::convert::TryFrom;
 
-use bencher_json::{Jwt, Url};
+use bencher_json::{
+    pagination::{DEFAULT_PER_PAGE, TOTAL_COUNT_HEADER},
+    JsonDirection, JsonEmpty, JsonPagination, Jwt, Url,
+};
+use clap::ValueEnum;
 use reqwest::{Client, RequestBuilder};
 use serde::Serialize;
 use tokio::time::{sleep, Duration};
 
-use crate::{cli::CliBackend, cli_println, CliError};
+use crate::{
+    cli::{CliBackend, CliDirection, CliPagination},
+    cli_println, CliError,
+};
 
 pub const BENCHER_API_TOKEN: &str = "BENCHER_API_TOKEN";
 pub const BENCHER_HOST: &str = "BENCHER_HOST";
@@ -37,6 +44,36 @@ impl TryFrom<CliBackend> for Backend {
     }
 }
 
+impl<C, J> From<CliPagination<C>> for JsonPagination<J>
+where
+    C: ValueEnum + Clone + Send + Sync + 'static,
+    J: From<C>,
+{
+    fn from(pagination: CliPagination<C>) -> Self {
+        let CliPagination {
+            sort,
+            direction,
+            per_page,
+            page,
+        } = pagination;
+        Self {
+            sort: sort.map(Into::into),
+            direction: direction.map(Into::into),
+            per_page,
+            page,
+        }
+    }
+}
+
+impl From<CliDirection> for JsonDirection {
+    fn from(direction: CliDirection) -> Self {
+        match direction {
+            CliDirection::Asc => Self::Asc,
+            CliDirection::Desc => Self::Desc,
+        }
+    }
+}
+
 fn unwrap_host(host: Option<Url>) -> Result<url::Url, CliError> {
     if let Some(url) = host {
         url.into()
@@ -73,6 +110,90 @@ impl Backend {
         self.send(Method::GetQuery(query), path).await
     }
 
+    /// Get without printing the response
+    pub async fn get_query_quiet<T>(
+        &self,
+        path: &str,
+        query: &T,
+    ) -> Result<serde_json::Value, CliError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let (json, _) = self.attempt(Method::GetQuery(query), path).await?;
+        Ok(json)
+    }
+
+    pub async fn get_ls<S>(
+        &self,
+        path: &str,
+        pagination: &JsonPagination<S>,
+    ) -> Result<serde_json::Value, CliError>
+    where
+        S: Serialize,
+    {
+        self.get_ls_query(path, pagination, &JsonEmpty::default())
+            .await
+    }
+
+    /// List all of the items across every page, unless a specific page is requested
+    pub async fn get_ls_query<S, T>(
+        &self,
+        path: &str,
+        pagination: &JsonPagination<S>,
+        query: &T,
+    ) -> Result<serde_json::Value, CliError>
+    where
+        S: Serialize,
+        T: Serialize + ?Sized,
+    {
+        let json = self.get_ls_query_quiet(path, pagination, query).await?;
+        cli_println!("{}", serde_json::to_string_pretty(&json)?);
+        Ok(json)
+    }
+
+    /// List without printing the response
+    pub async fn get_ls_query_quiet<S, T>(
+        &self,
+        path: &str,
+        pagination: &JsonPagination<S>,
+        query: &T,
+    ) -> Result<serde_json::Value, CliError>
+    where
+        S: Serialize,
+        T: Serialize + ?Sized,
+    {
+        let mut query = query_map(query)?;
+        query.extend(query_map(pagination)?);
+
+        if pagination.page.is_some() {
+            let (json, _) = self.attempt(Method::GetQuery(&query), path).await?;
+            return Ok(json);
+        }
+
+        let per_page = usize::from(pagination.per_page.unwrap_or(DEFAULT_PER_PAGE));
+        let mut items = Vec::new();
+        let mut page: u32 = 1;
+        loop {
+            query.insert("page".into(), page.into());
+            let (json, total_count) = self.attempt(Method::GetQuery(&query), path).await?;
+            // Any error response is returned as is
+            let serde_json::Value::Array(page_items) = json else {
+                return Ok(json);
+            };
+            let count = page_items.len();
+            items.extend(page_items);
+            if count == 0
+                || count < per_page
+                || total_count.map_or(false, |total| items.len() >= total)
+            {
+                break;
+            }
+            page = page.checked_add(1).ok_or(CliError::BadMath)?;
+        }
+
+        Ok(serde_json::Value::Array(items))
+    }
+
     pub async fn post<T>(&self, path: &str, json: &T) -> Result<serde_json::Value, CliError>
     where
         T: Serialize + ?Sized,
@@ -80,6 +201,15 @@ impl Backend {
         self.send(Method::Post(json), path).await
     }
 
+    /// Post without printing the response
+    pub async fn post_quiet<T>(&self, path: &str, json: &T) -> Result<serde_json::Value, CliError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let (json, _) = self.attempt(Method::Post(json), path).await?;
+        Ok(json)
+    }
+
     pub async fn put<T>(&self, path: &str, json: &T) -> Result<serde_json::Value, CliError>
     where
         T: Serialize + ?Sized,
@@ -99,6 +229,20 @@ impl Backend {
     }
 
     async fn send<T>(&self, method: Method<&T>, path: &str) -> Result<serde_json::Value, CliError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let (json, _) = self.attempt(method, path).await?;
+        cli_println!("{}", serde_json::to_string_pretty(&json)?);
+        Ok(json)
+    }
+
+    /// Send the request, returning the JSON response and the total count header if present
+    async fn attempt<T>(
+        &self,
+        method: Method<&T>,
+        path: &str,
+    ) -> Result<(serde_json::Value, Option<usize>), CliError>
     where
         T: Serialize + ?Sized,
     {
@@ -112,9 +256,13 @@ impl Backend {
         for attempt in 0..attempts {
             match self.builder(&client, &method, &url).send().await {
                 Ok(res) => {
+                    let total_count = res
+                        .headers()
+                        .get(TOTAL_COUNT_HEADER)
+                        .and_then(|total_count| total_count.to_str().ok())
+                        .and_then(|total_count| total_count.parse().ok());
                     let json = res.json().await?;
-                    cli_println!("{}", serde_json::to_string_pretty(&json)?);
-                    return Ok(json);
+                    return Ok((json, total_count));
                 },
                 Err(e) => {
                     cli_println!("Send attempt #{}: {e}", attempt + 1);
@@ -156,3 +304,14 @@ enum Method<T> {
     Patch(T),
     Delete,
 }
+
+/// Serialize the query parameters into a map, dropping any null values
+fn query_map<T>(query: &T) -> Result<serde_json::Map<String, serde_json::Value>, CliError>
+where
+    T: Serialize + ?Sized,
+{
+    Ok(match serde_json::to_value(query)? {
+        serde_json::Value::Object(map) => map.into_iter().filter(|(_, v)| !v.is_null()).collect(),
+        _ => serde_json::Map::new(),
+    })
+}
@@ -1,10 +1,12 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, path::PathBuf};
 
 use crate::{bencher::backend::Backend, cli::CliLocality, CliError};
 
+pub const BENCHER_LOCAL_DIR: &str = ".bencher";
+
 #[derive(Debug)]
 pub enum Locality {
-    Local,
+    Local(PathBuf),
     Backend(Backend),
 }
 
@@ -13,7 +15,11 @@ impl TryFrom<CliLocality> for Locality {
 
     fn try_from(locality: CliLocality) -> Result<Self, Self::Error> {
         Ok(if locality.local {
-            Self::Local
+            Self::Local(
+                locality
+                    .local_dir
+                    .unwrap_or_else(|| BENCHER_LOCAL_DIR.into()),
+            )
         } else {
             Self::Backend(locality.backend.try_into()?)
         })
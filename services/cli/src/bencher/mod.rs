@@ -7,6 +7,7 @@ use crate::{cli::CliBencher, CliError};
 
 pub mod backend;
 pub mod locality;
+pub mod spool;
 pub mod sub;
 
 use sub::{Sub, SubCmd};
@@ -0,0 +1,143 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bencher_json::{JsonNewReport, ResourceId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{bencher::locality::BENCHER_LOCAL_DIR, CliError};
+
+const SPOOL_DIR: &str = "spool";
+const FAILED_DIR: &str = "failed";
+
+/// A directory of reports that failed to send to the backend, to be uploaded later
+#[derive(Debug, Clone)]
+pub struct Spool {
+    dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpooledReport {
+    pub project: ResourceId,
+    pub report: JsonNewReport,
+}
+
+impl From<Option<PathBuf>> for Spool {
+    fn from(dir: Option<PathBuf>) -> Self {
+        Self {
+            dir: dir.unwrap_or_else(|| Path::new(BENCHER_LOCAL_DIR).join(SPOOL_DIR)),
+        }
+    }
+}
+
+impl Spool {
+    /// Save a report to the spool directory, returning its path
+    pub fn save(&self, project: &ResourceId, report: &JsonNewReport) -> Result<PathBuf, CliError> {
+        fs::create_dir_all(&self.dir)?;
+        // Prefix with the start time so that the file names sort in the order the reports were run
+        let path = self.dir.join(format!(
+            "{}-{}.json",
+            report.start_time.timestamp_nanos(),
+            Uuid::new_v4()
+        ));
+        let spooled_report = SpooledReport {
+            project: project.clone(),
+            report: report.clone(),
+        };
+        fs::write(&path, serde_json::to_string_pretty(&spooled_report)?)?;
+        Ok(path)
+    }
+
+    /// Load all of the spooled reports, ordered from oldest to newest start time
+    pub fn load(&self) -> Result<Vec<(PathBuf, SpooledReport)>, CliError> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut spooled_reports = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .map_or(true, |extension| extension != "json")
+            {
+                continue;
+            }
+            let spooled_report: SpooledReport = serde_json::from_str(&fs::read_to_string(&path)?)?;
+            spooled_reports.push((path, spooled_report));
+        }
+        spooled_reports.sort_by(|(a_path, a), (b_path, b)| {
+            (a.report.start_time, a_path).cmp(&(b.report.start_time, b_path))
+        });
+
+        Ok(spooled_reports)
+    }
+
+    /// Move a spooled report that was rejected to the failed subdirectory, returning its new path.
+    /// Failed reports are no longer loaded, so they do not block the remaining spooled reports.
+    pub fn fail(&self, path: &Path) -> Result<PathBuf, CliError> {
+        let failed_dir = self.dir.join(FAILED_DIR);
+        fs::create_dir_all(&failed_dir)?;
+        let failed_path = failed_dir.join(path.file_name().ok_or(CliError::BadMath)?);
+        fs::rename(path, &failed_path)?;
+        Ok(failed_path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::JsonNewReport;
+    use chrono::{Duration, Utc};
+    use pretty_assertions::assert_eq;
+
+    use super::{Spool, FAILED_DIR};
+
+    fn report(start_time: chrono::DateTime<Utc>) -> JsonNewReport {
+        JsonNewReport {
+            branch: "main".parse().unwrap(),
+            hash: None,
+            testbed: "localhost".parse().unwrap(),
+            start_time,
+            end_time: start_time,
+            results: Vec::new(),
+            settings: None,
+            output: None,
+            fingerprint: None,
+        }
+    }
+
+    #[test]
+    fn test_spool() {
+        let dir = tempfile::tempdir().unwrap();
+        let spool = Spool::from(Some(dir.path().to_path_buf()));
+        assert!(spool.load().unwrap().is_empty());
+
+        let project = "project".parse().unwrap();
+        let now = Utc::now();
+        let newer = spool.save(&project, &report(now)).unwrap();
+        let older = spool
+            .save(&project, &report(now - Duration::hours(1)))
+            .unwrap();
+        let paths = spool
+            .load()
+            .unwrap()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec![older.clone(), newer.clone()]);
+
+        // A failed report is moved aside, so that it does not block the remaining reports
+        let failed = spool.fail(&older).unwrap();
+        assert_eq!(failed.parent().unwrap(), dir.path().join(FAILED_DIR));
+        assert!(failed.exists());
+        let paths = spool
+            .load()
+            .unwrap()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec![newer]);
+    }
+}
@@ -16,24 +16,29 @@ mod user;
 #[cfg(feature = "docs")]
 use docs::Docs;
 use mock::Mock;
-use organization::{member::Member, resource::Organization};
+use organization::{
+    member::Member, resource::Organization, role::Role, service_account::ServiceAccount,
+};
 pub use project::run::runner::output::Output;
 use project::{
-    alert::Alert, benchmark::Benchmark, branch::Branch, metric_kind::MetricKind, perf::Perf,
-    project::Project, report::Report, result::Resultant, run::Run, testbed::Testbed,
-    threshold::Threshold,
+    alert::Alert, benchmark::Benchmark, branch::Branch, compare::Compare, metric_kind::MetricKind,
+    notification::Notification, perf::Perf, project::Project, report::Report, result::Resultant,
+    run::Run, testbed::Testbed, threshold::Threshold, webhook::Webhook,
 };
 pub use sub_cmd::SubCmd;
-use system::{auth::Auth, server::Server};
+use system::{audit::Audit, auth::Auth, server::Server};
 use user::resource::User;
 use user::token::Token;
 
 #[derive(Debug)]
 pub enum Sub {
     Server(Server),
+    Audit(Audit),
     Auth(Auth),
     Organization(Organization),
     Member(Member),
+    ServiceAccount(ServiceAccount),
+    Role(Role),
     Project(Project),
     Report(Report),
     Result(Resultant),
@@ -41,10 +46,13 @@ pub enum Sub {
     Testbed(Testbed),
     Threshold(Threshold),
     MetricKind(MetricKind),
-    Run(Run),
+    Run(Box<Run>),
     Benchmark(Benchmark),
     Perf(Perf),
+    Compare(Compare),
     Alert(Alert),
+    Notification(Notification),
+    Webhook(Webhook),
     User(User),
     Token(Token),
     Mock(Mock),
@@ -58,9 +66,14 @@ impl TryFrom<CliSub> for Sub {
     fn try_from(sub: CliSub) -> Result<Self, Self::Error> {
         Ok(match sub {
             CliSub::Server(server) => Self::Server(server.try_into()?),
+            CliSub::Audit(audit) => Self::Audit(audit.try_into()?),
             CliSub::Auth(auth) => Self::Auth(auth.try_into()?),
             CliSub::Organization(organization) => Self::Organization(organization.try_into()?),
             CliSub::Member(member) => Self::Member(member.try_into()?),
+            CliSub::ServiceAccount(service_account) => {
+                Self::ServiceAccount(service_account.try_into()?)
+            },
+            CliSub::Role(role) => Self::Role(role.try_into()?),
             CliSub::Project(project) => Self::Project(project.try_into()?),
             CliSub::Report(report) => Self::Report(report.try_into()?),
             CliSub::Result(result) => Self::Result(result.try_into()?),
@@ -68,10 +81,13 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Testbed(testbed) => Self::Testbed(testbed.try_into()?),
             CliSub::Threshold(threshold) => Self::Threshold(threshold.try_into()?),
             CliSub::MetricKind(metric_kind) => Self::MetricKind(metric_kind.try_into()?),
-            CliSub::Run(run) => Self::Run(run.try_into()?),
+            CliSub::Run(run) => Self::Run(Box::new(run.try_into()?)),
             CliSub::Benchmark(benchmark) => Self::Benchmark(benchmark.try_into()?),
             CliSub::Perf(perf) => Self::Perf(perf.try_into()?),
+            CliSub::Compare(compare) => Self::Compare(compare.try_into()?),
             CliSub::Alert(alert) => Self::Alert(alert.try_into()?),
+            CliSub::Notification(notification) => Self::Notification(notification.try_into()?),
+            CliSub::Webhook(webhook) => Self::Webhook(webhook.try_into()?),
             CliSub::User(user) => Self::User(user.try_into()?),
             CliSub::Token(token) => Self::Token(token.try_into()?),
             CliSub::Mock(mock) => Self::Mock(mock.into()),
@@ -86,9 +102,12 @@ impl SubCmd for Sub {
     async fn exec(&self) -> Result<(), CliError> {
         match self {
             Self::Server(server) => server.exec().await,
+            Self::Audit(audit) => audit.exec().await,
             Self::Auth(auth) => auth.exec().await,
             Self::Organization(organization) => organization.exec().await,
             Self::Member(member) => member.exec().await,
+            Self::ServiceAccount(service_account) => service_account.exec().await,
+            Self::Role(role) => role.exec().await,
             Self::Project(project) => project.exec().await,
             Self::Report(report) => report.exec().await,
             Self::Result(result) => result.exec().await,
@@ -99,7 +118,10 @@ impl SubCmd for Sub {
             Self::Run(run) => run.exec().await,
             Self::Benchmark(benchmark) => benchmark.exec().await,
             Self::Perf(perf) => perf.exec().await,
+            Self::Compare(compare) => compare.exec().await,
             Self::Alert(alert) => alert.exec().await,
+            Self::Notification(notification) => notification.exec().await,
+            Self::Webhook(webhook) => webhook.exec().await,
             Self::User(user) => user.exec().await,
             Self::Token(token) => token.exec().await,
             Self::Mock(mock) => mock.exec().await,
@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{
+    organization::member::JsonUpdateMemberProject, project::JsonProjectRole, ResourceId,
+};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::member::CliMemberAssign,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Assign {
+    pub org: ResourceId,
+    pub user: ResourceId,
+    pub project: ResourceId,
+    pub role: JsonProjectRole,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliMemberAssign> for Assign {
+    type Error = CliError;
+
+    fn try_from(assign: CliMemberAssign) -> Result<Self, Self::Error> {
+        let CliMemberAssign {
+            org,
+            user,
+            project,
+            role,
+            backend,
+        } = assign;
+        Ok(Self {
+            org,
+            user,
+            project,
+            role: role.parse().map_err(CliError::ProjectRole)?,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Assign> for JsonUpdateMemberProject {
+    fn from(assign: Assign) -> Self {
+        Self { role: assign.role }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Assign {
+    async fn exec(&self) -> Result<(), CliError> {
+        let assign: JsonUpdateMemberProject = self.clone().into();
+        self.backend
+            .put(
+                &format!(
+                    "/v0/organizations/{}/members/{}/projects/{}",
+                    self.org, self.user, self.project
+                ),
+                &assign,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{organization::member::JsonMembersSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::organization::member::CliMemberList,
+    cli::organization::member::{CliMemberList, CliMembersSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub org: ResourceId,
+    pub pagination: JsonPagination<JsonMembersSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,36 @@ impl TryFrom<CliMemberList> for List {
     type Error = CliError;
 
     fn try_from(list: CliMemberList) -> Result<Self, Self::Error> {
-        let CliMemberList { org, backend } = list;
+        let CliMemberList {
+            org,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             org,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliMembersSort> for JsonMembersSort {
+    fn from(sort: CliMembersSort) -> Self {
+        match sort {
+            CliMembersSort::Name => Self::Name,
+            CliMembersSort::Email => Self::Email,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/organizations/{}/members", self.org))
+            .get_ls(
+                &format!("/v0/organizations/{}/members", self.org),
+                &self.pagination,
+            )
             .await?;
         Ok(())
     }
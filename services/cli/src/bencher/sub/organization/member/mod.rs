@@ -2,9 +2,11 @@ use async_trait::async_trait;
 
 use crate::{bencher::sub::SubCmd, cli::organization::member::CliMember, CliError};
 
+mod assign;
 mod invite;
 mod list;
 mod remove;
+mod unassign;
 mod update;
 mod view;
 
@@ -15,6 +17,8 @@ pub enum Member {
     View(view::View),
     Update(update::Update),
     Remove(remove::Remove),
+    Assign(assign::Assign),
+    Unassign(unassign::Unassign),
 }
 
 impl TryFrom<CliMember> for Member {
@@ -27,6 +31,8 @@ impl TryFrom<CliMember> for Member {
             CliMember::View(view) => Self::View(view.try_into()?),
             CliMember::Update(update) => Self::Update(update.try_into()?),
             CliMember::Remove(remove) => Self::Remove(remove.try_into()?),
+            CliMember::Assign(assign) => Self::Assign(assign.try_into()?),
+            CliMember::Unassign(unassign) => Self::Unassign(unassign.try_into()?),
         })
     }
 }
@@ -40,6 +46,8 @@ impl SubCmd for Member {
             Self::View(view) => view.exec().await,
             Self::Update(update) => update.exec().await,
             Self::Remove(remove) => remove.exec().await,
+            Self::Assign(assign) => assign.exec().await,
+            Self::Unassign(unassign) => unassign.exec().await,
         }
     }
 }
@@ -0,0 +1,50 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::member::CliMemberUnassign,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Unassign {
+    pub org: ResourceId,
+    pub user: ResourceId,
+    pub project: ResourceId,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliMemberUnassign> for Unassign {
+    type Error = CliError;
+
+    fn try_from(unassign: CliMemberUnassign) -> Result<Self, Self::Error> {
+        let CliMemberUnassign {
+            org,
+            user,
+            project,
+            backend,
+        } = unassign;
+        Ok(Self {
+            org,
+            user,
+            project,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Unassign {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .delete(&format!(
+                "/v0/organizations/{}/members/{}/projects/{}",
+                self.org, self.user, self.project
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -1,2 +1,4 @@
 pub mod member;
 pub mod resource;
+pub mod role;
+pub mod service_account;
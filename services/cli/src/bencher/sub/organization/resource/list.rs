@@ -1,10 +1,11 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
+use bencher_json::{organization::JsonOrganizationsSort, JsonPagination};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::organization::CliOrganizationList,
+    cli::organization::{CliOrganizationList, CliOrganizationsSort},
     CliError,
 };
 
@@ -12,6 +13,7 @@ const ORGANIZATIONS_PATH: &str = "/v0/organizations";
 
 #[derive(Debug)]
 pub struct List {
+    pub pagination: JsonPagination<JsonOrganizationsSort>,
     pub backend: Backend,
 }
 
@@ -19,17 +21,32 @@ impl TryFrom<CliOrganizationList> for List {
     type Error = CliError;
 
     fn try_from(list: CliOrganizationList) -> Result<Self, Self::Error> {
-        let CliOrganizationList { backend } = list;
+        let CliOrganizationList {
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliOrganizationsSort> for JsonOrganizationsSort {
+    fn from(sort: CliOrganizationsSort) -> Self {
+        match sort {
+            CliOrganizationsSort::Name => Self::Name,
+            CliOrganizationsSort::Created => Self::Created,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
-        self.backend.get(ORGANIZATIONS_PATH).await?;
+        self.backend
+            .get_ls(ORGANIZATIONS_PATH, &self.pagination)
+            .await?;
         Ok(())
     }
 }
@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{
+    organization::role::{JsonNewRole, JsonRolePermission},
+    ResourceId, Slug,
+};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::role::{CliRoleCreate, CliRolePermission},
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub org: ResourceId,
+    pub name: Slug,
+    pub permissions: Vec<JsonRolePermission>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliRoleCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliRoleCreate) -> Result<Self, Self::Error> {
+        let CliRoleCreate {
+            org,
+            name,
+            permission,
+            backend,
+        } = create;
+        Ok(Self {
+            org,
+            name,
+            permissions: permission.into_iter().map(Into::into).collect(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliRolePermission> for JsonRolePermission {
+    fn from(permission: CliRolePermission) -> Self {
+        match permission {
+            CliRolePermission::View => Self::View,
+            CliRolePermission::Create => Self::Create,
+            CliRolePermission::Edit => Self::Edit,
+            CliRolePermission::Delete => Self::Delete,
+            CliRolePermission::Manage => Self::Manage,
+            CliRolePermission::ViewRole => Self::ViewRole,
+            CliRolePermission::CreateRole => Self::CreateRole,
+            CliRolePermission::EditRole => Self::EditRole,
+            CliRolePermission::DeleteRole => Self::DeleteRole,
+            CliRolePermission::CreateReport => Self::CreateReport,
+            CliRolePermission::ManageThreshold => Self::ManageThreshold,
+        }
+    }
+}
+
+impl From<Create> for JsonNewRole {
+    fn from(create: Create) -> Self {
+        let Create {
+            name, permissions, ..
+        } = create;
+        Self { name, permissions }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let role: JsonNewRole = self.clone().into();
+        self.backend
+            .post(&format!("/v0/organizations/{}/roles", self.org), &role)
+            .await?;
+        Ok(())
+    }
+}
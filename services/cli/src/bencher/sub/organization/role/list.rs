@@ -0,0 +1,56 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{organization::role::JsonRolesSort, JsonPagination, ResourceId};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::role::{CliRoleList, CliRolesSort},
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct List {
+    pub org: ResourceId,
+    pub pagination: JsonPagination<JsonRolesSort>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliRoleList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliRoleList) -> Result<Self, Self::Error> {
+        let CliRoleList {
+            org,
+            pagination,
+            backend,
+        } = list;
+        Ok(Self {
+            org,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliRolesSort> for JsonRolesSort {
+    fn from(sort: CliRolesSort) -> Self {
+        match sort {
+            CliRolesSort::Name => Self::Name,
+            CliRolesSort::Creation => Self::Creation,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get_ls(
+                &format!("/v0/organizations/{}/roles", self.org),
+                &self.pagination,
+            )
+            .await?;
+        Ok(())
+    }
+}
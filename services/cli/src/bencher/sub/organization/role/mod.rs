@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+
+use crate::{bencher::sub::SubCmd, cli::organization::role::CliRole, CliError};
+
+mod create;
+mod delete;
+mod list;
+mod update;
+mod view;
+
+#[derive(Debug)]
+pub enum Role {
+    List(list::List),
+    Create(create::Create),
+    View(view::View),
+    Update(update::Update),
+    Delete(delete::Delete),
+}
+
+impl TryFrom<CliRole> for Role {
+    type Error = CliError;
+
+    fn try_from(role: CliRole) -> Result<Self, Self::Error> {
+        Ok(match role {
+            CliRole::List(list) => Self::List(list.try_into()?),
+            CliRole::Create(create) => Self::Create(create.try_into()?),
+            CliRole::View(view) => Self::View(view.try_into()?),
+            CliRole::Update(update) => Self::Update(update.try_into()?),
+            CliRole::Delete(delete) => Self::Delete(delete.try_into()?),
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Role {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{
+    organization::role::{JsonRolePermission, JsonUpdateRole},
+    ResourceId,
+};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::role::CliRoleUpdate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub org: ResourceId,
+    pub role: ResourceId,
+    pub permissions: Vec<JsonRolePermission>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliRoleUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliRoleUpdate) -> Result<Self, Self::Error> {
+        let CliRoleUpdate {
+            org,
+            role,
+            permission,
+            backend,
+        } = update;
+        Ok(Self {
+            org,
+            role,
+            permissions: permission.into_iter().map(Into::into).collect(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateRole {
+    fn from(update: Update) -> Self {
+        let Update { permissions, .. } = update;
+        Self { permissions }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let update: JsonUpdateRole = self.clone().into();
+        self.backend
+            .patch(
+                &format!("/v0/organizations/{}/roles/{}", self.org, self.role),
+                &update,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::role::CliRoleView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub org: ResourceId,
+    pub role: ResourceId,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliRoleView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliRoleView) -> Result<Self, Self::Error> {
+        let CliRoleView { org, role, backend } = view;
+        Ok(Self {
+            org,
+            role,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get(&format!(
+                "/v0/organizations/{}/roles/{}",
+                self.org, self.role
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{
+    organization::{
+        member::JsonOrganizationRole,
+        service_account::{JsonNewServiceAccount, JsonServiceAccountProject},
+    },
+    ResourceId, Slug, UserName,
+};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::service_account::CliServiceAccountCreate,
+    CliError,
+};
+
+use super::project::Project;
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub org: ResourceId,
+    pub name: UserName,
+    pub slug: Option<Slug>,
+    pub role: Option<JsonOrganizationRole>,
+    pub projects: Vec<JsonServiceAccountProject>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliServiceAccountCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliServiceAccountCreate) -> Result<Self, Self::Error> {
+        let CliServiceAccountCreate {
+            org,
+            name,
+            slug,
+            role,
+            project,
+            backend,
+        } = create;
+        Ok(Self {
+            org,
+            name,
+            slug,
+            role: role.map(Into::into),
+            projects: project
+                .iter()
+                .map(|project| project.parse::<Project>().map(Into::into))
+                .collect::<Result<_, _>>()?,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewServiceAccount {
+    fn from(create: Create) -> Self {
+        let Create {
+            name,
+            slug,
+            role,
+            projects,
+            ..
+        } = create;
+        Self {
+            name,
+            slug,
+            role,
+            projects,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let service_account: JsonNewServiceAccount = self.clone().into();
+        self.backend
+            .post(
+                &format!("/v0/organizations/{}/service-accounts", self.org),
+                &service_account,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::service_account::CliServiceAccountView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub org: ResourceId,
+    pub service_account: ResourceId,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliServiceAccountView> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliServiceAccountView) -> Result<Self, Self::Error> {
+        let CliServiceAccountView {
+            org,
+            service_account,
+            backend,
+        } = delete;
+        Ok(Self {
+            org,
+            service_account,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .delete(&format!(
+                "/v0/organizations/{}/service-accounts/{}",
+                self.org, self.service_account
+            ))
+            .await?;
+        Ok(())
+    }
+}
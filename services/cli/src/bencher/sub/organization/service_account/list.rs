@@ -0,0 +1,58 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{
+    organization::service_account::JsonServiceAccountsSort, JsonPagination, ResourceId,
+};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::service_account::{CliServiceAccountList, CliServiceAccountsSort},
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct List {
+    pub org: ResourceId,
+    pub pagination: JsonPagination<JsonServiceAccountsSort>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliServiceAccountList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliServiceAccountList) -> Result<Self, Self::Error> {
+        let CliServiceAccountList {
+            org,
+            pagination,
+            backend,
+        } = list;
+        Ok(Self {
+            org,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliServiceAccountsSort> for JsonServiceAccountsSort {
+    fn from(sort: CliServiceAccountsSort) -> Self {
+        match sort {
+            CliServiceAccountsSort::Name => Self::Name,
+            CliServiceAccountsSort::Creation => Self::Creation,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get_ls(
+                &format!("/v0/organizations/{}/service-accounts", self.org),
+                &self.pagination,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+
+use crate::{
+    bencher::sub::SubCmd, cli::organization::service_account::CliServiceAccount, CliError,
+};
+
+mod create;
+mod delete;
+mod list;
+mod project;
+mod view;
+
+#[derive(Debug)]
+pub enum ServiceAccount {
+    List(list::List),
+    Create(create::Create),
+    View(view::View),
+    Delete(delete::Delete),
+}
+
+impl TryFrom<CliServiceAccount> for ServiceAccount {
+    type Error = CliError;
+
+    fn try_from(service_account: CliServiceAccount) -> Result<Self, Self::Error> {
+        Ok(match service_account {
+            CliServiceAccount::List(list) => Self::List(list.try_into()?),
+            CliServiceAccount::Create(create) => Self::Create(create.try_into()?),
+            CliServiceAccount::View(view) => Self::View(view.try_into()?),
+            CliServiceAccount::Delete(delete) => Self::Delete(delete.try_into()?),
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for ServiceAccount {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+use bencher_json::{
+    organization::service_account::JsonServiceAccountProject, project::JsonProjectRole,
+};
+
+use crate::CliError;
+
+const PROJECT_ROLE_DELIMITER: char = '=';
+
+#[derive(Debug, Clone)]
+pub struct Project(JsonServiceAccountProject);
+
+impl FromStr for Project {
+    type Err = CliError;
+
+    fn from_str(project: &str) -> Result<Self, Self::Err> {
+        let Some((project_id, role)) = project.split_once(PROJECT_ROLE_DELIMITER) else {
+            return Err(CliError::ServiceAccountProject(project.into()));
+        };
+        Ok(Self(JsonServiceAccountProject {
+            project: project_id.trim().parse()?,
+            role: role
+                .trim()
+                .parse::<JsonProjectRole>()
+                .map_err(CliError::ProjectRole)?,
+        }))
+    }
+}
+
+impl From<Project> for JsonServiceAccountProject {
+    fn from(project: Project) -> Self {
+        project.0
+    }
+}
@@ -0,0 +1,47 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::organization::service_account::CliServiceAccountView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub org: ResourceId,
+    pub service_account: ResourceId,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliServiceAccountView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliServiceAccountView) -> Result<Self, Self::Error> {
+        let CliServiceAccountView {
+            org,
+            service_account,
+            backend,
+        } = view;
+        Ok(Self {
+            org,
+            service_account,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get(&format!(
+                "/v0/organizations/{}/service-accounts/{}",
+                self.org, self.service_account
+            ))
+            .await?;
+        Ok(())
+    }
+}
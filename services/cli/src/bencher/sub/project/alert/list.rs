@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{project::alert::JsonAlertsSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::alert::CliAlertList,
+    cli::project::alert::{CliAlertList, CliAlertsSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub pagination: JsonPagination<JsonAlertsSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,36 @@ impl TryFrom<CliAlertList> for List {
     type Error = CliError;
 
     fn try_from(list: CliAlertList) -> Result<Self, Self::Error> {
-        let CliAlertList { project, backend } = list;
+        let CliAlertList {
+            project,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             project,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliAlertsSort> for JsonAlertsSort {
+    fn from(sort: CliAlertsSort) -> Self {
+        match sort {
+            CliAlertsSort::DateTime => Self::DateTime,
+            CliAlertsSort::Status => Self::Status,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/projects/{}/alerts", self.project))
+            .get_ls(
+                &format!("/v0/projects/{}/alerts", self.project),
+                &self.pagination,
+            )
             .await?;
         Ok(())
     }
@@ -3,12 +3,14 @@ use async_trait::async_trait;
 use crate::{bencher::sub::SubCmd, cli::project::alert::CliAlert, CliError};
 
 mod list;
+mod update;
 mod view;
 
 #[derive(Debug)]
 pub enum Alert {
     List(list::List),
     View(view::View),
+    Update(update::Update),
 }
 
 impl TryFrom<CliAlert> for Alert {
@@ -18,6 +20,7 @@ impl TryFrom<CliAlert> for Alert {
         Ok(match alert {
             CliAlert::List(list) => Self::List(list.try_into()?),
             CliAlert::View(view) => Self::View(view.try_into()?),
+            CliAlert::Update(update) => Self::Update(update.try_into()?),
         })
     }
 }
@@ -28,6 +31,7 @@ impl SubCmd for Alert {
         match self {
             Self::List(list) => list.exec().await,
             Self::View(create) => create.exec().await,
+            Self::Update(update) => update.exec().await,
         }
     }
 }
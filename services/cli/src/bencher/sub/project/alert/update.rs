@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{
+    project::alert::{JsonAlertStatus, JsonUpdateAlert},
+    ResourceId,
+};
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::alert::{CliAlertStatus, CliAlertUpdate},
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ResourceId,
+    pub alert: Uuid,
+    pub status: Option<JsonAlertStatus>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliAlertUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliAlertUpdate) -> Result<Self, Self::Error> {
+        let CliAlertUpdate {
+            project,
+            alert,
+            status,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            alert,
+            status: status.map(Into::into),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliAlertStatus> for JsonAlertStatus {
+    fn from(status: CliAlertStatus) -> Self {
+        match status {
+            CliAlertStatus::Acknowledged => Self::Acknowledged,
+            CliAlertStatus::Dismissed => Self::Dismissed,
+        }
+    }
+}
+
+impl From<Update> for JsonUpdateAlert {
+    fn from(update: Update) -> Self {
+        Self {
+            status: update.status,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let update: JsonUpdateAlert = self.clone().into();
+        self.backend
+            .patch(
+                &format!("/v0/projects/{}/alerts/{}", self.project, self.alert),
+                &update,
+            )
+            .await?;
+        Ok(())
+    }
+}
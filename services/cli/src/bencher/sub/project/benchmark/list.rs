@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{project::benchmark::JsonBenchmarksSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::benchmark::CliBenchmarkList,
+    cli::project::benchmark::{CliBenchmarkList, CliBenchmarksSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub pagination: JsonPagination<JsonBenchmarksSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,36 @@ impl TryFrom<CliBenchmarkList> for List {
     type Error = CliError;
 
     fn try_from(list: CliBenchmarkList) -> Result<Self, Self::Error> {
-        let CliBenchmarkList { project, backend } = list;
+        let CliBenchmarkList {
+            project,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             project,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliBenchmarksSort> for JsonBenchmarksSort {
+    fn from(sort: CliBenchmarksSort) -> Self {
+        match sort {
+            CliBenchmarksSort::Name => Self::Name,
+            CliBenchmarksSort::Created => Self::Created,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/projects/{}/benchmarks", self.project))
+            .get_ls(
+                &format!("/v0/projects/{}/benchmarks", self.project),
+                &self.pagination,
+            )
             .await?;
         Ok(())
     }
@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{project::branch::JsonBranchesSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::branch::CliBranchList,
+    cli::project::branch::{CliBranchList, CliBranchesSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub pagination: JsonPagination<JsonBranchesSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,36 @@ impl TryFrom<CliBranchList> for List {
     type Error = CliError;
 
     fn try_from(list: CliBranchList) -> Result<Self, Self::Error> {
-        let CliBranchList { project, backend } = list;
+        let CliBranchList {
+            project,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             project,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliBranchesSort> for JsonBranchesSort {
+    fn from(sort: CliBranchesSort) -> Self {
+        match sort {
+            CliBranchesSort::Name => Self::Name,
+            CliBranchesSort::Created => Self::Created,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/projects/{}/branches", self.project))
+            .get_ls(
+                &format!("/v0/projects/{}/branches", self.project),
+                &self.pagination,
+            )
             .await?;
         Ok(())
     }
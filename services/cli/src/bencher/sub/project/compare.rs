@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{JsonCompare, JsonCompareQuery, ResourceId};
+use tabled::Table;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::compare::CliCompare,
+    cli_println, CliError,
+};
+
+#[derive(Debug)]
+pub struct Compare {
+    pub project: ResourceId,
+    pub query: JsonCompareQuery,
+    pub table: bool,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliCompare> for Compare {
+    type Error = CliError;
+
+    fn try_from(compare: CliCompare) -> Result<Self, Self::Error> {
+        let CliCompare {
+            project,
+            base,
+            head,
+            testbed,
+            branch,
+            table,
+            backend,
+        } = compare;
+        Ok(Self {
+            project,
+            query: JsonCompareQuery {
+                base,
+                head,
+                testbed,
+                branch,
+            },
+            table,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Compare {
+    async fn exec(&self) -> Result<(), CliError> {
+        let resp = self
+            .backend
+            .get_query(
+                &format!("/v0/projects/{}/compare", self.project),
+                &self.query,
+            )
+            .await?;
+        if self.table {
+            let json_compare: JsonCompare = serde_json::from_value(resp)?;
+            let compare_table: Table = json_compare.into();
+            cli_println!("{compare_table}");
+        }
+        Ok(())
+    }
+}
@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{project::metric_kind::JsonMetricKindsSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::metric_kind::CliMetricKindList,
+    cli::project::metric_kind::{CliMetricKindList, CliMetricKindsSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub pagination: JsonPagination<JsonMetricKindsSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,36 @@ impl TryFrom<CliMetricKindList> for List {
     type Error = CliError;
 
     fn try_from(list: CliMetricKindList) -> Result<Self, Self::Error> {
-        let CliMetricKindList { project, backend } = list;
+        let CliMetricKindList {
+            project,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             project,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliMetricKindsSort> for JsonMetricKindsSort {
+    fn from(sort: CliMetricKindsSort) -> Self {
+        match sort {
+            CliMetricKindsSort::Name => Self::Name,
+            CliMetricKindsSort::Created => Self::Created,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/projects/{}/metric-kinds", self.project))
+            .get_ls(
+                &format!("/v0/projects/{}/metric-kinds", self.project),
+                &self.pagination,
+            )
             .await?;
         Ok(())
     }
@@ -1,7 +1,9 @@
 pub mod alert;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
 pub mod metric_kind;
+pub mod notification;
 pub mod perf;
 #[allow(clippy::module_inception)]
 pub mod project;
@@ -10,3 +12,4 @@ pub mod result;
 pub mod run;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
@@ -0,0 +1,55 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{Email, JsonNewNotification, ResourceId};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::notification::CliNotificationCreate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ResourceId,
+    pub email: Email,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliNotificationCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliNotificationCreate) -> Result<Self, Self::Error> {
+        let CliNotificationCreate {
+            project,
+            email,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            email,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewNotification {
+    fn from(create: Create) -> Self {
+        let Create { email, .. } = create;
+        Self { email }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let notification: JsonNewNotification = self.clone().into();
+        self.backend
+            .post(
+                &format!("/v0/projects/{}/notifications", self.project),
+                &notification,
+            )
+            .await?;
+        Ok(())
+    }
+}
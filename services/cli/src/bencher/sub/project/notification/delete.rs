@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::notification::CliNotificationDelete,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub project: ResourceId,
+    pub notification: Uuid,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliNotificationDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliNotificationDelete) -> Result<Self, Self::Error> {
+        let CliNotificationDelete {
+            project,
+            notification,
+            backend,
+        } = delete;
+        Ok(Self {
+            project,
+            notification,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .delete(&format!(
+                "/v0/projects/{}/notifications/{}",
+                self.project, self.notification
+            ))
+            .await?;
+        Ok(())
+    }
+}
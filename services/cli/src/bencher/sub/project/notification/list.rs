@@ -0,0 +1,56 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{project::notification::JsonNotificationsSort, JsonPagination, ResourceId};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::notification::{CliNotificationList, CliNotificationsSort},
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct List {
+    pub project: ResourceId,
+    pub pagination: JsonPagination<JsonNotificationsSort>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliNotificationList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliNotificationList) -> Result<Self, Self::Error> {
+        let CliNotificationList {
+            project,
+            pagination,
+            backend,
+        } = list;
+        Ok(Self {
+            project,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliNotificationsSort> for JsonNotificationsSort {
+    fn from(sort: CliNotificationsSort) -> Self {
+        match sort {
+            CliNotificationsSort::Created => Self::Created,
+            CliNotificationsSort::Email => Self::Email,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get_ls(
+                &format!("/v0/projects/{}/notifications", self.project),
+                &self.pagination,
+            )
+            .await?;
+        Ok(())
+    }
+}
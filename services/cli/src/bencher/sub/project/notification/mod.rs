@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::{bencher::sub::SubCmd, cli::project::notification::CliNotification, CliError};
+
+mod create;
+mod delete;
+mod list;
+mod view;
+
+#[derive(Debug)]
+pub enum Notification {
+    List(list::List),
+    Create(create::Create),
+    View(view::View),
+    Delete(delete::Delete),
+}
+
+impl TryFrom<CliNotification> for Notification {
+    type Error = CliError;
+
+    fn try_from(notification: CliNotification) -> Result<Self, Self::Error> {
+        Ok(match notification {
+            CliNotification::List(list) => Self::List(list.try_into()?),
+            CliNotification::Create(create) => Self::Create(create.try_into()?),
+            CliNotification::View(view) => Self::View(view.try_into()?),
+            CliNotification::Delete(delete) => Self::Delete(delete.try_into()?),
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Notification {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::notification::CliNotificationView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub project: ResourceId,
+    pub notification: Uuid,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliNotificationView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliNotificationView) -> Result<Self, Self::Error> {
+        let CliNotificationView {
+            project,
+            notification,
+            backend,
+        } = view;
+        Ok(Self {
+            project,
+            notification,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get(&format!(
+                "/v0/projects/{}/notifications/{}",
+                self.project, self.notification
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::JsonPerfChangepoints;
+use tabled::Table;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::perf::CliPerfChangepoints,
+    cli_println, CliError,
+};
+
+use super::PerfQuery;
+
+#[derive(Debug, Clone)]
+pub struct Changepoints {
+    pub query: PerfQuery,
+    pub table: bool,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliPerfChangepoints> for Changepoints {
+    type Error = CliError;
+
+    fn try_from(changepoints: CliPerfChangepoints) -> Result<Self, Self::Error> {
+        let CliPerfChangepoints {
+            query,
+            table,
+            backend,
+        } = changepoints;
+        Ok(Self {
+            query: query.try_into()?,
+            table,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Changepoints {
+    async fn exec(&self) -> Result<(), CliError> {
+        let resp = self
+            .backend
+            .get_query(
+                &format!("/v0/projects/{}/perf/changepoints", self.query.project),
+                &self.query.json,
+            )
+            .await?;
+        if self.table {
+            let json_changepoints: JsonPerfChangepoints = serde_json::from_value(resp)?;
+            let changepoint_table: Table = json_changepoints.into();
+            cli_println!("{changepoint_table}");
+        }
+        Ok(())
+    }
+}
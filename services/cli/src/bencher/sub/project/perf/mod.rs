@@ -1,27 +1,22 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::{JsonPerf, JsonPerfQuery, ResourceId};
+use bencher_json::{JsonPerfQuery, ResourceId};
 use chrono::serde::ts_milliseconds_option::deserialize as from_milli_ts;
-use chrono::{DateTime, Utc};
-use tabled::Table;
-use uuid::Uuid;
 
-use crate::{bencher::backend::Backend, cli::project::perf::CliPerf, cli_println, CliError};
+use crate::{
+    bencher::sub::SubCmd,
+    cli::project::perf::{CliPerf, CliPerfFilter, CliPerfQuery, CliPerfSub},
+    CliError,
+};
 
-use crate::bencher::SubCmd;
+mod changepoints;
+mod query;
 
-#[derive(Debug, Clone)]
-pub struct Perf {
-    project: ResourceId,
-    metric_kind: ResourceId,
-    branches: Vec<Uuid>,
-    testbeds: Vec<Uuid>,
-    benchmarks: Vec<Uuid>,
-    start_time: Option<DateTime<Utc>>,
-    end_time: Option<DateTime<Utc>>,
-    table: bool,
-    backend: Backend,
+#[derive(Debug)]
+pub enum Perf {
+    Query(query::Query),
+    Changepoints(changepoints::Changepoints),
 }
 
 impl TryFrom<CliPerf> for Perf {
@@ -29,65 +24,73 @@ impl TryFrom<CliPerf> for Perf {
 
     fn try_from(perf: CliPerf) -> Result<Self, Self::Error> {
         let CliPerf {
+            sub,
             project,
             metric_kind,
-            branches,
-            testbeds,
-            benchmarks,
-            start_time,
-            end_time,
+            filter,
             table,
             backend,
         } = perf;
-        Ok(Self {
-            project,
-            metric_kind,
-            branches,
-            testbeds,
-            benchmarks,
-            start_time: from_milli_ts(serde_json::json!(start_time))?,
-            end_time: from_milli_ts(serde_json::json!(end_time))?,
-            table,
-            backend: backend.try_into()?,
+        Ok(match sub {
+            Some(CliPerfSub::Changepoints(changepoints)) => {
+                Self::Changepoints(changepoints.try_into()?)
+            },
+            None => Self::Query(query::Query {
+                query: CliPerfQuery {
+                    project: project.ok_or(CliError::ProjectNotFound)?,
+                    metric_kind: metric_kind.ok_or(CliError::MetricKindNotFound)?,
+                    filter,
+                }
+                .try_into()?,
+                table,
+                backend: backend.try_into()?,
+            }),
         })
     }
 }
 
-impl From<Perf> for JsonPerfQuery {
-    fn from(perf: Perf) -> Self {
-        let Perf {
-            metric_kind,
-            branches,
-            testbeds,
-            benchmarks,
-            start_time,
-            end_time,
-            ..
-        } = perf;
-        Self {
-            metric_kind,
-            branches,
-            testbeds,
-            benchmarks,
-            start_time,
-            end_time,
-        }
-    }
-}
-
 #[async_trait]
 impl SubCmd for Perf {
     async fn exec(&self) -> Result<(), CliError> {
-        let perf: JsonPerfQuery = self.clone().into();
-        let resp = self
-            .backend
-            .get_query(&format!("/v0/projects/{}/perf", self.project), &perf)
-            .await?;
-        if self.table {
-            let json_perf: JsonPerf = serde_json::from_value(resp)?;
-            let perf_table: Table = json_perf.into();
-            cli_println!("{perf_table}");
+        match self {
+            Self::Query(query) => query.exec().await,
+            Self::Changepoints(changepoints) => changepoints.exec().await,
         }
-        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PerfQuery {
+    pub project: ResourceId,
+    pub json: JsonPerfQuery,
+}
+
+impl TryFrom<CliPerfQuery> for PerfQuery {
+    type Error = CliError;
+
+    fn try_from(query: CliPerfQuery) -> Result<Self, Self::Error> {
+        let CliPerfQuery {
+            project,
+            metric_kind,
+            filter:
+                CliPerfFilter {
+                    branches,
+                    testbeds,
+                    benchmarks,
+                    start_time,
+                    end_time,
+                },
+        } = query;
+        Ok(Self {
+            project,
+            json: JsonPerfQuery {
+                metric_kind,
+                branches,
+                testbeds,
+                benchmarks,
+                start_time: from_milli_ts(serde_json::json!(start_time))?,
+                end_time: from_milli_ts(serde_json::json!(end_time))?,
+            },
+        })
     }
 }
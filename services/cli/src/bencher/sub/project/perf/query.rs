@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use bencher_json::JsonPerf;
+use tabled::Table;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli_println, CliError,
+};
+
+use super::PerfQuery;
+
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub query: PerfQuery,
+    pub table: bool,
+    pub backend: Backend,
+}
+
+#[async_trait]
+impl SubCmd for Query {
+    async fn exec(&self) -> Result<(), CliError> {
+        let resp = self
+            .backend
+            .get_query(
+                &format!("/v0/projects/{}/perf", self.query.project),
+                &self.query.json,
+            )
+            .await?;
+        if self.table {
+            let json_perf: JsonPerf = serde_json::from_value(resp)?;
+            let perf_table: Table = json_perf.into();
+            cli_println!("{perf_table}");
+        }
+        Ok(())
+    }
+}
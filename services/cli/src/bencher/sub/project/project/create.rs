@@ -16,6 +16,7 @@ pub struct Create {
     pub slug: Option<Slug>,
     pub url: Option<Url>,
     pub visibility: Option<Visibility>,
+    pub auto_metric_kinds: bool,
     pub backend: Backend,
 }
 
@@ -36,6 +37,7 @@ impl TryFrom<CliProjectCreate> for Create {
             slug,
             url,
             visibility,
+            auto_metric_kinds,
             backend,
         } = create;
         Ok(Self {
@@ -44,6 +46,7 @@ impl TryFrom<CliProjectCreate> for Create {
             slug,
             url,
             visibility: visibility.map(Into::into),
+            auto_metric_kinds,
             backend: backend.try_into()?,
         })
     }
@@ -66,6 +69,7 @@ impl From<Create> for JsonNewProject {
             slug,
             url,
             visibility,
+            auto_metric_kinds,
             ..
         } = create;
         Self {
@@ -73,6 +77,7 @@ impl From<Create> for JsonNewProject {
             slug,
             url,
             visibility: visibility.map(Into::into),
+            auto_metric_kinds: Some(auto_metric_kinds),
         }
     }
 }
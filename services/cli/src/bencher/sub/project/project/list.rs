@@ -1,11 +1,14 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::{project::JsonProjects, ResourceId};
+use bencher_json::{
+    project::{JsonProjects, JsonProjectsSort},
+    JsonPagination, ResourceId,
+};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::CliProjectList,
+    cli::project::{CliProjectList, CliProjectsSort},
     CliError,
 };
 
@@ -13,6 +16,7 @@ use crate::{
 pub struct List {
     pub org: Option<ResourceId>,
     pub public: bool,
+    pub pagination: JsonPagination<JsonProjectsSort>,
     pub backend: Backend,
 }
 
@@ -23,11 +27,13 @@ impl TryFrom<CliProjectList> for List {
         let CliProjectList {
             org,
             public,
+            pagination,
             backend,
         } = list;
         Ok(Self {
             org,
             public,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
@@ -42,17 +48,29 @@ impl From<List> for JsonProjects {
     }
 }
 
+impl From<CliProjectsSort> for JsonProjectsSort {
+    fn from(sort: CliProjectsSort) -> Self {
+        match sort {
+            CliProjectsSort::Name => Self::Name,
+            CliProjectsSort::Created => Self::Created,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         if let Some(org) = &self.org {
             self.backend
-                .get(&format!("/v0/organizations/{org}/projects"))
+                .get_ls(
+                    &format!("/v0/organizations/{org}/projects"),
+                    &self.pagination,
+                )
                 .await?;
         } else {
             let json_projects: JsonProjects = self.clone().into();
             self.backend
-                .get_query("/v0/projects", &json_projects)
+                .get_ls_query("/v0/projects", &self.pagination, &json_projects)
                 .await?;
         }
 
@@ -4,6 +4,7 @@ use crate::{bencher::sub::SubCmd, cli::project::CliProject, CliError};
 
 mod create;
 mod list;
+mod update;
 mod view;
 
 #[derive(Debug)]
@@ -11,6 +12,7 @@ pub enum Project {
     Create(create::Create),
     List(list::List),
     View(view::View),
+    Update(update::Update),
 }
 
 impl TryFrom<CliProject> for Project {
@@ -21,6 +23,7 @@ impl TryFrom<CliProject> for Project {
             CliProject::Create(create) => Self::Create(create.try_into()?),
             CliProject::List(list) => Self::List(list.try_into()?),
             CliProject::View(view) => Self::View(view.try_into()?),
+            CliProject::Update(update) => Self::Update(update.try_into()?),
         })
     }
 }
@@ -32,6 +35,7 @@ impl SubCmd for Project {
             Self::Create(create) => create.exec().await,
             Self::List(list) => list.exec().await,
             Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
         }
     }
 }
@@ -0,0 +1,54 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{project::JsonUpdateProject, ResourceId};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::CliProjectUpdate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ResourceId,
+    pub auto_metric_kinds: Option<bool>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliProjectUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliProjectUpdate) -> Result<Self, Self::Error> {
+        let CliProjectUpdate {
+            project,
+            auto_metric_kinds,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            auto_metric_kinds,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateProject {
+    fn from(update: Update) -> Self {
+        let Update {
+            auto_metric_kinds, ..
+        } = update;
+        Self { auto_metric_kinds }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let update: JsonUpdateProject = self.clone().into();
+        self.backend
+            .patch(&format!("/v0/projects/{}", self.project), &update)
+            .await?;
+        Ok(())
+    }
+}
@@ -1,17 +1,22 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{
+    project::report::{JsonReports, JsonReportsSort},
+    JsonPagination, ResourceId,
+};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::report::CliReportList,
+    cli::project::report::{CliReportList, CliReportsSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub query: JsonReports,
+    pub pagination: JsonPagination<JsonReportsSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +24,51 @@ impl TryFrom<CliReportList> for List {
     type Error = CliError;
 
     fn try_from(list: CliReportList) -> Result<Self, Self::Error> {
-        let CliReportList { project, backend } = list;
+        let CliReportList {
+            project,
+            hash,
+            branch,
+            testbed,
+            user,
+            since,
+            until,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             project,
+            query: JsonReports {
+                hash,
+                branch,
+                testbed,
+                user,
+                start_time: since,
+                end_time: until,
+            },
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliReportsSort> for JsonReportsSort {
+    fn from(sort: CliReportsSort) -> Self {
+        match sort {
+            CliReportsSort::DateTime => Self::DateTime,
+            CliReportsSort::Version => Self::Version,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/projects/{}/reports", self.project))
+            .get_ls_query(
+                &format!("/v0/projects/{}/reports", self.project),
+                &self.pagination,
+                &self.query,
+            )
             .await?;
         Ok(())
     }
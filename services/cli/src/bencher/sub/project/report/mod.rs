@@ -4,6 +4,8 @@ use super::run::Run as Create;
 use crate::{bencher::sub::SubCmd, cli::project::report::CliReport, CliError};
 
 mod list;
+mod output;
+mod upload;
 mod view;
 
 #[derive(Debug)]
@@ -11,6 +13,8 @@ pub enum Report {
     List(list::List),
     Create(Box<Create>),
     View(view::View),
+    Output(output::Output),
+    Upload(upload::Upload),
 }
 
 impl TryFrom<CliReport> for Report {
@@ -21,6 +25,8 @@ impl TryFrom<CliReport> for Report {
             CliReport::List(list) => Self::List(list.try_into()?),
             CliReport::Create(create) => Self::Create(Box::new((*create).try_into()?)),
             CliReport::View(view) => Self::View(view.try_into()?),
+            CliReport::Output(output) => Self::Output(output.try_into()?),
+            CliReport::Upload(upload) => Self::Upload(upload.try_into()?),
         })
     }
 }
@@ -32,6 +38,8 @@ impl SubCmd for Report {
             Self::List(list) => list.exec().await,
             Self::Create(create) => create.exec().await,
             Self::View(create) => create.exec().await,
+            Self::Output(output) => output.exec().await,
+            Self::Upload(upload) => upload.exec().await,
         }
     }
 }
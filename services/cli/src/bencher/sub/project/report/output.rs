@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::report::CliReportView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Output {
+    pub project: ResourceId,
+    pub report: Uuid,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliReportView> for Output {
+    type Error = CliError;
+
+    fn try_from(output: CliReportView) -> Result<Self, Self::Error> {
+        let CliReportView {
+            project,
+            report,
+            backend,
+        } = output;
+        Ok(Self {
+            project,
+            report,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Output {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get(&format!(
+                "/v0/projects/{}/reports/{}/output",
+                self.project, self.report
+            ))
+            .await?;
+        Ok(())
+    }
+}
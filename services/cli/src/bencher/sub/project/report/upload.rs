@@ -0,0 +1,87 @@
+use std::{convert::TryFrom, fs};
+
+use async_trait::async_trait;
+use bencher_json::JsonReport;
+
+use crate::{
+    bencher::{backend::Backend, spool::Spool, sub::SubCmd},
+    cli::project::report::CliReportUpload,
+    cli_eprintln, cli_println, CliError,
+};
+
+#[derive(Debug)]
+pub struct Upload {
+    pub spool: Spool,
+    pub skip_failed: bool,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliReportUpload> for Upload {
+    type Error = CliError;
+
+    fn try_from(upload: CliReportUpload) -> Result<Self, Self::Error> {
+        let CliReportUpload {
+            spool_dir,
+            skip_failed,
+            backend,
+        } = upload;
+        Ok(Self {
+            spool: spool_dir.into(),
+            skip_failed,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Upload {
+    async fn exec(&self) -> Result<(), CliError> {
+        let spooled_reports = self.spool.load()?;
+        let total = spooled_reports.len();
+        let mut failed = 0;
+        // Upload in the order that the reports were run and stop at the first rejected report,
+        // so that the remaining reports stay spooled in order.
+        // Unless rejected reports are skipped, in which case they are moved aside.
+        for (count, (path, spooled_report)) in spooled_reports.into_iter().enumerate() {
+            let value = self
+                .backend
+                .post(
+                    &format!("/v0/projects/{}/reports", spooled_report.project),
+                    &spooled_report.report,
+                )
+                .await?;
+            if serde_json::from_value::<JsonReport>(value.clone()).is_err() {
+                // Report the error from the server, not just the report
+                let error = value
+                    .get("message")
+                    .and_then(serde_json::Value::as_str)
+                    .map_or_else(|| value.to_string(), ToOwned::to_owned);
+                if !self.skip_failed {
+                    return Err(CliError::SpoolUpload(path.display().to_string(), error));
+                }
+                let failed_path = self.spool.fail(&path)?;
+                failed += 1;
+                cli_eprintln!(
+                    "Failed to upload spooled report {}/{total}: {error}\nMoved it to: {}",
+                    count + 1,
+                    failed_path.display()
+                );
+                continue;
+            }
+            fs::remove_file(&path)?;
+            cli_println!(
+                "Uploaded spooled report {}/{total}: {}",
+                count + 1,
+                path.display()
+            );
+        }
+        cli_println!(
+            "Uploaded {} spooled report(s).",
+            total.checked_sub(failed).ok_or(CliError::BadMath)?
+        );
+        if failed > 0 {
+            cli_eprintln!("Failed to upload {failed} spooled report(s).");
+        }
+        Ok(())
+    }
+}
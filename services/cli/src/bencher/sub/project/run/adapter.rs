@@ -1,6 +1,11 @@
-use bencher_json::project::report::JsonAdapter;
+use std::path::Path;
 
-use crate::cli::project::run::CliRunAdapter;
+use bencher_adapter::AdapterCustom;
+use bencher_json::project::report::{JsonAdapter, JsonAdapterConfig};
+
+use crate::{cli::project::run::CliRunAdapter, CliError};
+
+const TOML_EXTENSION: &str = "toml";
 
 #[derive(Debug, Clone, Copy)]
 pub enum RunAdapter {
@@ -26,6 +31,10 @@ pub enum RunAdapter {
     Rust,
     RustBench,
     RustCriterion,
+    RustCriterionJson,
+    RustIai,
+    Shell,
+    ShellHyperfine,
 }
 
 impl From<CliRunAdapter> for RunAdapter {
@@ -53,6 +62,10 @@ impl From<CliRunAdapter> for RunAdapter {
             CliRunAdapter::Rust => Self::Rust,
             CliRunAdapter::RustBench => Self::RustBench,
             CliRunAdapter::RustCriterion => Self::RustCriterion,
+            CliRunAdapter::RustCriterionJson => Self::RustCriterionJson,
+            CliRunAdapter::RustIai => Self::RustIai,
+            CliRunAdapter::Shell => Self::Shell,
+            CliRunAdapter::ShellHyperfine => Self::ShellHyperfine,
         }
     }
 }
@@ -82,6 +95,23 @@ impl From<RunAdapter> for JsonAdapter {
             RunAdapter::Rust => Self::Rust,
             RunAdapter::RustBench => Self::RustBench,
             RunAdapter::RustCriterion => Self::RustCriterion,
+            RunAdapter::RustCriterionJson => Self::RustCriterionJson,
+            RunAdapter::RustIai => Self::RustIai,
+            RunAdapter::Shell => Self::Shell,
+            RunAdapter::ShellHyperfine => Self::ShellHyperfine,
         }
     }
 }
+
+pub fn adapter_config(config_path: &Path) -> Result<JsonAdapterConfig, CliError> {
+    let config = std::fs::read_to_string(config_path)?;
+    let adapter_config: JsonAdapterConfig =
+        if config_path.extension().and_then(|ext| ext.to_str()) == Some(TOML_EXTENSION) {
+            toml::from_str(&config)?
+        } else {
+            serde_json::from_str(&config)?
+        };
+    // Validate the adapter config before running any benchmarks
+    AdapterCustom::try_from(&adapter_config)?;
+    Ok(adapter_config)
+}
@@ -0,0 +1,109 @@
+use std::{collections::BTreeMap, process::Command};
+
+use bencher_json::project::testbed::JsonFingerprint;
+
+use crate::cli::project::run::CliRunFingerprint;
+
+#[derive(Debug)]
+pub struct Fingerprint {
+    toolchains: Vec<String>,
+    env: Vec<String>,
+}
+
+impl Fingerprint {
+    pub fn new(fingerprint: CliRunFingerprint) -> Option<Self> {
+        let CliRunFingerprint {
+            no_fingerprint,
+            fingerprint_toolchain,
+            fingerprint_env,
+        } = fingerprint;
+        (!no_fingerprint).then_some(Self {
+            toolchains: fingerprint_toolchain,
+            env: fingerprint_env,
+        })
+    }
+
+    /// Collect the testbed hardware and software fingerprint.
+    /// Anything that can not be collected is left out instead of failing the run.
+    pub fn collect(&self) -> JsonFingerprint {
+        let mut fingerprint = JsonFingerprint {
+            arch: Some(std::env::consts::ARCH.into()),
+            toolchains: self
+                .toolchains
+                .iter()
+                .filter_map(|toolchain| Some((toolchain.clone(), toolchain_version(toolchain)?)))
+                .collect(),
+            env: self
+                .env
+                .iter()
+                .filter_map(|key| Some((key.clone(), std::env::var(key).ok()?)))
+                .collect::<BTreeMap<_, _>>(),
+            ..Default::default()
+        };
+        #[cfg(target_os = "linux")]
+        linux::collect(&mut fingerprint);
+        fingerprint
+    }
+}
+
+/// The first line of the `--version` output for a toolchain command (ie `rustc 1.68.1 (8460ca823 2023-03-20)`)
+fn toolchain_version(toolchain: &str) -> Option<String> {
+    let output = Command::new(toolchain).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_owned())
+        .filter(|line| !line.is_empty())
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::fs;
+
+    use bencher_json::project::testbed::JsonFingerprint;
+
+    const CPUINFO: &str = "/proc/cpuinfo";
+    const MEMINFO: &str = "/proc/meminfo";
+    const OSTYPE: &str = "/proc/sys/kernel/ostype";
+    const OSRELEASE: &str = "/proc/sys/kernel/osrelease";
+
+    pub fn collect(fingerprint: &mut JsonFingerprint) {
+        if let Ok(cpuinfo) = fs::read_to_string(CPUINFO) {
+            fingerprint.cpu = cpuinfo_values(&cpuinfo, "model name")
+                .next()
+                .map(Into::into);
+            let cores = cpuinfo_values(&cpuinfo, "processor").count();
+            fingerprint.cores = u32::try_from(cores).ok().filter(|cores| *cores > 0);
+        }
+        if let Ok(meminfo) = fs::read_to_string(MEMINFO) {
+            fingerprint.memory = mem_total(&meminfo);
+        }
+        fingerprint.os = read_trimmed(OSTYPE);
+        fingerprint.kernel = read_trimmed(OSRELEASE);
+    }
+
+    fn cpuinfo_values<'a>(cpuinfo: &'a str, key: &'a str) -> impl Iterator<Item = &'a str> {
+        cpuinfo.lines().filter_map(move |line| {
+            let (k, v) = line.split_once(':')?;
+            (k.trim() == key).then_some(v.trim())
+        })
+    }
+
+    // The total memory is reported in kibibytes (ie `MemTotal:       16318480 kB`)
+    fn mem_total(meminfo: &str) -> Option<u64> {
+        meminfo.lines().find_map(|line| {
+            let kibibytes = line.strip_prefix("MemTotal:")?.trim().strip_suffix("kB")?;
+            kibibytes.trim().parse::<u64>().ok()?.checked_mul(1024)
+        })
+    }
+
+    fn read_trimmed(path: &str) -> Option<String> {
+        fs::read_to_string(path)
+            .ok()
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !value.is_empty())
+    }
+}
@@ -0,0 +1,540 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use bencher_adapter::{
+    results::adapter_results::AdapterResults, AdapterCustom, AdapterResultsArray,
+    Settings as AdapterSettings,
+};
+use bencher_boundary::{
+    stats::{mean, percent_change},
+    Boundary, Detection,
+};
+use bencher_json::{
+    project::{
+        alert::JsonSide,
+        compare::table::{Percent, Value},
+        report::{JsonAdapter, JsonReportSettings},
+        threshold::JsonNewStatistic,
+    },
+    BenchmarkName, GitHash, JsonNewReport, ResourceId,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tabled::{Table, TableIteratorExt, Tabled};
+use uuid::Uuid;
+
+use crate::{cli_println, CliError};
+
+const REPORTS_DIR: &str = "reports";
+const THRESHOLDS_FILE: &str = "thresholds.json";
+
+/// Metric values for each benchmark and metric kind, one per iteration
+type LocalResults = BTreeMap<String, BTreeMap<ResourceId, Vec<f64>>>;
+
+/// A directory of JSON reports and thresholds for running without a backend
+#[derive(Debug)]
+pub struct LocalStore<'a> {
+    dir: &'a Path,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalReport {
+    pub uuid: Uuid,
+    pub project: ResourceId,
+    pub branch: ResourceId,
+    pub hash: Option<GitHash>,
+    pub testbed: ResourceId,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub results: LocalResults,
+    pub alerts: Vec<LocalAlert>,
+}
+
+/// A local threshold is the same as a threshold on the backend,
+/// except that the branch and testbed are optional and match any branch or testbed if not set.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LocalThreshold {
+    pub branch: Option<ResourceId>,
+    pub testbed: Option<ResourceId>,
+    pub metric_kind: ResourceId,
+    #[serde(flatten)]
+    pub statistic: JsonNewStatistic,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalAlert {
+    pub benchmark: String,
+    pub metric_kind: ResourceId,
+    pub iteration: usize,
+    pub side: JsonSide,
+    pub limit: f32,
+    pub outlier: f64,
+}
+
+impl<'a> LocalStore<'a> {
+    pub fn new(dir: &'a Path) -> Self {
+        Self { dir }
+    }
+
+    /// Evaluate a report against the local thresholds and previous local reports,
+    /// print the before and after difference, and then save it.
+    pub fn run(
+        &self,
+        project: &ResourceId,
+        report: &JsonNewReport,
+    ) -> Result<LocalReport, CliError> {
+        let results = local_results(report)?;
+        let history = self.history(project, &report.branch, &report.testbed)?;
+        let thresholds = self.thresholds()?;
+
+        let mut alerts = Vec::new();
+        for (benchmark, metric_kinds) in &results {
+            // Ignored benchmarks do not get checked against the threshold even if one exists
+            if benchmark.parse::<BenchmarkName>()?.is_ignored() {
+                continue;
+            }
+            for (metric_kind, values) in metric_kinds {
+                let Some(threshold) = thresholds.iter().find(|threshold| {
+                    threshold.is_match(&report.branch, &report.testbed, metric_kind)
+                }) else {
+                    continue;
+                };
+                alerts.extend(threshold.detect(
+                    benchmark,
+                    metric_kind,
+                    values,
+                    &history,
+                    report.start_time,
+                )?);
+            }
+        }
+
+        let local_report = LocalReport {
+            uuid: Uuid::new_v4(),
+            project: project.clone(),
+            branch: report.branch.clone(),
+            hash: report.hash.clone(),
+            testbed: report.testbed.clone(),
+            start_time: report.start_time,
+            end_time: report.end_time,
+            results,
+            alerts,
+        };
+
+        cli_println!("{}", Diff::new(history.first(), &local_report).table());
+        for alert in &local_report.alerts {
+            cli_println!("{alert}");
+        }
+
+        let reports_dir = self.dir.join(REPORTS_DIR);
+        fs::create_dir_all(&reports_dir)?;
+        let path = reports_dir.join(format!("{}.json", local_report.uuid));
+        fs::write(&path, serde_json::to_string_pretty(&local_report)?)?;
+        cli_println!("Local report saved to: {}", path.display());
+
+        Ok(local_report)
+    }
+
+    /// The previous local reports for the project, branch, and testbed from most to least recent
+    fn history(
+        &self,
+        project: &ResourceId,
+        branch: &ResourceId,
+        testbed: &ResourceId,
+    ) -> Result<Vec<LocalReport>, CliError> {
+        let reports_dir = self.dir.join(REPORTS_DIR);
+        if !reports_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut history = Vec::new();
+        for entry in fs::read_dir(reports_dir)? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .map_or(true, |extension| extension != "json")
+            {
+                continue;
+            }
+            let local_report: LocalReport = serde_json::from_str(&fs::read_to_string(&path)?)?;
+            if &local_report.project == project
+                && &local_report.branch == branch
+                && &local_report.testbed == testbed
+            {
+                history.push(local_report);
+            }
+        }
+        history.sort_by(|a, b| b.start_time.cmp(&a.start_time));
+
+        Ok(history)
+    }
+
+    fn thresholds(&self) -> Result<Vec<LocalThreshold>, CliError> {
+        let path = self.dir.join(THRESHOLDS_FILE);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+}
+
+/// Parse the results with the same adapter settings that the backend would use
+fn local_results(report: &JsonNewReport) -> Result<LocalResults, CliError> {
+    let JsonReportSettings {
+        adapter,
+        average,
+        fold,
+        adapter_config,
+        ..
+    } = report.settings.clone().unwrap_or_default();
+    let results_array = report
+        .results
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let adapter_settings = AdapterSettings::new(average);
+    let results_array = if let Some(adapter_config) = &adapter_config {
+        let adapter_custom = AdapterCustom::try_from(adapter_config)?;
+        AdapterResultsArray::new(&results_array, &adapter_custom, adapter_settings)?
+    } else {
+        AdapterResultsArray::new(
+            &results_array,
+            &adapter.unwrap_or(JsonAdapter::Magic),
+            adapter_settings,
+        )?
+    };
+
+    let results_array = if let Some(fold) = fold {
+        vec![results_array.fold(fold)]
+    } else {
+        results_array.inner
+    };
+
+    let mut results = LocalResults::new();
+    for AdapterResults { inner } in results_array {
+        for (benchmark_name, metrics) in inner {
+            let metric_kinds = results.entry(benchmark_name.to_string()).or_default();
+            for (metric_kind, metric) in metrics.inner {
+                metric_kinds
+                    .entry(metric_kind)
+                    .or_default()
+                    .push(metric.value.into());
+            }
+        }
+    }
+    Ok(results)
+}
+
+impl LocalThreshold {
+    fn is_match(
+        &self,
+        branch: &ResourceId,
+        testbed: &ResourceId,
+        metric_kind: &ResourceId,
+    ) -> bool {
+        &self.metric_kind == metric_kind
+            && self.branch.as_ref().map_or(true, |b| b == branch)
+            && self.testbed.as_ref().map_or(true, |t| t == testbed)
+    }
+
+    /// Check each iteration against the historical data, the same as the backend.
+    /// The data includes any earlier iterations, followed by the previous reports.
+    /// The datum itself is never part of its own baseline.
+    fn detect(
+        &self,
+        benchmark: &str,
+        metric_kind: &ResourceId,
+        values: &[f64],
+        history: &[LocalReport],
+        start_time: DateTime<Utc>,
+    ) -> Result<Vec<LocalAlert>, CliError> {
+        let historical_data = history
+            .iter()
+            .filter(|local_report| {
+                self.statistic.window.map_or(true, |window| {
+                    local_report.start_time >= start_time - Duration::seconds(window.into())
+                })
+            })
+            .filter_map(|local_report| local_report.results.get(benchmark)?.get(metric_kind))
+            .flat_map(|values| values.iter().rev().copied())
+            .collect::<Vec<_>>();
+
+        let mut alerts = Vec::new();
+        for (iteration, datum) in values.iter().copied().enumerate() {
+            let mut data = values
+                .iter()
+                .take(iteration)
+                .rev()
+                .chain(historical_data.iter())
+                .copied()
+                .collect::<Vec<_>>();
+            if let Some(max_sample_size) = self.statistic.max_sample_size {
+                data.truncate(max_sample_size as usize);
+            }
+            if let Detection::Outlier(Boundary {
+                side,
+                limit,
+                outlier,
+            }) = Boundary::detect(&self.statistic, &data, datum)?
+            {
+                alerts.push(LocalAlert {
+                    benchmark: benchmark.into(),
+                    metric_kind: metric_kind.clone(),
+                    iteration,
+                    side,
+                    limit,
+                    outlier,
+                });
+            }
+        }
+        Ok(alerts)
+    }
+}
+
+impl fmt::Display for LocalAlert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.side {
+            JsonSide::Left => "lower",
+            JsonSide::Right => "upper",
+        };
+        write!(
+            f,
+            "🚨 Alert: {} ({}) iteration {} is outside of the {side} boundary: {} > {}",
+            self.benchmark, self.metric_kind, self.iteration, self.outlier, self.limit
+        )
+    }
+}
+
+/// The difference between the previous and current local reports,
+/// with the metrics for each benchmark and metric kind averaged across iterations
+struct Diff(Vec<DiffRow>);
+
+#[derive(Tabled)]
+struct DiffRow {
+    #[tabled(rename = "Benchmark")]
+    benchmark: String,
+    #[tabled(rename = "Metric Kind")]
+    metric_kind: ResourceId,
+    #[tabled(rename = "Before")]
+    before: Value,
+    #[tabled(rename = "After")]
+    after: Value,
+    #[tabled(rename = "Change")]
+    percent: Percent,
+    #[tabled(rename = "Alerts")]
+    alerts: usize,
+}
+
+impl Diff {
+    fn new(before: Option<&LocalReport>, after: &LocalReport) -> Self {
+        let mut rows = Vec::new();
+        for (benchmark, metric_kinds) in &after.results {
+            for (metric_kind, values) in metric_kinds {
+                let before = before
+                    .and_then(|before| before.results.get(benchmark)?.get(metric_kind))
+                    .and_then(|values| mean(values));
+                let after_value = mean(values);
+                let percent = before
+                    .zip(after_value)
+                    .and_then(|(before, after)| percent_change(before, after));
+                rows.push(DiffRow {
+                    benchmark: benchmark.clone(),
+                    metric_kind: metric_kind.clone(),
+                    before: Value(before.map(Into::into)),
+                    after: Value(after_value.map(Into::into)),
+                    percent: Percent(percent.map(Into::into)),
+                    alerts: after
+                        .alerts
+                        .iter()
+                        .filter(|alert| {
+                            &alert.benchmark == benchmark && &alert.metric_kind == metric_kind
+                        })
+                        .count(),
+                });
+            }
+        }
+        Self(rows)
+    }
+
+    fn table(self) -> Table {
+        self.0.table()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use bencher_json::{
+        project::{
+            alert::JsonSide,
+            threshold::{JsonNewStatistic, JsonStatisticKind},
+        },
+        ResourceId,
+    };
+    use chrono::{DateTime, Duration, Utc};
+    use pretty_assertions::assert_eq;
+    use uuid::Uuid;
+
+    use super::{
+        LocalReport, LocalResults, LocalStore, LocalThreshold, REPORTS_DIR, THRESHOLDS_FILE,
+    };
+
+    const BENCHMARK: &str = "bench";
+
+    fn resource_id(id: &str) -> ResourceId {
+        id.parse().unwrap()
+    }
+
+    fn local_report(branch: &str, start_time: DateTime<Utc>, values: Vec<f64>) -> LocalReport {
+        let mut results = LocalResults::new();
+        results
+            .entry(BENCHMARK.into())
+            .or_default()
+            .insert(resource_id("latency"), values);
+        LocalReport {
+            uuid: Uuid::new_v4(),
+            project: resource_id("project"),
+            branch: resource_id(branch),
+            hash: None,
+            testbed: resource_id("localhost"),
+            start_time,
+            end_time: start_time,
+            results,
+            alerts: Vec::new(),
+        }
+    }
+
+    fn percentage_threshold(window: Option<u32>) -> LocalThreshold {
+        LocalThreshold {
+            branch: None,
+            testbed: None,
+            metric_kind: resource_id("latency"),
+            statistic: JsonNewStatistic {
+                test: JsonStatisticKind::Percentage,
+                min_sample_size: None,
+                max_sample_size: None,
+                window,
+                left_side: None,
+                right_side: None,
+                baseline: None,
+                lower_limit: None,
+                upper_limit: Some(10.0.into()),
+            },
+        }
+    }
+
+    #[test]
+    fn test_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::new(dir.path());
+        let project = resource_id("project");
+        let main = resource_id("main");
+        let testbed = resource_id("localhost");
+        assert!(store.history(&project, &main, &testbed).unwrap().is_empty());
+
+        let now = Utc::now();
+        let older = local_report("main", now - Duration::days(1), vec![1.0]);
+        let newer = local_report("main", now, vec![2.0]);
+        let other = local_report("feature", now, vec![3.0]);
+        let reports_dir = dir.path().join(REPORTS_DIR);
+        fs::create_dir_all(&reports_dir).unwrap();
+        for report in [&older, &newer, &other] {
+            fs::write(
+                reports_dir.join(format!("{}.json", report.uuid)),
+                serde_json::to_string(report).unwrap(),
+            )
+            .unwrap();
+        }
+        fs::write(reports_dir.join("notes.txt"), "not a report").unwrap();
+
+        let history = store
+            .history(&project, &main, &testbed)
+            .unwrap()
+            .into_iter()
+            .map(|report| report.uuid)
+            .collect::<Vec<_>>();
+        assert_eq!(history, vec![newer.uuid, older.uuid]);
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::new(dir.path());
+        assert!(store.thresholds().unwrap().is_empty());
+
+        fs::write(
+            dir.path().join(THRESHOLDS_FILE),
+            r#"[
+                {
+                    "branch": "main",
+                    "metric_kind": "latency",
+                    "test": "percentage",
+                    "baseline": "median",
+                    "max_sample_size": 64,
+                    "upper_limit": 10.0
+                },
+                {
+                    "metric_kind": "latency",
+                    "test": "t",
+                    "min_sample_size": 2,
+                    "left_side": 0.05
+                }
+            ]"#,
+        )
+        .unwrap();
+        let thresholds = store.thresholds().unwrap();
+        assert_eq!(thresholds.len(), 2);
+
+        let latency = resource_id("latency");
+        let testbed = resource_id("localhost");
+        let find = |branch: &str| {
+            thresholds
+                .iter()
+                .position(|threshold| threshold.is_match(&resource_id(branch), &testbed, &latency))
+                .unwrap()
+        };
+        assert_eq!(find("main"), 0);
+        assert_eq!(find("feature"), 1);
+        assert!(!thresholds.iter().any(|threshold| threshold.is_match(
+            &resource_id("main"),
+            &testbed,
+            &resource_id("throughput")
+        )));
+    }
+
+    #[test]
+    fn test_detect() {
+        let now = Utc::now();
+        let history = vec![
+            local_report("main", now - Duration::hours(1), vec![100.0, 100.0]),
+            local_report("main", now - Duration::days(1), vec![1.0]),
+        ];
+        let metric_kind = resource_id("latency");
+
+        // The old report is outside of the window, so the baseline is 100
+        let threshold = percentage_threshold(Some(3600 * 2));
+        let alerts = threshold
+            .detect(BENCHMARK, &metric_kind, &[105.0, 150.0], &history, now)
+            .unwrap();
+        assert_eq!(alerts.len(), 1);
+        let alert = alerts.first().unwrap();
+        assert_eq!(alert.iteration, 1);
+        assert_eq!(alert.side, JsonSide::Right);
+        assert_eq!(alert.limit, 10.0);
+
+        // Without a window the old report pulls the baseline down, so both iterations are outliers
+        let threshold = percentage_threshold(None);
+        let alerts = threshold
+            .detect(BENCHMARK, &metric_kind, &[105.0, 150.0], &history, now)
+            .unwrap();
+        assert_eq!(alerts.len(), 2);
+
+        // The datum is not part of its own baseline, so the change is the full 20%
+        let history = vec![local_report("main", now - Duration::hours(1), vec![100.0])];
+        let alerts = threshold
+            .detect(BENCHMARK, &metric_kind, &[120.0], &history, now)
+            .unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts.first().unwrap().outlier, 20.0);
+    }
+}
@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+use bencher_json::{JsonNewMetricKind, NonEmpty};
+
+use crate::CliError;
+
+const NAME_UNITS_DELIMITER: char = '=';
+
+#[derive(Debug, Clone)]
+pub struct MetricKind(JsonNewMetricKind);
+
+impl FromStr for MetricKind {
+    type Err = CliError;
+
+    fn from_str(metric_kind: &str) -> Result<Self, Self::Err> {
+        let Some((name, units)) = metric_kind.split_once(NAME_UNITS_DELIMITER) else {
+            return Err(CliError::MetricKind(metric_kind.into()));
+        };
+        Ok(Self(JsonNewMetricKind {
+            name: NonEmpty::from_str(name.trim())?,
+            slug: None,
+            units: NonEmpty::from_str(units.trim())?,
+        }))
+    }
+}
+
+impl From<MetricKind> for JsonNewMetricKind {
+    fn from(metric_kind: MetricKind) -> Self {
+        metric_kind.0
+    }
+}
@@ -2,14 +2,18 @@ use std::{convert::TryFrom, str::FromStr};
 
 use async_trait::async_trait;
 use bencher_json::{
-    project::{report::JsonReportSettings, testbed::TESTBED_LOCALHOST_STR},
+    project::{
+        alert::JsonAlertStatus,
+        report::{JsonAdapter, JsonAdapterConfig, JsonReportOutput, JsonReportSettings},
+        testbed::TESTBED_LOCALHOST_STR,
+    },
     GitHash, JsonNewReport, JsonReport, ResourceId,
 };
 use chrono::Utc;
 use clap::ValueEnum;
 
 use crate::{
-    bencher::locality::Locality,
+    bencher::{locality::Locality, spool::Spool},
     cli::project::run::{CliRun, CliRunAdapter},
     cli_eprintln, cli_println, CliError,
 };
@@ -17,14 +21,24 @@ use crate::{
 mod adapter;
 mod average;
 mod branch;
+mod fingerprint;
 mod fold;
+mod local;
+mod metric_kind;
 pub mod runner;
+mod stream;
+mod summary;
 
 use adapter::RunAdapter;
 use average::Average;
 use branch::Branch;
+use fingerprint::Fingerprint;
 use fold::Fold;
+use local::LocalStore;
+use metric_kind::MetricKind;
 use runner::Runner;
+use stream::Stream;
+use summary::Summary;
 
 use crate::bencher::SubCmd;
 
@@ -43,11 +57,18 @@ pub struct Run {
     hash: Option<GitHash>,
     testbed: ResourceId,
     adapter: Option<RunAdapter>,
+    adapter_config: Option<JsonAdapterConfig>,
+    stream: Stream,
     average: Option<Average>,
     iter: usize,
     fold: Option<Fold>,
+    metric_kinds: Vec<MetricKind>,
     allow_failure: bool,
     err: bool,
+    save_output: bool,
+    spool: Spool,
+    fingerprint: Option<Fingerprint>,
+    summary: Option<Summary>,
 }
 
 impl TryFrom<CliRun> for Run {
@@ -62,25 +83,53 @@ impl TryFrom<CliRun> for Run {
             hash,
             testbed,
             adapter,
+            adapter_config,
+            stream,
             average,
             iter,
             fold,
+            metric_kind,
             allow_failure,
             err,
+            save_output,
+            spool_dir,
+            fingerprint,
+            summary,
         } = run;
+        let runner = Runner::try_from(command)?;
+        // Without a command, each of the pre-existing output files is an iteration
+        if let (Runner::Files(files), Some(iter)) = (&runner, iter) {
+            cli_eprintln!(
+                "⚠️ Warning: `--iter {iter}` is ignored without a command. Each of the {} files matching `--file` is read as an iteration instead.",
+                files.count()
+            );
+        }
         Ok(Self {
             project: unwrap_project(project)?,
             locality: locality.try_into()?,
-            runner: command.try_into()?,
+            runner,
             branch: run_branch.try_into()?,
             hash: map_hash(hash)?,
             testbed: unwrap_testbed(testbed)?,
             adapter: map_adapter(adapter),
+            adapter_config: adapter_config
+                .as_deref()
+                .map(adapter::adapter_config)
+                .transpose()?,
+            stream: stream.map(Into::into).unwrap_or_default(),
             average: average.map(Into::into),
             iter: iter.unwrap_or(1),
             fold: fold.map(Into::into),
+            metric_kinds: metric_kind
+                .iter()
+                .map(|metric_kind| metric_kind.parse())
+                .collect::<Result<_, _>>()?,
             allow_failure,
             err,
+            save_output,
+            spool: spool_dir.into(),
+            fingerprint: Fingerprint::new(fingerprint),
+            summary: Summary::new(summary),
         })
     }
 }
@@ -135,11 +184,16 @@ impl SubCmd for Run {
         };
 
         let start_time = Utc::now();
-        let mut results = Vec::with_capacity(self.iter);
-        for _ in 0..self.iter {
-            let output = self.runner.run()?;
+        let iter = self.runner.iter(self.iter);
+        let mut results = Vec::with_capacity(iter);
+        let mut outputs = Vec::with_capacity(iter);
+        for iteration in 0..iter {
+            let output = self.runner.run(iteration)?;
             if output.success() {
-                results.push(output.stdout)
+                results.push(self.stream.results(&output));
+                if self.save_output {
+                    outputs.push(JsonReportOutput::new(output.stdout, output.stderr));
+                }
             } else if self.allow_failure {
                 cli_eprintln!("Skipping failure:\n{}", output);
             } else {
@@ -160,27 +214,103 @@ impl SubCmd for Run {
             end_time: Utc::now(),
             results,
             settings: Some(JsonReportSettings {
-                adapter: self.adapter.map(Into::into),
+                adapter: if self.adapter_config.is_some() {
+                    Some(JsonAdapter::Custom)
+                } else {
+                    self.adapter.map(Into::into)
+                },
                 average: self.average.map(Into::into),
                 fold: self.fold.map(Into::into),
+                adapter_config: self.adapter_config.clone(),
+                metric_kinds: (!self.metric_kinds.is_empty())
+                    .then(|| self.metric_kinds.iter().cloned().map(Into::into).collect()),
             }),
+            output: self.save_output.then_some(outputs),
+            fingerprint: self.fingerprint.as_ref().map(Fingerprint::collect),
         };
 
+        // Keep stdout clean when the Markdown summary is written to it
+        let quiet = self.summary.as_ref().map_or(false, Summary::is_stdout);
         // TODO disable when quiet
-        cli_println!("{}", serde_json::to_string_pretty(&report)?);
+        if !quiet {
+            cli_println!("{}", serde_json::to_string_pretty(&report)?);
+        }
 
         match &self.locality {
-            Locality::Local => {},
+            Locality::Local(dir) => {
+                let local_report = LocalStore::new(dir).run(&self.project, &report)?;
+                if self.err && !local_report.alerts.is_empty() {
+                    return Err(CliError::Alerts);
+                }
+            },
             Locality::Backend(backend) => {
-                let value = backend
-                    .post(&format!("/v0/projects/{}/reports", self.project), &report)
-                    .await?;
-                if self.err {
-                    let json_report: JsonReport = serde_json::from_value(value)?;
-                    if !json_report.alerts.is_empty() {
-                        return Err(CliError::Alerts);
+                let path = format!("/v0/projects/{}/reports", self.project);
+                let posted = if quiet {
+                    backend.post_quiet(&path, &report).await
+                } else {
+                    backend.post(&path, &report).await
+                };
+                let value = match posted {
+                    Ok(value) => value,
+                    // Keep the results from being lost if the backend is unreachable
+                    Err(CliError::Send(attempts)) => {
+                        let path = self.spool.save(&self.project, &report)?;
+                        return Err(CliError::Spooled(attempts, path.display().to_string()));
+                    },
+                    Err(e) => return Err(e),
+                };
+                let json_report = serde_json::from_value::<JsonReport>(value);
+                if let Ok(JsonReport {
+                    fingerprint_changed: true,
+                    ..
+                }) = &json_report
+                {
+                    cli_eprintln!(
+                        "⚠️ Warning: The fingerprint for testbed {} has changed since the previous report. Results may not be comparable.",
+                        self.testbed
+                    );
+                }
+                if let Ok(JsonReport {
+                    skipped_metric_kinds,
+                    ..
+                }) = &json_report
+                {
+                    if !skipped_metric_kinds.is_empty() {
+                        cli_eprintln!(
+                            "⚠️ Warning: Metrics were skipped for Metric Kinds that do not exist in project {}: {}. Declare them with `--metric-kind` or enable `auto_metric_kinds` for the project.",
+                            self.project,
+                            skipped_metric_kinds
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        );
                     }
                 }
+                if self.summary.is_none() && !self.err {
+                    return Ok(());
+                }
+                let json_report = json_report?;
+                if let Some(summary) = &self.summary {
+                    summary
+                        .write(
+                            backend,
+                            &self.project,
+                            &report.branch,
+                            &self.testbed,
+                            &json_report,
+                        )
+                        .await?;
+                }
+                // Only fail on new alerts, not on previously acknowledged or dismissed ones
+                if self.err
+                    && json_report
+                        .alerts
+                        .iter()
+                        .any(|alert| alert.status == JsonAlertStatus::Active)
+                {
+                    return Err(CliError::Alerts);
+                }
             },
         }
 
@@ -0,0 +1,127 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::{json, Value};
+
+use crate::CliError;
+
+const NEW_DIR: &str = "new";
+const REPORT_DIR: &str = "report";
+const BENCHMARK_FILE: &str = "benchmark.json";
+const ESTIMATES_FILE: &str = "estimates.json";
+
+/// Collect the latest `benchmark.json` and `estimates.json` pair for each benchmark
+/// in a Criterion output directory into a single JSON array.
+/// Benchmarks that were not run since `since` are left over from an earlier run, so they are skipped.
+pub fn criterion_json(criterion_dir: &Path, since: SystemTime) -> Result<String, CliError> {
+    let mut new_dirs = Vec::new();
+    find_new_dirs(criterion_dir, since, &mut new_dirs)?;
+    if new_dirs.is_empty() {
+        return Err(CliError::CriterionDir(criterion_dir.display().to_string()));
+    }
+    new_dirs.sort();
+
+    let mut benchmarks = Vec::with_capacity(new_dirs.len());
+    for new_dir in new_dirs {
+        let benchmark: Value =
+            serde_json::from_str(&fs::read_to_string(new_dir.join(BENCHMARK_FILE))?)?;
+        let estimates: Value =
+            serde_json::from_str(&fs::read_to_string(new_dir.join(ESTIMATES_FILE))?)?;
+        benchmarks.push(json!({
+            "benchmark": benchmark,
+            "estimates": estimates,
+        }));
+    }
+
+    serde_json::to_string(&benchmarks).map_err(Into::into)
+}
+
+fn find_new_dirs(
+    dir: &Path,
+    since: SystemTime,
+    new_dirs: &mut Vec<PathBuf>,
+) -> Result<(), CliError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        match path.file_name().and_then(|name| name.to_str()) {
+            // The HTML report directories do not contain any estimates
+            Some(REPORT_DIR) => {},
+            Some(NEW_DIR) => {
+                let benchmark_file = path.join(BENCHMARK_FILE);
+                if benchmark_file.is_file()
+                    && path.join(ESTIMATES_FILE).is_file()
+                    && modified_since(&benchmark_file, since)?
+                {
+                    new_dirs.push(path);
+                }
+            },
+            _ => find_new_dirs(&path, since, new_dirs)?,
+        }
+    }
+    Ok(())
+}
+
+// Compare whole seconds, since some file systems only keep the modified time to the second
+fn modified_since(path: &Path, since: SystemTime) -> Result<bool, CliError> {
+    let as_secs = |time: SystemTime| {
+        time.duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default()
+    };
+    Ok(as_secs(fs::metadata(path)?.modified()?) >= as_secs(since))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        path::Path,
+        time::{Duration, SystemTime},
+    };
+
+    use pretty_assertions::assert_eq;
+
+    use super::{criterion_json, BENCHMARK_FILE, ESTIMATES_FILE, NEW_DIR, REPORT_DIR};
+
+    fn write_benchmark(dir: &Path, full_id: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            dir.join(BENCHMARK_FILE),
+            serde_json::json!({ "full_id": full_id }).to_string(),
+        )
+        .unwrap();
+        fs::write(dir.join(ESTIMATES_FILE), "{}").unwrap();
+    }
+
+    #[test]
+    fn test_criterion_json() {
+        let dir = tempfile::tempdir().unwrap();
+        write_benchmark(
+            &dir.path().join("fib").join("fib 10").join(NEW_DIR),
+            "fib/fib 10",
+        );
+        write_benchmark(&dir.path().join("parse").join(NEW_DIR), "parse");
+        // The HTML report directories are never read
+        write_benchmark(&dir.path().join(REPORT_DIR).join(NEW_DIR), "report");
+
+        let now = SystemTime::now();
+        let json = criterion_json(dir.path(), now - Duration::from_secs(60)).unwrap();
+        let benchmarks: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let full_ids = benchmarks
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|benchmark| benchmark["benchmark"]["full_id"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(full_ids, vec!["fib/fib 10", "parse"]);
+
+        // Benchmarks from before the run are stale
+        assert!(criterion_json(dir.path(), now + Duration::from_secs(60)).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use crate::CliError;
+
+use super::Output;
+
+/// Pre-existing benchmark output files, each of which is read as an iteration
+#[derive(Debug)]
+pub struct Files(Vec<PathBuf>);
+
+impl Files {
+    pub fn new(pattern: &str) -> Result<Self, CliError> {
+        let mut files = glob::glob(pattern)?.collect::<Result<Vec<_>, _>>()?;
+        if files.is_empty() {
+            return Err(CliError::FilesNotFound(pattern.into()));
+        }
+        files.sort();
+        Ok(Self(files))
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn output(&self, iteration: usize) -> Result<Output, CliError> {
+        let file_path = self.0.get(iteration).ok_or(CliError::BadMath)?;
+        Ok(Output {
+            stdout: std::fs::read_to_string(file_path)?,
+            ..Default::default()
+        })
+    }
+}
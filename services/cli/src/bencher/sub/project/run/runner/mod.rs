@@ -3,17 +3,21 @@ use std::{
     fs::File,
     io::{BufRead, BufReader},
     path::PathBuf,
+    time::SystemTime,
 };
 
 use crate::{cli::project::run::CliRunCommand, CliError};
 
 mod command;
+mod criterion_dir;
+mod files;
 mod flag;
 pub mod output;
 mod pipe;
 mod shell;
 
 use command::Command;
+use files::Files;
 use output::Output;
 use pipe::Pipe;
 
@@ -22,8 +26,10 @@ use super::BENCHER_CMD;
 #[derive(Debug)]
 pub enum Runner {
     Pipe(Pipe),
+    Files(Files),
     Command(Command),
     CommandToFile(Command, PathBuf),
+    CommandToCriterionDir(Command, PathBuf),
 }
 
 impl TryFrom<CliRunCommand> for Runner {
@@ -34,6 +40,8 @@ impl TryFrom<CliRunCommand> for Runner {
             (command, cmd).try_into()
         } else if let Ok(cmd) = std::env::var(BENCHER_CMD) {
             (command, cmd).try_into()
+        } else if let Some(file) = &command.file {
+            Ok(Self::Files(Files::new(file)?))
         } else if let Some(pipe) = Pipe::new() {
             Ok(Self::Pipe(pipe))
         } else {
@@ -48,7 +56,9 @@ impl TryFrom<(CliRunCommand, String)> for Runner {
     fn try_from((command, cmd): (CliRunCommand, String)) -> Result<Self, Self::Error> {
         let cmd = Command::try_from((command.shell, cmd))?;
         Ok(if let Some(file) = command.file {
-            Self::CommandToFile(cmd, file)
+            Self::CommandToFile(cmd, file.into())
+        } else if let Some(criterion_dir) = command.criterion_dir {
+            Self::CommandToCriterionDir(cmd, criterion_dir)
         } else {
             Self::Command(cmd)
         })
@@ -56,9 +66,19 @@ impl TryFrom<(CliRunCommand, String)> for Runner {
 }
 
 impl Runner {
-    pub fn run(&self) -> Result<Output, CliError> {
+    /// The number of iterations to run, which is the number of files for pre-existing output files
+    pub fn iter(&self, iter: usize) -> usize {
+        if let Self::Files(files) = self {
+            files.count()
+        } else {
+            iter
+        }
+    }
+
+    pub fn run(&self, iteration: usize) -> Result<Output, CliError> {
         Ok(match self {
             Self::Pipe(pipe) => pipe.output(),
+            Self::Files(files) => files.output(iteration)?,
             Self::Command(command) => command.try_into()?,
             Self::CommandToFile(command, file_path) => {
                 let mut output: Output = command.try_into()?;
@@ -73,6 +93,12 @@ impl Runner {
 
                 output
             },
+            Self::CommandToCriterionDir(command, criterion_dir) => {
+                let start = SystemTime::now();
+                let mut output: Output = command.try_into()?;
+                output.stdout = criterion_dir::criterion_json(criterion_dir, start)?;
+                output
+            },
         })
     }
 }
@@ -0,0 +1,72 @@
+use crate::cli::project::run::CliRunStream;
+
+use super::runner::output::Output;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Stream {
+    #[default]
+    Stdout,
+    Stderr,
+    Both,
+}
+
+impl From<CliRunStream> for Stream {
+    fn from(stream: CliRunStream) -> Self {
+        match stream {
+            CliRunStream::Stdout => Self::Stdout,
+            CliRunStream::Stderr => Self::Stderr,
+            CliRunStream::Both => Self::Both,
+        }
+    }
+}
+
+impl Stream {
+    /// The adapter input from the benchmark command output.
+    /// Both streams are captured separately, so they are concatenated instead of interleaved.
+    pub fn results(self, output: &Output) -> String {
+        match self {
+            Self::Stdout => output.stdout.clone(),
+            Self::Stderr => output.stderr.clone(),
+            Self::Both => {
+                if output.stdout.is_empty() || output.stdout.ends_with('\n') {
+                    format!("{}{}", output.stdout, output.stderr)
+                } else {
+                    format!("{}\n{}", output.stdout, output.stderr)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{Output, Stream};
+
+    fn output(stdout: &str, stderr: &str) -> Output {
+        Output {
+            stdout: stdout.into(),
+            stderr: stderr.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_stream() {
+        let output = output("out\n", "err\n");
+        assert_eq!(Stream::default().results(&output), "out\n");
+        assert_eq!(Stream::Stdout.results(&output), "out\n");
+        assert_eq!(Stream::Stderr.results(&output), "err\n");
+        assert_eq!(Stream::Both.results(&output), "out\nerr\n");
+    }
+
+    #[test]
+    fn test_stream_both() {
+        // Standard output without a trailing newline is kept on its own line
+        assert_eq!(Stream::Both.results(&output("out", "err")), "out\nerr");
+        assert_eq!(Stream::Both.results(&output("", "err")), "err");
+        assert_eq!(Stream::Both.results(&output("out\n", "")), "out\n");
+        assert_eq!(Stream::Both.results(&output("", "")), "");
+    }
+}
@@ -0,0 +1,343 @@
+use std::{fmt, fs::OpenOptions, io::Write, path::PathBuf};
+
+use bencher_json::{
+    project::compare::JsonCompareResult, JsonBranch, JsonCompare, JsonCompareQuery, JsonEmpty,
+    JsonReport, ResourceId,
+};
+
+use crate::{bencher::backend::Backend, cli::project::run::CliRunSummary, cli_println, CliError};
+
+#[derive(Debug)]
+pub struct Summary {
+    file: Option<PathBuf>,
+    base: Option<ResourceId>,
+}
+
+impl Summary {
+    pub fn new(summary: CliRunSummary) -> Option<Self> {
+        let CliRunSummary {
+            summary,
+            summary_file,
+            summary_base,
+        } = summary;
+        (summary || summary_file.is_some()).then_some(Self {
+            file: summary_file,
+            base: summary_base,
+        })
+    }
+
+    /// Whether the summary is written to stdout instead of a file
+    pub fn is_stdout(&self) -> bool {
+        self.file.is_none()
+    }
+
+    /// Render the report as Markdown, comparing it to the latest report on the base branch if there is one
+    pub async fn write(
+        &self,
+        backend: &Backend,
+        project: &ResourceId,
+        branch: &ResourceId,
+        testbed: &ResourceId,
+        json_report: &JsonReport,
+    ) -> Result<(), CliError> {
+        let head = json_report.uuid.to_string();
+        let base = if let Some(base_branch) = &self.base {
+            base_report(backend, project, base_branch, testbed, &head).await?
+        } else {
+            None
+        };
+        let has_base = base.is_some();
+
+        // The branch may be a UUID, so look up its name
+        let json_branch: JsonBranch = serde_json::from_value(
+            backend
+                .get_query_quiet(
+                    &format!("/v0/projects/{project}/branches/{branch}"),
+                    &JsonEmpty::default(),
+                )
+                .await?,
+        )?;
+        let json_compare: JsonCompare = serde_json::from_value(
+            backend
+                .get_query_quiet(
+                    &format!("/v0/projects/{project}/compare"),
+                    &JsonCompareQuery {
+                        base: base.unwrap_or_else(|| head.clone()),
+                        head,
+                        testbed: None,
+                        branch: None,
+                    },
+                )
+                .await?,
+        )?;
+
+        let markdown = Markdown {
+            json_compare: &json_compare,
+            branch: &json_branch,
+            base_branch: self.base.as_ref().filter(|_| has_base),
+        };
+        if let Some(file) = &self.file {
+            // Append so that multiple runs can share a file like `$GITHUB_STEP_SUMMARY`
+            let mut file = OpenOptions::new().create(true).append(true).open(file)?;
+            writeln!(file, "{markdown}")?;
+        } else {
+            cli_println!("{markdown}");
+        }
+
+        Ok(())
+    }
+}
+
+/// The UUID of the most recent report for the base branch on the same testbed, other than the head report
+async fn base_report(
+    backend: &Backend,
+    project: &ResourceId,
+    branch: &ResourceId,
+    testbed: &ResourceId,
+    head: &str,
+) -> Result<Option<String>, CliError> {
+    let json_reports: Vec<JsonReport> = serde_json::from_value(
+        backend
+            .get_query_quiet(
+                &format!("/v0/projects/{project}/reports"),
+                &[
+                    ("branch", branch.to_string()),
+                    ("testbed", testbed.to_string()),
+                    ("per_page", "2".into()),
+                    ("page", "1".into()),
+                ],
+            )
+            .await?,
+    )?;
+    Ok(json_reports
+        .into_iter()
+        .map(|json_report| json_report.uuid.to_string())
+        .find(|uuid| uuid != head))
+}
+
+struct Markdown<'a> {
+    json_compare: &'a JsonCompare,
+    branch: &'a JsonBranch,
+    base_branch: Option<&'a ResourceId>,
+}
+
+impl fmt::Display for Markdown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let JsonCompare {
+            project,
+            head,
+            results,
+            ..
+        } = self.json_compare;
+
+        writeln!(f, "## 🐰 Bencher Report for {project}")?;
+        writeln!(f)?;
+        writeln!(f, "| Branch | Testbed | Commit | Report |")?;
+        writeln!(f, "| --- | --- | --- | --- |")?;
+        writeln!(
+            f,
+            "| {} | {} | {} | `{}` |",
+            self.branch,
+            head.testbed,
+            head.version_hash
+                .as_ref()
+                .map(|hash| format!("`{}`", hash.as_ref()))
+                .unwrap_or_else(|| "---".into()),
+            head.report
+        )?;
+        writeln!(f)?;
+
+        if let Some(base_branch) = self.base_branch {
+            writeln!(
+                f,
+                "| Benchmark | Metric Kind | {base_branch} | {} | Change | Alerts |",
+                self.branch
+            )?;
+            writeln!(f, "| --- | --- | ---: | ---: | ---: | :---: |")?;
+        } else {
+            writeln!(f, "| Benchmark | Metric Kind | Value | Alerts |")?;
+            writeln!(f, "| --- | --- | ---: | :---: |")?;
+        }
+        for result in results {
+            let JsonCompareResult {
+                benchmark,
+                metric_kind,
+                base,
+                head,
+                percent,
+                alerts,
+                ..
+            } = result;
+            let alerts = if alerts.is_empty() {
+                "✅".into()
+            } else {
+                format!("🚨 {}", alerts.len())
+            };
+            if self.base_branch.is_some() {
+                writeln!(
+                    f,
+                    "| {benchmark} | {metric_kind} | {} | {} | {} | {alerts} |",
+                    value(*base),
+                    value(*head),
+                    percent
+                        .map(|percent| format!("{:+.2}%", percent.0))
+                        .unwrap_or_else(|| "---".into()),
+                )?;
+            } else {
+                writeln!(
+                    f,
+                    "| {benchmark} | {metric_kind} | {} | {alerts} |",
+                    value(*head)
+                )?;
+            }
+        }
+        writeln!(f)?;
+
+        let alert_count = results
+            .iter()
+            .map(|result| result.alerts.len())
+            .sum::<usize>();
+        match alert_count {
+            0 => write!(f, "✅ No Alerts"),
+            1 => write!(f, "🚨 1 Alert"),
+            count => write!(f, "🚨 {count} Alerts"),
+        }
+    }
+}
+
+fn value<T: fmt::Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "---".into(), |value| value.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use bencher_json::{JsonBranch, JsonCompare, ResourceId};
+    use pretty_assertions::assert_eq;
+
+    use super::Markdown;
+
+    const PROJECT: &str = "00000000-0000-0000-0000-000000000001";
+    const REPORT: &str = "00000000-0000-0000-0000-000000000002";
+    const ALERT: &str = "00000000-0000-0000-0000-000000000003";
+
+    fn json_compare() -> JsonCompare {
+        let testbed = serde_json::json!({
+            "uuid": PROJECT,
+            "project": PROJECT,
+            "name": "base",
+            "slug": "base",
+        });
+        let report = serde_json::json!({
+            "report": REPORT,
+            "testbed": testbed,
+            "version_number": 1,
+            "version_hash": "1234567890abcdef1234567890abcdef12345678",
+            "start_time": "2023-01-01T00:00:00Z",
+            "end_time": "2023-01-01T00:01:00Z",
+        });
+        let metric_kind = serde_json::json!({
+            "uuid": PROJECT,
+            "project": PROJECT,
+            "name": "Latency",
+            "slug": "latency",
+            "units": "nanoseconds (ns)",
+        });
+        serde_json::from_value(serde_json::json!({
+            "project": {
+                "uuid": PROJECT,
+                "organization": PROJECT,
+                "name": "The Computer",
+                "slug": "the-computer",
+                "url": null,
+                "visibility": "public",
+                "auto_metric_kinds": false,
+            },
+            "base": report,
+            "head": report,
+            "results": [
+                {
+                    "benchmark": { "uuid": PROJECT, "project": PROJECT, "name": "fast" },
+                    "metric_kind": metric_kind,
+                    "base": 100.0,
+                    "head": 110.0,
+                    "delta": 10.0,
+                    "percent": 10.0,
+                    "alerts": [],
+                },
+                {
+                    "benchmark": { "uuid": PROJECT, "project": PROJECT, "name": "slow" },
+                    "metric_kind": metric_kind,
+                    "base": null,
+                    "head": 200.0,
+                    "delta": null,
+                    "percent": null,
+                    "alerts": [ALERT],
+                },
+            ],
+        }))
+        .unwrap()
+    }
+
+    fn json_branch() -> JsonBranch {
+        serde_json::from_value(serde_json::json!({
+            "uuid": PROJECT,
+            "project": PROJECT,
+            "name": "feature",
+            "slug": "feature",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_markdown() {
+        let json_compare = json_compare();
+        let branch = json_branch();
+        let markdown = Markdown {
+            json_compare: &json_compare,
+            branch: &branch,
+            base_branch: None,
+        };
+        assert_eq!(
+            markdown.to_string(),
+            "## 🐰 Bencher Report for The Computer
+
+| Branch | Testbed | Commit | Report |
+| --- | --- | --- | --- |
+| feature | base | `1234567890abcdef1234567890abcdef12345678` | `00000000-0000-0000-0000-000000000002` |
+
+| Benchmark | Metric Kind | Value | Alerts |
+| --- | --- | ---: | :---: |
+| fast | Latency: nanoseconds (ns) | 110 | ✅ |
+| slow | Latency: nanoseconds (ns) | 200 | 🚨 1 |
+
+🚨 1 Alert"
+        );
+    }
+
+    #[test]
+    fn test_markdown_base() {
+        let json_compare = json_compare();
+        let branch = json_branch();
+        let base_branch: ResourceId = serde_json::from_value(serde_json::json!("main")).unwrap();
+        let markdown = Markdown {
+            json_compare: &json_compare,
+            branch: &branch,
+            base_branch: Some(&base_branch),
+        };
+        assert_eq!(
+            markdown.to_string(),
+            "## 🐰 Bencher Report for The Computer
+
+| Branch | Testbed | Commit | Report |
+| --- | --- | --- | --- |
+| feature | base | `1234567890abcdef1234567890abcdef12345678` | `00000000-0000-0000-0000-000000000002` |
+
+| Benchmark | Metric Kind | main | feature | Change | Alerts |
+| --- | --- | ---: | ---: | ---: | :---: |
+| fast | Latency: nanoseconds (ns) | 100 | 110 | +10.00% | ✅ |
+| slow | Latency: nanoseconds (ns) | --- | 200 | --- | 🚨 1 |
+
+🚨 1 Alert"
+        );
+    }
+}
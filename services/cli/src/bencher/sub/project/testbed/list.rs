@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{project::testbed::JsonTestbedsSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::testbed::CliTestbedList,
+    cli::project::testbed::{CliTestbedList, CliTestbedsSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub pagination: JsonPagination<JsonTestbedsSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,36 @@ impl TryFrom<CliTestbedList> for List {
     type Error = CliError;
 
     fn try_from(list: CliTestbedList) -> Result<Self, Self::Error> {
-        let CliTestbedList { project, backend } = list;
+        let CliTestbedList {
+            project,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             project,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliTestbedsSort> for JsonTestbedsSort {
+    fn from(sort: CliTestbedsSort) -> Self {
+        match sort {
+            CliTestbedsSort::Name => Self::Name,
+            CliTestbedsSort::Created => Self::Created,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/projects/{}/testbeds", self.project))
+            .get_ls(
+                &format!("/v0/projects/{}/testbeds", self.project),
+                &self.pagination,
+            )
             .await?;
         Ok(())
     }
@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{project::threshold::JsonThresholdsSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::project::threshold::CliThresholdList,
+    cli::project::threshold::{CliThresholdList, CliThresholdsSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub pagination: JsonPagination<JsonThresholdsSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,36 @@ impl TryFrom<CliThresholdList> for List {
     type Error = CliError;
 
     fn try_from(list: CliThresholdList) -> Result<Self, Self::Error> {
-        let CliThresholdList { project, backend } = list;
+        let CliThresholdList {
+            project,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             project,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliThresholdsSort> for JsonThresholdsSort {
+    fn from(sort: CliThresholdsSort) -> Self {
+        match sort {
+            CliThresholdsSort::Created => Self::Created,
+            CliThresholdsSort::Testbed => Self::Testbed,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/projects/{}/thresholds", self.project))
+            .get_ls(
+                &format!("/v0/projects/{}/thresholds", self.project),
+                &self.pagination,
+            )
             .await?;
         Ok(())
     }
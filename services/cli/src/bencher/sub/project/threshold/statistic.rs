@@ -1,9 +1,12 @@
 use std::convert::TryFrom;
 
-use bencher_json::project::threshold::{JsonNewStatistic, JsonStatisticKind};
+use bencher_json::project::{
+    report::JsonAverage,
+    threshold::{JsonNewStatistic, JsonStatisticKind},
+};
 
 use crate::{
-    cli::project::threshold::{CliStatisticCreate, CliStatisticKind},
+    cli::project::threshold::{CliStatisticBaseline, CliStatisticCreate, CliStatisticKind},
     CliError,
 };
 
@@ -15,6 +18,9 @@ pub struct Statistic {
     pub window: Option<u32>,
     pub left_side: Option<f32>,
     pub right_side: Option<f32>,
+    pub baseline: Option<JsonAverage>,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
 }
 
 impl TryFrom<CliStatisticCreate> for Statistic {
@@ -28,24 +34,61 @@ impl TryFrom<CliStatisticCreate> for Statistic {
             window,
             left_side,
             right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
         } = create;
+        let test = test.into();
+        if matches!(
+            test,
+            JsonStatisticKind::Percentage | JsonStatisticKind::Absolute
+        ) && lower_limit.is_none()
+            && upper_limit.is_none()
+        {
+            return Err(CliError::StatisticLimitMissing);
+        }
         Ok(Self {
-            test: test.into(),
+            test,
             min_sample_size,
             max_sample_size,
             window,
             // TODO validate these as reasonable percentages
             left_side,
             right_side,
+            baseline: baseline.map(Into::into),
+            lower_limit: validate_limit(lower_limit)?,
+            upper_limit: validate_limit(upper_limit)?,
         })
     }
 }
 
+fn validate_limit(limit: Option<f64>) -> Result<Option<f64>, CliError> {
+    match limit {
+        Some(limit) if !limit.is_finite() || limit.is_sign_negative() => {
+            Err(CliError::StatisticLimit(limit))
+        },
+        _ => Ok(limit),
+    }
+}
+
 impl From<CliStatisticKind> for JsonStatisticKind {
     fn from(kind: CliStatisticKind) -> Self {
         match kind {
             CliStatisticKind::Z => Self::Z,
             CliStatisticKind::T => Self::T,
+            CliStatisticKind::Percentage => Self::Percentage,
+            CliStatisticKind::Absolute => Self::Absolute,
+            CliStatisticKind::Mad => Self::Mad,
+            CliStatisticKind::Iqr => Self::Iqr,
+        }
+    }
+}
+
+impl From<CliStatisticBaseline> for JsonAverage {
+    fn from(baseline: CliStatisticBaseline) -> Self {
+        match baseline {
+            CliStatisticBaseline::Mean => Self::Mean,
+            CliStatisticBaseline::Median => Self::Median,
         }
     }
 }
@@ -59,6 +102,9 @@ impl From<Statistic> for JsonNewStatistic {
             window,
             left_side,
             right_side,
+            baseline,
+            lower_limit,
+            upper_limit,
         } = statistic;
         Self {
             test,
@@ -67,6 +113,9 @@ impl From<Statistic> for JsonNewStatistic {
             window,
             left_side: left_side.map(Into::into),
             right_side: right_side.map(Into::into),
+            baseline,
+            lower_limit: lower_limit.map(Into::into),
+            upper_limit: upper_limit.map(Into::into),
         }
     }
 }
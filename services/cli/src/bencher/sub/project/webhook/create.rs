@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{project::webhook::JsonWebhookEvent, JsonNewWebhook, ResourceId, Secret, Url};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::webhook::{CliWebhookCreate, CliWebhookEvent},
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ResourceId,
+    pub url: Url,
+    pub secret: Option<Secret>,
+    pub events: Option<Vec<JsonWebhookEvent>>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliWebhookCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliWebhookCreate) -> Result<Self, Self::Error> {
+        let CliWebhookCreate {
+            project,
+            url,
+            secret,
+            event,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            url,
+            secret,
+            events: event.map(|events| events.into_iter().map(Into::into).collect()),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliWebhookEvent> for JsonWebhookEvent {
+    fn from(event: CliWebhookEvent) -> Self {
+        match event {
+            CliWebhookEvent::Report => Self::Report,
+            CliWebhookEvent::Branch => Self::Branch,
+            CliWebhookEvent::Threshold => Self::Threshold,
+            CliWebhookEvent::Alert => Self::Alert,
+        }
+    }
+}
+
+impl From<Create> for JsonNewWebhook {
+    fn from(create: Create) -> Self {
+        let Create {
+            url,
+            secret,
+            events,
+            ..
+        } = create;
+        Self {
+            url,
+            secret,
+            events,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let webhook: JsonNewWebhook = self.clone().into();
+        self.backend
+            .post(&format!("/v0/projects/{}/webhooks", self.project), &webhook)
+            .await?;
+        Ok(())
+    }
+}
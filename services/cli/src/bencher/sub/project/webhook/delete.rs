@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::webhook::CliWebhookDelete,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub project: ResourceId,
+    pub webhook: Uuid,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliWebhookDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliWebhookDelete) -> Result<Self, Self::Error> {
+        let CliWebhookDelete {
+            project,
+            webhook,
+            backend,
+        } = delete;
+        Ok(Self {
+            project,
+            webhook,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .delete(&format!(
+                "/v0/projects/{}/webhooks/{}",
+                self.project, self.webhook
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{project::webhook::JsonWebhookDeliveriesSort, JsonPagination, ResourceId};
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::webhook::{CliWebhookDeliveries, CliWebhookDeliveriesSort},
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Deliveries {
+    pub project: ResourceId,
+    pub webhook: Uuid,
+    pub pagination: JsonPagination<JsonWebhookDeliveriesSort>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliWebhookDeliveries> for Deliveries {
+    type Error = CliError;
+
+    fn try_from(deliveries: CliWebhookDeliveries) -> Result<Self, Self::Error> {
+        let CliWebhookDeliveries {
+            project,
+            webhook,
+            pagination,
+            backend,
+        } = deliveries;
+        Ok(Self {
+            project,
+            webhook,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliWebhookDeliveriesSort> for JsonWebhookDeliveriesSort {
+    fn from(sort: CliWebhookDeliveriesSort) -> Self {
+        match sort {
+            CliWebhookDeliveriesSort::Created => Self::Created,
+            CliWebhookDeliveriesSort::Modified => Self::Modified,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Deliveries {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get_ls(
+                &format!(
+                    "/v0/projects/{}/webhooks/{}/deliveries",
+                    self.project, self.webhook
+                ),
+                &self.pagination,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{project::webhook::JsonWebhooksSort, JsonPagination, ResourceId};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::webhook::{CliWebhookList, CliWebhooksSort},
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct List {
+    pub project: ResourceId,
+    pub pagination: JsonPagination<JsonWebhooksSort>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliWebhookList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliWebhookList) -> Result<Self, Self::Error> {
+        let CliWebhookList {
+            project,
+            pagination,
+            backend,
+        } = list;
+        Ok(Self {
+            project,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliWebhooksSort> for JsonWebhooksSort {
+    fn from(sort: CliWebhooksSort) -> Self {
+        match sort {
+            CliWebhooksSort::Created => Self::Created,
+            CliWebhooksSort::Url => Self::Url,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get_ls(
+                &format!("/v0/projects/{}/webhooks", self.project),
+                &self.pagination,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{
+    project::webhook::{JsonUpdateWebhook, JsonWebhookEvent},
+    ResourceId, Secret, Url,
+};
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::webhook::CliWebhookUpdate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ResourceId,
+    pub webhook: Uuid,
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub events: Option<Vec<JsonWebhookEvent>>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliWebhookUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliWebhookUpdate) -> Result<Self, Self::Error> {
+        let CliWebhookUpdate {
+            project,
+            webhook,
+            url,
+            secret,
+            event,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            webhook,
+            url,
+            secret,
+            events: event.map(|events| events.into_iter().map(Into::into).collect()),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateWebhook {
+    fn from(update: Update) -> Self {
+        let Update {
+            url,
+            secret,
+            events,
+            ..
+        } = update;
+        Self {
+            url,
+            secret,
+            events,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let update: JsonUpdateWebhook = self.clone().into();
+        self.backend
+            .patch(
+                &format!("/v0/projects/{}/webhooks/{}", self.project, self.webhook),
+                &update,
+            )
+            .await?;
+        Ok(())
+    }
+}
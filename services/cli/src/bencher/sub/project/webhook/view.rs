@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::project::webhook::CliWebhookView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub project: ResourceId,
+    pub webhook: Uuid,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliWebhookView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliWebhookView) -> Result<Self, Self::Error> {
+        let CliWebhookView {
+            project,
+            webhook,
+            backend,
+        } = view;
+        Ok(Self {
+            project,
+            webhook,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get(&format!(
+                "/v0/projects/{}/webhooks/{}",
+                self.project, self.webhook
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::{convert::TryFrom, fs, path::PathBuf};
+
+use async_trait::async_trait;
+use bencher_json::{system::audit::JsonAuditEventsSort, JsonDirection, JsonPagination};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::system::audit::CliAuditExport,
+    cli_println, CliError,
+};
+
+use super::AuditQuery;
+
+#[derive(Debug)]
+pub struct Export {
+    pub query: AuditQuery,
+    pub file: PathBuf,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliAuditExport> for Export {
+    type Error = CliError;
+
+    fn try_from(export: CliAuditExport) -> Result<Self, Self::Error> {
+        let CliAuditExport {
+            query,
+            file,
+            backend,
+        } = export;
+        Ok(Self {
+            query: query.into(),
+            file,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Export {
+    async fn exec(&self) -> Result<(), CliError> {
+        // Export every page in chronological order
+        let pagination = JsonPagination {
+            sort: Some(JsonAuditEventsSort::Creation),
+            direction: Some(JsonDirection::Asc),
+            per_page: None,
+            page: None,
+        };
+        let json = self
+            .backend
+            .get_ls_query_quiet(&self.query.path, &pagination, &self.query.query)
+            .await?;
+        let serde_json::Value::Array(events) = &json else {
+            return Err(CliError::AuditExport(serde_json::to_string_pretty(&json)?));
+        };
+        fs::write(&self.file, serde_json::to_string_pretty(&json)?)?;
+        cli_println!(
+            "Exported {} audit event(s) to {}",
+            events.len(),
+            self.file.display()
+        );
+        Ok(())
+    }
+}
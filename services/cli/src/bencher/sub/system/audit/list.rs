@@ -0,0 +1,55 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::{system::audit::JsonAuditEventsSort, JsonPagination};
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::system::audit::{CliAuditEventsSort, CliAuditList},
+    CliError,
+};
+
+use super::AuditQuery;
+
+#[derive(Debug)]
+pub struct List {
+    pub query: AuditQuery,
+    pub pagination: JsonPagination<JsonAuditEventsSort>,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliAuditList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliAuditList) -> Result<Self, Self::Error> {
+        let CliAuditList {
+            query,
+            pagination,
+            backend,
+        } = list;
+        Ok(Self {
+            query: query.into(),
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliAuditEventsSort> for JsonAuditEventsSort {
+    fn from(sort: CliAuditEventsSort) -> Self {
+        match sort {
+            CliAuditEventsSort::Creation => Self::Creation,
+            CliAuditEventsSort::Action => Self::Action,
+        }
+    }
+}
+
+#[async_trait]
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .get_ls_query(&self.query.path, &self.pagination, &self.query.query)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use bencher_json::{
+    system::audit::{JsonAuditAction, JsonAuditEvents},
+    ResourceId,
+};
+
+use crate::{
+    bencher::sub::SubCmd,
+    cli::system::audit::{CliAudit, CliAuditAction, CliAuditQuery},
+    CliError,
+};
+
+mod export;
+mod list;
+
+#[derive(Debug)]
+pub enum Audit {
+    List(list::List),
+    Export(export::Export),
+}
+
+impl TryFrom<CliAudit> for Audit {
+    type Error = CliError;
+
+    fn try_from(audit: CliAudit) -> Result<Self, Self::Error> {
+        Ok(match audit {
+            CliAudit::List(list) => Self::List(list.try_into()?),
+            CliAudit::Export(export) => Self::Export(export.try_into()?),
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Audit {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Export(export) => export.exec().await,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditQuery {
+    path: String,
+    query: JsonAuditEvents,
+}
+
+impl From<CliAuditQuery> for AuditQuery {
+    fn from(query: CliAuditQuery) -> Self {
+        let CliAuditQuery {
+            org,
+            project,
+            action,
+            user,
+            since,
+            until,
+        } = query;
+        Self {
+            path: audit_path(org.as_ref(), project.as_ref()),
+            query: JsonAuditEvents {
+                action: action.map(Into::into),
+                user,
+                start_time: since,
+                end_time: until,
+            },
+        }
+    }
+}
+
+fn audit_path(org: Option<&ResourceId>, project: Option<&ResourceId>) -> String {
+    match (org, project) {
+        (Some(org), _) => format!("/v0/organizations/{org}/audit"),
+        (None, Some(project)) => format!("/v0/projects/{project}/audit"),
+        (None, None) => "/v0/server/audit".into(),
+    }
+}
+
+impl From<CliAuditAction> for JsonAuditAction {
+    fn from(action: CliAuditAction) -> Self {
+        match action {
+            CliAuditAction::ServerRestart => Self::ServerRestart,
+            CliAuditAction::ServerBackup => Self::ServerBackup,
+            CliAuditAction::ServerConfigUpdate => Self::ServerConfigUpdate,
+            CliAuditAction::TokenCreate => Self::TokenCreate,
+            CliAuditAction::TokenRevoke => Self::TokenRevoke,
+            CliAuditAction::MemberInvite => Self::MemberInvite,
+            CliAuditAction::MemberRoleUpdate => Self::MemberRoleUpdate,
+            CliAuditAction::MemberRemove => Self::MemberRemove,
+            CliAuditAction::ServiceAccountCreate => Self::ServiceAccountCreate,
+            CliAuditAction::ServiceAccountDelete => Self::ServiceAccountDelete,
+            CliAuditAction::ProjectCreate => Self::ProjectCreate,
+            CliAuditAction::BranchCreate => Self::BranchCreate,
+            CliAuditAction::ThresholdCreate => Self::ThresholdCreate,
+            CliAuditAction::AlertUpdate => Self::AlertUpdate,
+            CliAuditAction::NotificationDelete => Self::NotificationDelete,
+            CliAuditAction::WebhookUpdate => Self::WebhookUpdate,
+            CliAuditAction::WebhookDelete => Self::WebhookDelete,
+            CliAuditAction::RoleCreate => Self::RoleCreate,
+            CliAuditAction::RoleDelete => Self::RoleDelete,
+            CliAuditAction::ProjectUpdate => Self::ProjectUpdate,
+            CliAuditAction::ProjectRoleUpdate => Self::ProjectRoleUpdate,
+            CliAuditAction::ProjectRoleRemove => Self::ProjectRoleRemove,
+            CliAuditAction::OrganizationCreate => Self::OrganizationCreate,
+            CliAuditAction::PlanCreate => Self::PlanCreate,
+            CliAuditAction::WebhookCreate => Self::WebhookCreate,
+            CliAuditAction::NotificationCreate => Self::NotificationCreate,
+            CliAuditAction::RoleUpdate => Self::RoleUpdate,
+        }
+    }
+}
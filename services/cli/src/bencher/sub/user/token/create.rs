@@ -1,7 +1,7 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::{JsonNewToken, NonEmpty, ResourceId};
+use bencher_json::{user::token::JsonTokenScope, JsonNewToken, NonEmpty, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
@@ -9,11 +9,14 @@ use crate::{
     CliError,
 };
 
+use super::scope::Scope;
+
 #[derive(Debug, Clone)]
 pub struct Create {
     pub user: ResourceId,
     pub name: NonEmpty,
     pub ttl: Option<u32>,
+    pub scopes: Option<Vec<JsonTokenScope>>,
     pub backend: Backend,
 }
 
@@ -25,12 +28,23 @@ impl TryFrom<CliTokenCreate> for Create {
             user,
             name,
             ttl,
+            scope,
             backend,
         } = create;
         Ok(Self {
             user,
             name,
             ttl,
+            scopes: if scope.is_empty() {
+                None
+            } else {
+                Some(
+                    scope
+                        .iter()
+                        .map(|scope| scope.parse::<Scope>().map(Into::into))
+                        .collect::<Result<_, _>>()?,
+                )
+            },
             backend: backend.try_into()?,
         })
     }
@@ -38,8 +52,10 @@ impl TryFrom<CliTokenCreate> for Create {
 
 impl From<Create> for JsonNewToken {
     fn from(create: Create) -> Self {
-        let Create { name, ttl, .. } = create;
-        Self { name, ttl }
+        let Create {
+            name, ttl, scopes, ..
+        } = create;
+        Self { name, ttl, scopes }
     }
 }
 
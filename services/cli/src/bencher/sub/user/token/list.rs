@@ -1,17 +1,18 @@
 use std::convert::TryFrom;
 
 use async_trait::async_trait;
-use bencher_json::ResourceId;
+use bencher_json::{user::token::JsonTokensSort, JsonPagination, ResourceId};
 
 use crate::{
     bencher::{backend::Backend, sub::SubCmd},
-    cli::user::token::CliTokenList,
+    cli::user::token::{CliTokenList, CliTokensSort},
     CliError,
 };
 
 #[derive(Debug)]
 pub struct List {
     pub user: ResourceId,
+    pub pagination: JsonPagination<JsonTokensSort>,
     pub backend: Backend,
 }
 
@@ -19,19 +20,33 @@ impl TryFrom<CliTokenList> for List {
     type Error = CliError;
 
     fn try_from(list: CliTokenList) -> Result<Self, Self::Error> {
-        let CliTokenList { user, backend } = list;
+        let CliTokenList {
+            user,
+            pagination,
+            backend,
+        } = list;
         Ok(Self {
             user,
+            pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliTokensSort> for JsonTokensSort {
+    fn from(sort: CliTokensSort) -> Self {
+        match sort {
+            CliTokensSort::Creation => Self::Creation,
+            CliTokensSort::Name => Self::Name,
+        }
+    }
+}
+
 #[async_trait]
 impl SubCmd for List {
     async fn exec(&self) -> Result<(), CliError> {
         self.backend
-            .get(&format!("/v0/users/{}/tokens", self.user))
+            .get_ls(&format!("/v0/users/{}/tokens", self.user), &self.pagination)
             .await?;
         Ok(())
     }
@@ -4,6 +4,8 @@ use crate::{bencher::sub::SubCmd, cli::user::token::CliToken, CliError};
 
 mod create;
 mod list;
+mod revoke;
+mod scope;
 mod view;
 
 #[derive(Debug)]
@@ -11,6 +13,7 @@ pub enum Token {
     List(list::List),
     Create(create::Create),
     View(view::View),
+    Revoke(revoke::Revoke),
 }
 
 impl TryFrom<CliToken> for Token {
@@ -21,6 +24,7 @@ impl TryFrom<CliToken> for Token {
             CliToken::List(list) => Self::List(list.try_into()?),
             CliToken::Create(create) => Self::Create(create.try_into()?),
             CliToken::View(view) => Self::View(view.try_into()?),
+            CliToken::Revoke(revoke) => Self::Revoke(revoke.try_into()?),
         })
     }
 }
@@ -32,6 +36,7 @@ impl SubCmd for Token {
             Self::List(list) => list.exec().await,
             Self::Create(create) => create.exec().await,
             Self::View(view) => view.exec().await,
+            Self::Revoke(revoke) => revoke.exec().await,
         }
     }
 }
@@ -0,0 +1,45 @@
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use bencher_json::ResourceId;
+use uuid::Uuid;
+
+use crate::{
+    bencher::{backend::Backend, sub::SubCmd},
+    cli::user::token::CliTokenView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Revoke {
+    pub user: ResourceId,
+    pub uuid: Uuid,
+    pub backend: Backend,
+}
+
+impl TryFrom<CliTokenView> for Revoke {
+    type Error = CliError;
+
+    fn try_from(revoke: CliTokenView) -> Result<Self, Self::Error> {
+        let CliTokenView {
+            user,
+            uuid,
+            backend,
+        } = revoke;
+        Ok(Self {
+            user,
+            uuid,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+#[async_trait]
+impl SubCmd for Revoke {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.backend
+            .delete(&format!("/v0/users/{}/tokens/{}", self.user, self.uuid))
+            .await?;
+        Ok(())
+    }
+}
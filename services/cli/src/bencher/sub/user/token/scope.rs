@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use bencher_json::user::token::{JsonTokenPermission, JsonTokenScope};
+
+use crate::CliError;
+
+const PROJECT_PERMISSIONS_DELIMITER: char = '=';
+const PERMISSIONS_DELIMITER: char = ',';
+
+#[derive(Debug, Clone)]
+pub struct Scope(JsonTokenScope);
+
+impl FromStr for Scope {
+    type Err = CliError;
+
+    fn from_str(scope: &str) -> Result<Self, Self::Err> {
+        let Some((project, permissions)) = scope.split_once(PROJECT_PERMISSIONS_DELIMITER) else {
+            return Err(CliError::TokenScope(scope.into()));
+        };
+        Ok(Self(JsonTokenScope {
+            project: project.trim().parse()?,
+            permissions: permissions
+                .split(PERMISSIONS_DELIMITER)
+                .map(|permission| match permission.trim() {
+                    "view" => Ok(JsonTokenPermission::View),
+                    "create" => Ok(JsonTokenPermission::Create),
+                    "edit" => Ok(JsonTokenPermission::Edit),
+                    "delete" => Ok(JsonTokenPermission::Delete),
+                    "manage" => Ok(JsonTokenPermission::Manage),
+                    _ => Err(CliError::TokenPermission(permission.into())),
+                })
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+}
+
+impl From<Scope> for JsonTokenScope {
+    fn from(scope: Scope) -> Self {
+        scope.0
+    }
+}
@@ -1,5 +1,7 @@
+use std::path::PathBuf;
+
 use bencher_json::{Jwt, Url};
-use clap::{ArgGroup, Args, Parser, Subcommand};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
 pub mod docs;
 pub mod mock;
@@ -11,13 +13,16 @@ pub mod user;
 #[cfg(feature = "docs")]
 use docs::CliDocs;
 use mock::CliMock;
-use organization::{member::CliMember, CliOrganization};
+use organization::{
+    member::CliMember, role::CliRole, service_account::CliServiceAccount, CliOrganization,
+};
 use project::{
-    alert::CliAlert, benchmark::CliBenchmark, branch::CliBranch, metric_kind::CliMetricKind,
-    perf::CliPerf, report::CliReport, result::CliResult, run::CliRun, testbed::CliTestbed,
-    threshold::CliThreshold, CliProject,
+    alert::CliAlert, benchmark::CliBenchmark, branch::CliBranch, compare::CliCompare,
+    metric_kind::CliMetricKind, notification::CliNotification, perf::CliPerf, report::CliReport,
+    result::CliResult, run::CliRun, testbed::CliTestbed, threshold::CliThreshold,
+    webhook::CliWebhook, CliProject,
 };
-use system::{auth::CliAuth, server::CliServer};
+use system::{audit::CliAudit, auth::CliAuth, server::CliServer};
 use user::{token::CliToken, CliUser};
 
 /// Bencher CLI
@@ -41,6 +46,12 @@ pub enum CliSub {
     /// Manage organization members
     #[clap(subcommand)]
     Member(CliMember),
+    /// Manage organization service accounts
+    #[clap(subcommand)]
+    ServiceAccount(CliServiceAccount),
+    /// Manage organization custom roles
+    #[clap(subcommand)]
+    Role(CliRole),
 
     /// Manage projects
     #[clap(subcommand)]
@@ -68,11 +79,19 @@ pub enum CliSub {
     /// View benchmarks
     #[clap(subcommand)]
     Benchmark(CliBenchmark),
-    /// View alerts
+    /// Manage alerts
     #[clap(subcommand)]
     Alert(CliAlert),
+    /// Manage alert notifications
+    #[clap(subcommand)]
+    Notification(CliNotification),
+    /// Manage webhooks
+    #[clap(subcommand)]
+    Webhook(CliWebhook),
     /// Query benchmark data
     Perf(CliPerf),
+    /// Compare benchmark results between two commits
+    Compare(CliCompare),
 
     /// View user
     #[clap(subcommand)]
@@ -84,6 +103,9 @@ pub enum CliSub {
     /// Server commands
     #[clap(subcommand)]
     Server(CliServer),
+    /// View the audit log of administrative and destructive actions
+    #[clap(subcommand)]
+    Audit(CliAudit),
 
     /// Generate mock benchmark data
     Mock(CliMock),
@@ -99,6 +121,10 @@ pub struct CliLocality {
     #[clap(long)]
     pub local: bool,
 
+    /// Local report and threshold directory (requires `--local`) (default is `.bencher`)
+    #[clap(long, requires = "local")]
+    pub local_dir: Option<PathBuf>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -127,3 +153,35 @@ pub struct CliBackend {
     #[clap(long)]
     pub retry_after: Option<u64>,
 }
+
+#[derive(Args, Debug)]
+pub struct CliPagination<T>
+where
+    T: ValueEnum + Clone + Send + Sync + 'static,
+{
+    /// The field to sort by
+    #[clap(value_enum, long)]
+    pub sort: Option<T>,
+
+    /// The direction to sort in
+    #[clap(value_enum, long)]
+    pub direction: Option<CliDirection>,
+
+    /// The number of items per page (default 255, max 255)
+    #[clap(long)]
+    pub per_page: Option<u8>,
+
+    /// Only list this page, starting at 1 (default all pages)
+    #[clap(long)]
+    pub page: Option<u32>,
+}
+
+/// Sort direction
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliDirection {
+    /// Ascending
+    Asc,
+    /// Descending
+    Desc,
+}
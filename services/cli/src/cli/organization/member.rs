@@ -1,7 +1,7 @@
 use bencher_json::{Email, ResourceId, UserName};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
 pub enum CliMember {
@@ -18,6 +18,10 @@ pub enum CliMember {
     /// Remove an organization member
     #[clap(alias = "rm")]
     Remove(CliMemberRemove),
+    /// Assign a project role to an organization member
+    Assign(CliMemberAssign),
+    /// Remove a project role from an organization member
+    Unassign(CliMemberUnassign),
 }
 
 #[derive(Parser, Debug)]
@@ -26,10 +30,23 @@ pub struct CliMemberList {
     #[clap(long)]
     pub org: ResourceId,
 
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliMembersSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliMembersSort {
+    /// Sort by name
+    Name,
+    /// Sort by email
+    Email,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliMemberInvite {
     /// Organization slug or UUID
@@ -95,6 +112,44 @@ pub struct CliMemberRemove {
     pub backend: CliBackend,
 }
 
+#[derive(Parser, Debug)]
+pub struct CliMemberAssign {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    /// User slug or UUID
+    pub user: ResourceId,
+
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Project role, either built-in or custom (ie `developer` or `reporter`)
+    #[clap(long)]
+    pub role: String,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliMemberUnassign {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    /// User slug or UUID
+    pub user: ResourceId,
+
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
 /// Role within the organization
 #[derive(ValueEnum, Debug, Clone)]
 pub enum CliMemberRole {
@@ -1,11 +1,13 @@
 use bencher_json::{NonEmpty, ResourceId, Slug};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 pub mod member;
 #[cfg(feature = "plus")]
 pub mod plan;
+pub mod role;
+pub mod service_account;
 #[cfg(feature = "plus")]
 pub mod usage;
 
@@ -37,10 +39,23 @@ pub enum CliOrganization {
 
 #[derive(Parser, Debug)]
 pub struct CliOrganizationList {
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliOrganizationsSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliOrganizationsSort {
+    /// Sort by name
+    Name,
+    /// Sort by creation order
+    Created,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliOrganizationCreate {
     /// Organization name
@@ -0,0 +1,111 @@
+use bencher_json::{ResourceId, Slug};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::cli::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliRole {
+    /// List organization custom roles
+    #[clap(alias = "ls")]
+    List(CliRoleList),
+    /// Create an organization custom role
+    #[clap(alias = "add")]
+    Create(CliRoleCreate),
+    /// View an organization custom role
+    View(CliRoleView),
+    /// Update the permissions of an organization custom role
+    #[clap(alias = "edit")]
+    Update(CliRoleUpdate),
+    /// Delete an organization custom role that is no longer assigned
+    #[clap(alias = "rm")]
+    Delete(CliRoleView),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleList {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliRolesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRolesSort {
+    /// Sort by name
+    Name,
+    /// Sort by creation date time
+    Creation,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleCreate {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    /// Custom role name, used to assign the role on a project
+    pub name: Slug,
+
+    /// Project permission granted by the custom role
+    #[clap(value_enum, long, required = true)]
+    pub permission: Vec<CliRolePermission>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Project permission
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRolePermission {
+    View,
+    Create,
+    Edit,
+    Delete,
+    Manage,
+    ViewRole,
+    CreateRole,
+    EditRole,
+    DeleteRole,
+    /// Only upload reports
+    CreateReport,
+    /// Only create thresholds
+    ManageThreshold,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleUpdate {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    /// Custom role name or UUID
+    pub role: ResourceId,
+
+    /// Project permission granted by the custom role, replacing all current permissions
+    #[clap(value_enum, long, required = true)]
+    pub permission: Vec<CliRolePermission>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleView {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    /// Custom role name or UUID
+    pub role: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -0,0 +1,83 @@
+use bencher_json::{ResourceId, Slug, UserName};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::cli::{CliBackend, CliPagination};
+
+use super::member::CliMemberRole;
+
+#[derive(Subcommand, Debug)]
+pub enum CliServiceAccount {
+    /// List organization service accounts
+    #[clap(alias = "ls")]
+    List(CliServiceAccountList),
+    /// Create an organization service account
+    #[clap(alias = "add")]
+    Create(CliServiceAccountCreate),
+    /// View an organization service account
+    View(CliServiceAccountView),
+    /// Delete an organization service account, locking all of its API tokens
+    #[clap(alias = "rm")]
+    Delete(CliServiceAccountView),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliServiceAccountList {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliServiceAccountsSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliServiceAccountsSort {
+    /// Sort by name
+    Name,
+    /// Sort by creation date time
+    Creation,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliServiceAccountCreate {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    /// Service account name
+    #[clap(long)]
+    pub name: UserName,
+
+    /// Service account slug
+    #[clap(long)]
+    pub slug: Option<Slug>,
+
+    /// Organization role, if more than view access to the organization is needed
+    #[clap(value_enum, long)]
+    pub role: Option<CliMemberRole>,
+
+    /// Project role for the service account, either built-in or custom (ie `my-project=developer`)
+    #[clap(long)]
+    pub project: Vec<String>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliServiceAccountView {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: ResourceId,
+
+    /// Service account slug or UUID
+    pub service_account: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,8 +1,8 @@
 use bencher_json::ResourceId;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use uuid::Uuid;
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
 pub enum CliAlert {
@@ -11,6 +11,9 @@ pub enum CliAlert {
     List(CliAlertList),
     /// View a alert
     View(CliAlertView),
+    /// Update an alert
+    #[clap(alias = "edit")]
+    Update(CliAlertUpdate),
 }
 
 #[derive(Parser, Debug)]
@@ -19,10 +22,23 @@ pub struct CliAlertList {
     #[clap(long)]
     pub project: ResourceId,
 
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliAlertsSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAlertsSort {
+    /// Sort by report date time
+    DateTime,
+    /// Sort by status
+    Status,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliAlertView {
     /// Project slug or UUID
@@ -35,3 +51,30 @@ pub struct CliAlertView {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliAlertUpdate {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Alert UUID
+    pub alert: Uuid,
+
+    /// Alert status
+    #[clap(value_enum, long)]
+    pub status: Option<CliAlertStatus>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Alert status that can be set by a user
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAlertStatus {
+    /// A known alert for a regression that is still ongoing
+    Acknowledged,
+    /// A false positive alert
+    Dismissed,
+}
@@ -1,8 +1,8 @@
 use bencher_json::ResourceId;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use uuid::Uuid;
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
 pub enum CliBenchmark {
@@ -19,10 +19,23 @@ pub struct CliBenchmarkList {
     #[clap(long)]
     pub project: ResourceId,
 
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliBenchmarksSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliBenchmarksSort {
+    /// Sort by name
+    Name,
+    /// Sort by creation order
+    Created,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliBenchmarkView {
     /// Project slug or UUID
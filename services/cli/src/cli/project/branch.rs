@@ -1,7 +1,7 @@
 use bencher_json::{BranchName, ResourceId, Slug};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
 pub enum CliBranch {
@@ -21,10 +21,23 @@ pub struct CliBranchList {
     #[clap(long)]
     pub project: ResourceId,
 
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliBranchesSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliBranchesSort {
+    /// Sort by name
+    Name,
+    /// Sort by creation order
+    Created,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliBranchCreate {
     /// Project slug or UUID
@@ -0,0 +1,32 @@
+use bencher_json::ResourceId;
+use clap::Parser;
+
+use crate::cli::CliBackend;
+
+#[derive(Parser, Debug)]
+pub struct CliCompare {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Base git hash, abbreviated hash, or report UUID
+    pub base: String,
+
+    /// Head git hash, abbreviated hash, or report UUID
+    pub head: String,
+
+    /// Testbed slug or UUID
+    #[clap(long)]
+    pub testbed: Option<ResourceId>,
+
+    /// Branch slug or UUID
+    #[clap(long)]
+    pub branch: Option<ResourceId>,
+
+    /// Output results in a table
+    #[clap(long)]
+    pub table: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,18 +1,21 @@
 use bencher_json::{NonEmpty, ResourceId, Slug, Url};
 use clap::{Parser, Subcommand, ValueEnum};
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 pub mod alert;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
 pub mod metric_kind;
+pub mod notification;
 pub mod perf;
 pub mod report;
 pub mod result;
 pub mod run;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 #[derive(Subcommand, Debug)]
 pub enum CliProject {
@@ -24,6 +27,9 @@ pub enum CliProject {
     Create(CliProjectCreate),
     // View a project
     View(CliProjectView),
+    // Update a project
+    #[clap(alias = "edit")]
+    Update(CliProjectUpdate),
 }
 
 #[derive(Parser, Debug)]
@@ -36,10 +42,23 @@ pub struct CliProjectList {
     #[clap(long, conflicts_with = "org")]
     pub public: bool,
 
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliProjectsSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliProjectsSort {
+    /// Sort by name
+    Name,
+    /// Sort by creation order
+    Created,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliProjectCreate {
     /// Organization slug or UUID
@@ -61,6 +80,10 @@ pub struct CliProjectCreate {
     #[clap(long)]
     pub visibility: Option<CliProjectVisibility>,
 
+    /// Create missing custom metric kinds the first time that they are reported
+    #[clap(long)]
+    pub auto_metric_kinds: bool,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -87,3 +110,16 @@ pub struct CliProjectView {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliProjectUpdate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Create missing custom metric kinds the first time that they are reported
+    #[clap(long)]
+    pub auto_metric_kinds: Option<bool>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -0,0 +1,83 @@
+use bencher_json::{Email, ResourceId};
+use clap::{Parser, Subcommand, ValueEnum};
+use uuid::Uuid;
+
+use crate::cli::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliNotification {
+    /// List notifications
+    #[clap(alias = "ls")]
+    List(CliNotificationList),
+    /// Create a notification
+    #[clap(alias = "add")]
+    Create(CliNotificationCreate),
+    /// View a notification
+    View(CliNotificationView),
+    /// Delete a notification
+    #[clap(alias = "rm")]
+    Delete(CliNotificationDelete),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliNotificationList {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliNotificationsSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliNotificationsSort {
+    /// Sort by creation order
+    Created,
+    /// Sort by email
+    Email,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliNotificationCreate {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Email address to notify
+    #[clap(long)]
+    pub email: Email,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliNotificationView {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Notification UUID
+    pub notification: Uuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliNotificationDelete {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Notification UUID
+    pub notification: Uuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
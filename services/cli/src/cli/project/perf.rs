@@ -1,11 +1,56 @@
 use bencher_json::ResourceId;
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use uuid::Uuid;
 
 use crate::cli::CliBackend;
 
 #[derive(Parser, Debug)]
+#[clap(args_conflicts_with_subcommands = true)]
 pub struct CliPerf {
+    #[clap(subcommand)]
+    pub sub: Option<CliPerfSub>,
+
+    // The project and metric kind are only optional here so that they are not needed by the subcommands
+    /// Project slug or UUID
+    #[clap(long, required = true)]
+    pub project: Option<ResourceId>,
+
+    /// Metric kind slug or UUID
+    #[clap(long, required = true)]
+    pub metric_kind: Option<ResourceId>,
+
+    #[clap(flatten)]
+    pub filter: CliPerfFilter,
+
+    /// Output results in a table
+    #[clap(long)]
+    pub table: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CliPerfSub {
+    /// Detect shifts in benchmark data across its history
+    Changepoints(CliPerfChangepoints),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliPerfChangepoints {
+    #[clap(flatten)]
+    pub query: CliPerfQuery,
+
+    /// Output results in a table
+    #[clap(long)]
+    pub table: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Args, Debug)]
+pub struct CliPerfQuery {
     /// Project slug or UUID
     #[clap(long)]
     pub project: ResourceId,
@@ -14,6 +59,12 @@ pub struct CliPerf {
     #[clap(long)]
     pub metric_kind: ResourceId,
 
+    #[clap(flatten)]
+    pub filter: CliPerfFilter,
+}
+
+#[derive(Args, Debug)]
+pub struct CliPerfFilter {
     /// Branch UUIDs
     #[clap(long)]
     pub branches: Vec<Uuid>,
@@ -33,11 +84,4 @@ pub struct CliPerf {
     /// End time
     #[clap(long)]
     pub end_time: Option<i64>,
-
-    /// Output results in a table
-    #[clap(long)]
-    pub table: bool,
-
-    #[clap(flatten)]
-    pub backend: CliBackend,
 }
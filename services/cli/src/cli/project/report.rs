@@ -1,9 +1,12 @@
+use std::path::PathBuf;
+
 use bencher_json::ResourceId;
-use clap::{Parser, Subcommand};
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
 use uuid::Uuid;
 
 use super::run::CliRun;
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
 pub enum CliReport {
@@ -15,6 +18,11 @@ pub enum CliReport {
     Create(Box<CliRun>),
     /// View a report
     View(CliReportView),
+    /// View the raw benchmark command output for a report
+    Output(CliReportView),
+    /// Upload any reports that were spooled because they failed to send
+    #[clap(alias = "flush")]
+    Upload(CliReportUpload),
 }
 
 #[derive(Parser, Debug)]
@@ -23,10 +31,47 @@ pub struct CliReportList {
     #[clap(long)]
     pub project: ResourceId,
 
+    /// Git commit hash or an abbreviated prefix of one
+    #[clap(long)]
+    pub hash: Option<String>,
+
+    /// Branch slug or UUID
+    #[clap(long)]
+    pub branch: Option<ResourceId>,
+
+    /// Testbed slug or UUID
+    #[clap(long)]
+    pub testbed: Option<ResourceId>,
+
+    /// User slug or UUID
+    #[clap(long)]
+    pub user: Option<ResourceId>,
+
+    /// Only reports that started at or after this time (ex: 2023-01-01T00:00:00Z)
+    #[clap(long)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only reports that ended at or before this time (ex: 2023-01-31T00:00:00Z)
+    #[clap(long)]
+    pub until: Option<DateTime<Utc>>,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliReportsSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliReportsSort {
+    /// Sort by report date time
+    DateTime,
+    /// Sort by branch version number
+    Version,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliReportView {
     /// Project slug or UUID
@@ -39,3 +84,17 @@ pub struct CliReportView {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliReportUpload {
+    /// Spooled report directory (default is `.bencher/spool`)
+    #[clap(long)]
+    pub spool_dir: Option<PathBuf>,
+
+    /// Move any rejected reports to a `failed` subdirectory and keep uploading the rest
+    #[clap(long)]
+    pub skip_failed: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,12 +1,17 @@
 use std::path::PathBuf;
 
 use bencher_json::{BranchName, ResourceId};
-use clap::{Args, Parser, ValueEnum};
+use clap::{ArgGroup, Args, Parser, ValueEnum};
 
 use crate::cli::CliLocality;
 
 #[allow(clippy::option_option, clippy::struct_excessive_bools)]
 #[derive(Parser, Debug)]
+#[clap(group(
+    ArgGroup::new("iterations")
+        .multiple(true)
+        .args(&["iter", "file"]),
+))]
 pub struct CliRun {
     /// Project slug or UUID (or set BENCHER_PROJECT)
     #[clap(long)]
@@ -27,18 +32,35 @@ pub struct CliRun {
     #[clap(value_enum, long)]
     pub adapter: Option<CliRunAdapter>,
 
+    /// Custom adapter config file path (JSON or TOML)
+    #[clap(long, conflicts_with = "adapter")]
+    pub adapter_config: Option<PathBuf>,
+
+    /// Benchmark command output stream to use as the adapter input (default is `stdout`)
+    #[clap(
+        value_enum,
+        long,
+        conflicts_with = "file",
+        conflicts_with = "criterion_dir"
+    )]
+    pub stream: Option<CliRunStream>,
+
     /// Benchmark harness suggested central tendency (ie average)
     #[clap(value_enum, long)]
     pub average: Option<CliRunAverage>,
 
-    /// Number of run iterations (default is `1`)
+    /// Number of run iterations (default is `1`), ignored without a command as each `--file` match is an iteration
     #[clap(long)]
     pub iter: Option<usize>,
 
-    /// Fold multiple metrics into a single metric
-    #[clap(value_enum, long, requires = "iter")]
+    /// Fold multiple metrics into a single metric (requires `--iter` or `--file`)
+    #[clap(value_enum, long, requires = "iterations")]
     pub fold: Option<CliRunFold>,
 
+    /// Create a metric kind the first time that it is reported (ie `"Binary Size=bytes"`)
+    #[clap(long)]
+    pub metric_kind: Vec<String>,
+
     /// Allow test failure
     #[clap(long)]
     pub allow_failure: bool,
@@ -47,6 +69,20 @@ pub struct CliRun {
     #[clap(long)]
     pub err: bool,
 
+    /// Save the raw benchmark command output with the report, keeping the last 64 KiB of each stream
+    #[clap(long)]
+    pub save_output: bool,
+
+    /// Save the report to this directory if it fails to send, to upload later with `bencher report upload` (default is `.bencher/spool`)
+    #[clap(long, conflicts_with = "local")]
+    pub spool_dir: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub fingerprint: CliRunFingerprint,
+
+    #[clap(flatten)]
+    pub summary: CliRunSummary,
+
     #[clap(flatten)]
     pub command: CliRunCommand,
 
@@ -77,14 +113,48 @@ pub struct CliRunBranch {
     pub endif_branch: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct CliRunFingerprint {
+    /// Do not collect a testbed hardware and software fingerprint
+    #[clap(long)]
+    pub no_fingerprint: bool,
+
+    /// Toolchain command to add to the fingerprint with its `--version` output (ie `rustc`)
+    #[clap(long, conflicts_with = "no_fingerprint")]
+    pub fingerprint_toolchain: Vec<String>,
+
+    /// Environment variable to add to the fingerprint (ie `RUSTFLAGS`)
+    #[clap(long, conflicts_with = "no_fingerprint")]
+    pub fingerprint_env: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct CliRunSummary {
+    /// Output a Markdown summary of the report (ie for a pull request comment)
+    #[clap(long, conflicts_with = "local")]
+    pub summary: bool,
+
+    /// Append the Markdown summary to a file instead of stdout (ie `$GITHUB_STEP_SUMMARY`)
+    #[clap(long, conflicts_with = "local")]
+    pub summary_file: Option<PathBuf>,
+
+    /// Branch slug or UUID to compare against in the Markdown summary (ie `main`)
+    #[clap(long, conflicts_with = "local")]
+    pub summary_base: Option<ResourceId>,
+}
+
 #[derive(Args, Debug)]
 pub struct CliRunCommand {
     #[clap(flatten)]
     pub shell: CliRunShell,
 
-    /// Benchmark command output file path
-    #[clap(long, requires = "cmd")]
-    pub file: Option<PathBuf>,
+    /// Benchmark command output file path. Without a command, a glob pattern of pre-existing output files that are each read as an iteration (ie `"results/*.json"`)
+    #[clap(long)]
+    pub file: Option<String>,
+
+    /// Criterion output directory to read JSON estimates from (ie `target/criterion`)
+    #[clap(long, requires = "cmd", conflicts_with = "file")]
+    pub criterion_dir: Option<PathBuf>,
 
     /// Benchmark command
     pub cmd: Option<String>,
@@ -149,6 +219,14 @@ pub enum CliRunAdapter {
     RustBench,
     /// 🦀 Rust Criterion
     RustCriterion,
+    /// 🦀 Rust Criterion JSON
+    RustCriterionJson,
+    /// 🦀 Rust Iai
+    RustIai,
+    /// 🐚 Shell
+    Shell,
+    /// 🐚 Shell Hyperfine
+    ShellHyperfine,
 }
 
 /// Suggested Central Tendency (Average)
@@ -161,6 +239,18 @@ pub enum CliRunAverage {
     Median,
 }
 
+/// Benchmark Command Output Streams
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRunStream {
+    /// Standard output
+    Stdout,
+    /// Standard error
+    Stderr,
+    /// Standard output followed by standard error
+    Both,
+}
+
 /// Supported Fold Operations
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
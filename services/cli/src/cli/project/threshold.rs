@@ -2,7 +2,7 @@ use bencher_json::ResourceId;
 use clap::{Parser, Subcommand, ValueEnum};
 use uuid::Uuid;
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
 pub enum CliThreshold {
@@ -22,10 +22,23 @@ pub struct CliThresholdList {
     #[clap(long)]
     pub project: ResourceId,
 
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliThresholdsSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliThresholdsSort {
+    /// Sort by creation order
+    Created,
+    /// Sort by testbed name
+    Testbed,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliThresholdCreate {
     /// Project slug or UUID
@@ -76,6 +89,20 @@ pub struct CliStatisticCreate {
     /// Right side percentage
     #[clap(long)]
     pub right_side: Option<f32>,
+
+    /// Baseline average for percentage and absolute tests
+    #[clap(value_enum, long)]
+    pub baseline: Option<CliStatisticBaseline>,
+
+    /// Lower limit: a percent for percentage tests, metric kind units for absolute tests,
+    /// or a multiple of the spread below the fence for MAD and IQR tests
+    #[clap(long)]
+    pub lower_limit: Option<f64>,
+
+    /// Upper limit: a percent for percentage tests, metric kind units for absolute tests,
+    /// or a multiple of the spread above the fence for MAD and IQR tests
+    #[clap(long)]
+    pub upper_limit: Option<f64>,
 }
 
 /// Supported kinds of statistic
@@ -85,6 +112,24 @@ pub enum CliStatisticKind {
     Z,
     /// t-test
     T,
+    /// Percentage change from the baseline
+    Percentage,
+    /// Absolute change from the baseline
+    Absolute,
+    /// Median absolute deviation
+    Mad,
+    /// Interquartile range fences
+    Iqr,
+}
+
+/// Supported baseline averages
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliStatisticBaseline {
+    /// Mean of the historical data
+    Mean,
+    /// Median of the historical data
+    Median,
 }
 
 #[derive(Parser, Debug)]
@@ -0,0 +1,161 @@
+use bencher_json::{ResourceId, Secret, Url};
+use clap::{Parser, Subcommand, ValueEnum};
+use uuid::Uuid;
+
+use crate::cli::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliWebhook {
+    /// List webhooks
+    #[clap(alias = "ls")]
+    List(CliWebhookList),
+    /// Create a webhook
+    #[clap(alias = "add")]
+    Create(CliWebhookCreate),
+    /// View a webhook
+    View(CliWebhookView),
+    /// Update a webhook
+    #[clap(alias = "edit")]
+    Update(CliWebhookUpdate),
+    /// Delete a webhook
+    #[clap(alias = "rm")]
+    Delete(CliWebhookDelete),
+    /// List webhook deliveries
+    Deliveries(CliWebhookDeliveries),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookList {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhooksSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhooksSort {
+    /// Sort by creation order
+    Created,
+    /// Sort by URL
+    Url,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookCreate {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Webhook URL
+    #[clap(long)]
+    pub url: Url,
+
+    /// Secret used to sign the webhook payload
+    #[clap(long)]
+    pub secret: Option<Secret>,
+
+    /// Event to send (default: all events)
+    #[clap(value_enum, long)]
+    pub event: Option<Vec<CliWebhookEvent>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Webhook event
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhookEvent {
+    /// A new report
+    Report,
+    /// A new branch
+    Branch,
+    /// A new threshold
+    Threshold,
+    /// New alerts generated by a report
+    Alert,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookView {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: Uuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookUpdate {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: Uuid,
+
+    /// Webhook URL
+    #[clap(long)]
+    pub url: Option<Url>,
+
+    /// Secret used to sign the webhook payload
+    #[clap(long)]
+    pub secret: Option<Secret>,
+
+    /// Event to send
+    #[clap(value_enum, long)]
+    pub event: Option<Vec<CliWebhookEvent>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDelete {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: Uuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDeliveries {
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: Uuid,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhookDeliveriesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhookDeliveriesSort {
+    /// Sort by creation order
+    Created,
+    /// Sort by last modified date time
+    Modified,
+}
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use bencher_json::ResourceId;
+use chrono::{DateTime, Utc};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+
+use crate::cli::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliAudit {
+    /// List audit events
+    #[clap(alias = "ls")]
+    List(CliAuditList),
+    /// Export all matching audit events to a file
+    Export(CliAuditExport),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAuditList {
+    #[clap(flatten)]
+    pub query: CliAuditQuery,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliAuditEventsSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliAuditExport {
+    #[clap(flatten)]
+    pub query: CliAuditQuery,
+
+    /// File to write the audit events to as JSON
+    #[clap(long)]
+    pub file: PathBuf,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Without an organization or project, the audit events for the whole server are listed (admin only)
+#[derive(Args, Debug)]
+#[clap(group(
+    ArgGroup::new("scope")
+        .multiple(false)
+        .args(&["org", "project"]),
+))]
+pub struct CliAuditQuery {
+    /// Organization slug or UUID
+    #[clap(long)]
+    pub org: Option<ResourceId>,
+
+    /// Project slug or UUID
+    #[clap(long)]
+    pub project: Option<ResourceId>,
+
+    /// Audit action
+    #[clap(value_enum, long)]
+    pub action: Option<CliAuditAction>,
+
+    /// Slug or UUID of the user that performed the action
+    #[clap(long)]
+    pub user: Option<ResourceId>,
+
+    /// Only events at or after this time (ex: 2023-01-01T00:00:00Z)
+    #[clap(long)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only events at or before this time (ex: 2023-01-31T00:00:00Z)
+    #[clap(long)]
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAuditEventsSort {
+    /// Sort by event date time
+    Creation,
+    /// Sort by action
+    Action,
+}
+
+/// Audit action
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAuditAction {
+    ServerRestart,
+    ServerBackup,
+    ServerConfigUpdate,
+    TokenCreate,
+    TokenRevoke,
+    MemberInvite,
+    MemberRoleUpdate,
+    MemberRemove,
+    ServiceAccountCreate,
+    ServiceAccountDelete,
+    ProjectCreate,
+    BranchCreate,
+    ThresholdCreate,
+    AlertUpdate,
+    NotificationDelete,
+    WebhookUpdate,
+    WebhookDelete,
+    RoleCreate,
+    RoleDelete,
+    ProjectUpdate,
+    ProjectRoleUpdate,
+    ProjectRoleRemove,
+    OrganizationCreate,
+    PlanCreate,
+    WebhookCreate,
+    NotificationCreate,
+    RoleUpdate,
+}
@@ -1,8 +1,8 @@
 use bencher_json::{NonEmpty, ResourceId};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use uuid::Uuid;
 
-use crate::cli::CliBackend;
+use crate::cli::{CliBackend, CliPagination};
 
 #[derive(Subcommand, Debug)]
 pub enum CliToken {
@@ -14,6 +14,8 @@ pub enum CliToken {
     Create(CliTokenCreate),
     /// View a token
     View(CliTokenView),
+    /// Revoke a token
+    Revoke(CliTokenView),
 }
 
 #[derive(Parser, Debug)]
@@ -22,10 +24,23 @@ pub struct CliTokenList {
     #[clap(long)]
     pub user: ResourceId,
 
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliTokensSort>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Sort field
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliTokensSort {
+    /// Sort by creation date time
+    Creation,
+    /// Sort by name
+    Name,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliTokenCreate {
     /// User slug or UUID
@@ -39,6 +54,10 @@ pub struct CliTokenCreate {
     #[clap(long)]
     pub ttl: Option<u32>,
 
+    /// Limit the token to permissions on a project (ie `my-project=view,create`)
+    #[clap(long)]
+    pub scope: Vec<String>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -13,6 +13,8 @@ pub enum CliError {
     ResourceId(String),
     #[error("Failed to find Bencher project. Set the `--project` flag or the `BENCHER_PROJECT` environment variable.")]
     ProjectNotFound,
+    #[error("Failed to find Bencher metric kind. Set the `--metric-kind` flag.")]
+    MetricKindNotFound,
     #[error("Branch env var `BENCHER_BRANCH` was set to an invalid value: {0}")]
     BranchInvalid(String),
     #[error("Testbed env var `BENCHER_TESTBED` was set to an invalid value: {0}")]
@@ -31,12 +33,34 @@ pub enum CliError {
     Alerts,
     #[error("Failed to send after {0} attempt(s)")]
     Send(usize),
+    #[error("Failed to send after {0} attempt(s). The report was spooled to {1}, upload it later with `bencher report upload`.")]
+    Spooled(usize, String),
+    #[error("Failed to upload spooled report {0}: {1}\nFix or remove it, or set `--skip-failed`, to upload the remaining spooled reports.")]
+    SpoolUpload(String, String),
     #[error("Arithmetic error")]
     BadMath,
     #[error("Error Code: {0}")]
     ErrorCode(String),
     #[error("Failed to run benchmark command: {0}")]
     Output(Output),
+    #[error("Invalid metric kind, expected `NAME=UNITS`: {0}")]
+    MetricKind(String),
+    #[error("Invalid token scope, expected `PROJECT=PERMISSION,...`: {0}")]
+    TokenScope(String),
+    #[error("Invalid token scope permission, expected `view`, `create`, `edit`, `delete`, or `manage`: {0}")]
+    TokenPermission(String),
+    #[error("Invalid service account project role, expected `PROJECT=ROLE`: {0}")]
+    ServiceAccountProject(String),
+    #[error("Invalid project role, expected `viewer`, `developer`, `maintainer`, or a custom role name: {0}")]
+    ProjectRole(String),
+    #[error("Failed to find any benchmark output files matching: {0}")]
+    FilesNotFound(String),
+    #[error("Failed to find any Criterion benchmark results in: {0}")]
+    CriterionDir(String),
+    #[error("Invalid statistic limit, expected a non-negative number: {0}")]
+    StatisticLimit(f64),
+    #[error("Percentage and absolute statistics require a lower limit, an upper limit, or both")]
+    StatisticLimitMissing,
     #[error("Mock failure")]
     MockFailure,
 
@@ -44,16 +68,26 @@ pub enum CliError {
     Url(#[from] url::ParseError),
     #[error("Failed to parse UUID: {0}")]
     Uuid(#[from] uuid::Error),
+    #[error("Failed to export audit events, expected a list of events: {0}")]
+    AuditExport(String),
     #[error("Failed to (de)serialize JSON: {0}")]
     Serde(#[from] serde_json::Error),
+    #[error("Failed to deserialize TOML: {0}")]
+    Toml(#[from] toml::de::Error),
     #[error("Failed to send request: {0}")]
     Client(#[from] reqwest::Error),
     #[error("Failed to convert from UTF-8: {0}")]
     FromUtf8(#[from] std::string::FromUtf8Error),
     #[error("Failed to run benchmark command: {0}")]
     Io(#[from] std::io::Error),
+    #[error("Invalid benchmark output file glob pattern: {0}")]
+    GlobPattern(#[from] glob::PatternError),
+    #[error("Failed to read benchmark output file: {0}")]
+    Glob(#[from] glob::GlobError),
     #[error("Failed run adapter: {0}")]
     Adapter(#[from] bencher_adapter::AdapterError),
+    #[error("Failed to detect boundary: {0}")]
+    Boundary(#[from] bencher_boundary::BoundaryError),
     #[error("Failed to validate: {0}")]
     Valid(#[from] bencher_json::ValidError),
     #[error("Failed to parse URL encoding: {0}")]
@@ -94,6 +94,18 @@ const alertsConfig = {
 					key: "outlier",
 					display: Display.RAW,
 				},
+				{
+					kind: Card.FIELD,
+					label: "Units",
+					key: "units",
+					display: Display.RAW,
+				},
+				{
+					kind: Card.FIELD,
+					label: "Status",
+					key: "status",
+					display: Display.RAW,
+				},
 			],
 		},
 	},
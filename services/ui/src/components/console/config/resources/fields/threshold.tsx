@@ -8,6 +8,14 @@ const validate_boundary = (input: string) => {
 	return Number.isFinite(num) && num >= 0.5 && num <= 1.0;
 };
 
+const validate_limit = (input: string) => {
+	if (input.length === 0) {
+		return false;
+	}
+	const num = Number(input);
+	return Number.isFinite(num) && num >= 0;
+};
+
 const THRESHOLD_FIELDS = {
 	branch: {
 		icon: "fas fa-code-branch",
@@ -70,6 +78,20 @@ const THRESHOLD_FIELDS = {
 		help: "Must be between 0.5000 and 1.0000",
 		validate: validate_boundary,
 	},
+	lower_limit: {
+		type: "input",
+		placeholder: "10",
+		icon: "fas fa-arrow-down",
+		help: "Must be a number greater than or equal to zero",
+		validate: validate_limit,
+	},
+	upper_limit: {
+		type: "input",
+		placeholder: "10",
+		icon: "fas fa-arrow-up",
+		help: "Must be a number greater than or equal to zero",
+		validate: validate_limit,
+	},
 };
 
 export default THRESHOLD_FIELDS;
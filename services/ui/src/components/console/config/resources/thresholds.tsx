@@ -15,6 +15,22 @@ const TEST_VALUE = {
 			value: "t",
 			option: "Student's t-test",
 		},
+		{
+			value: "percentage",
+			option: "Percentage Change",
+		},
+		{
+			value: "absolute",
+			option: "Absolute Change",
+		},
+		{
+			value: "mad",
+			option: "Median Absolute Deviation",
+		},
+		{
+			value: "iqr",
+			option: "Interquartile Range",
+		},
 	],
 };
 
@@ -142,6 +158,26 @@ const thresholdsConfig = {
 					nullable: true,
 					config: THRESHOLD_FIELDS.right_side,
 				},
+				{
+					kind: FieldKind.NUMBER,
+					label: "Lower Limit",
+					key: "lower_limit",
+					value: "",
+					valid: true,
+					validate: true,
+					nullable: true,
+					config: THRESHOLD_FIELDS.lower_limit,
+				},
+				{
+					kind: FieldKind.NUMBER,
+					label: "Upper Limit",
+					key: "upper_limit",
+					value: "",
+					valid: true,
+					validate: true,
+					nullable: true,
+					config: THRESHOLD_FIELDS.upper_limit,
+				},
 				{
 					kind: FieldKind.NUMBER,
 					label: "Minimum Sample Size",
@@ -8,7 +8,7 @@ import {
 } from "solid-js";
 import { isPerfTab, is_range, PerfTab, Range } from "../../config/types";
 import { is_valid_slug } from "bencher_valid";
-import { get_options, validate_string } from "../../../site/util";
+import { get_options, validate_string, get_ls_all } from "../../../site/util";
 import PerfHeader from "./PerfHeader";
 import PerfPlot from "./plot/PerfPlot";
 import { createStore } from "solid-js/store";
@@ -264,8 +264,7 @@ const PerfPanel = (props) => {
 
 	const getPerfTab = async (perf_tab: PerfTab, token: null | string) => {
 		const url = props.config?.plot?.tab_url(project_slug(), perf_tab);
-		return await axios(get_options(url, token))
-			.then((resp) => resp?.data)
+		return await get_ls_all(url, token)
 			.catch((error) => {
 				console.error(error);
 				return [];
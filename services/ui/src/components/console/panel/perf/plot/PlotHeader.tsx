@@ -1,4 +1,3 @@
-import axios from "axios";
 import {
 	createEffect,
 	createMemo,
@@ -6,7 +5,7 @@ import {
 	createSignal,
 	For,
 } from "solid-js";
-import { get_options } from "../../../../site/util";
+import { get_ls_all } from "../../../../site/util";
 import { Range } from "../../../config/types";
 
 const BENCHER_METRIC_KIND = "--bencher--metric--kind--";
@@ -26,9 +25,8 @@ const PlotHeader = (props) => {
 			slug: BENCHER_METRIC_KIND,
 		};
 		const url = props.config?.metric_kinds_url(fetcher.project);
-		return await axios(get_options(url, fetcher.token))
-			.then((resp) => {
-				let data = resp?.data;
+		return await get_ls_all(url, fetcher.token)
+			.then((data) => {
 				data.push(SELECT_METRIC_KIND);
 				return data;
 			})
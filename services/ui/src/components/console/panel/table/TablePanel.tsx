@@ -4,6 +4,7 @@ import {
 	createResource,
 	createMemo,
 	createEffect,
+	Show,
 } from "solid-js";
 import Table from "./Table";
 
@@ -14,10 +15,15 @@ import {
 	NOTIFY_TEXT_PARAM,
 	validate_jwt,
 	PLAN_PARAM,
+	paginated_url,
+	TOTAL_COUNT_HEADER,
 } from "../../../site/util";
 import { useNavigate } from "solid-app-router";
 import Forward, { forward_path } from "../../../site/Forward";
 
+// The number of items to show on each page of the table
+const TABLE_PER_PAGE = 25;
+
 const TablePanel = (props) => {
 	const navigate = useNavigate();
 
@@ -37,18 +43,32 @@ const TablePanel = (props) => {
 	});
 
 	const getLs = async (fetcher) => {
-		const EMPTY_ARRAY = [];
+		const EMPTY_PAGE = { data: [], total_count: 0 };
 		if (!validate_jwt(fetcher.token)) {
-			return EMPTY_ARRAY;
+			return EMPTY_PAGE;
 		}
-		return await axios(get_options(url(), fetcher.token))
-			.then((resp) => resp?.data)
+		return await axios(
+			get_options(
+				paginated_url(url(), TABLE_PER_PAGE, fetcher.page),
+				fetcher.token,
+			),
+		)
+			.then((resp) => {
+				return {
+					data: resp?.data,
+					total_count: Number(resp?.headers?.[TOTAL_COUNT_HEADER]),
+				};
+			})
 			.catch((error) => {
 				console.error(error);
-				return EMPTY_ARRAY;
+				return EMPTY_PAGE;
 			});
 	};
-	const [table_data] = createResource(fetcher, getLs);
+	const [table_page] = createResource(fetcher, getLs);
+	const table_data = createMemo(() => table_page()?.data);
+	const page_count = createMemo(() =>
+		Math.max(1, Math.ceil((table_page()?.total_count || 0) / TABLE_PER_PAGE)),
+	);
 
 	const redirect = createMemo(() => props.config.redirect?.(table_data()));
 
@@ -73,6 +93,38 @@ const TablePanel = (props) => {
 				handleRefresh={handleRefresh}
 			/>
 			<Table config={props.config?.table} table_data={table_data} />
+			<Show when={page_count() > 1}>
+				<br />
+				<nav class="pagination is-centered" role="navigation">
+					<button
+						class="button pagination-previous"
+						disabled={page() <= 1}
+						onClick={(e) => {
+							e.preventDefault();
+							setPage(page() - 1);
+						}}
+					>
+						Previous
+					</button>
+					<button
+						class="button pagination-next"
+						disabled={page() >= page_count()}
+						onClick={(e) => {
+							e.preventDefault();
+							setPage(page() + 1);
+						}}
+					>
+						Next
+					</button>
+					<ul class="pagination-list">
+						<li>
+							<span class="pagination-ellipsis">
+								Page {page()} of {page_count()}
+							</span>
+						</li>
+					</ul>
+				</nav>
+			</Show>
 		</>
 	);
 };
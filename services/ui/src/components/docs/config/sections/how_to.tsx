@@ -3,6 +3,7 @@ import GitHubActions from "../../pages/how_to/GitHubActions.mdx";
 import GitLabCiCd from "../../pages/how_to/GitLabCiCd.mdx";
 import InstallCli from "../../pages/how_to/InstallCli.mdx";
 import TrackBenchmarks from "../../pages/how_to/TrackBenchmarks.mdx";
+import Webhooks from "../../pages/how_to/Webhooks.mdx";
 
 const HowTo = [
 	{
@@ -45,6 +46,15 @@ const HowTo = [
 			content: <GitLabCiCd />,
 		},
 	},
+	{
+		title: "Webhooks",
+		slug: "webhooks",
+		panel: {
+			kind: PageKind.MDX,
+			heading: "How to use Bencher Webhooks",
+			content: <Webhooks />,
+		},
+	},
 ];
 
 export default HowTo;
@@ -1,6 +1,5 @@
-import axios from "axios";
 import { createMemo, createResource, For } from "solid-js";
-import { get_options } from "../../site/util";
+import { get_ls_all } from "../../site/util";
 
 const Radio = (props) => {
 	const radioFetcher = createMemo(() => {
@@ -11,8 +10,7 @@ const Radio = (props) => {
 	});
 
 	const getRadio = async (fetcher) => {
-		return await axios(get_options(fetcher.url, fetcher.token))
-			.then((resp) => resp?.data)
+		return await get_ls_all(fetcher.url, fetcher.token)
 			.catch((error) => {
 				console.error(error);
 				return [];
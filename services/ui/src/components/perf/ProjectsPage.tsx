@@ -1,14 +1,12 @@
-import axios from "axios";
 import { Link } from "solid-app-router";
 import { createEffect, createMemo, createResource, For } from "solid-js";
-import { BENCHER_API_URL, get_options, pageTitle } from "../site/util";
+import { BENCHER_API_URL, pageTitle, get_ls_all } from "../site/util";
 
 const ProjectsPage = (props) => {
 	const url = createMemo(() => `${BENCHER_API_URL()}/v0/projects?public=true`);
 
 	const fetchProjects = async (user) => {
-		return await axios(get_options(url(), user?.token))
-			.then((resp) => resp?.data)
+		return await get_ls_all(url(), user?.token)
 			.catch((error) => {
 				console.error(error);
 				return [];
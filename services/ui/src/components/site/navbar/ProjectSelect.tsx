@@ -1,4 +1,3 @@
-import axios from "axios";
 import {
 	createSignal,
 	createResource,
@@ -9,7 +8,7 @@ import {
 import {
 	BENCHER_API_URL,
 	getToken,
-	get_options,
+	get_ls_all,
 	validate_jwt,
 } from "../../site/util";
 import { useNavigate } from "solid-app-router";
@@ -33,9 +32,8 @@ const ProjectSelect = (props) => {
 		if (!validate_jwt(token)) {
 			return [ALL_PROJECTS];
 		}
-		return await axios(get_options(url(), token))
-			.then((resp) => {
-				let data = resp?.data;
+		return await get_ls_all(url(), token)
+			.then((data) => {
 				data.push(ALL_PROJECTS);
 				return data;
 			})
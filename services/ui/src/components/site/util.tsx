@@ -198,6 +198,34 @@ export const get_options = (url: string, token: null | string) => {
 	};
 };
 
+// The max number of items per page for list endpoints
+export const MAX_PER_PAGE = 255;
+export const TOTAL_COUNT_HEADER = "x-total-count";
+
+export const paginated_url = (url: string, per_page: number, page: number) => {
+	const separator = url.includes("?") ? "&" : "?";
+	return `${url}${separator}per_page=${per_page}&page=${page}`;
+};
+
+// Page through a list endpoint and return all of its items
+export const get_ls_all = async (url: string, token: null | string) => {
+	let items = [];
+	for (let page = 1; ; page++) {
+		const resp = await axios(
+			get_options(paginated_url(url, MAX_PER_PAGE, page), token),
+		);
+		const data = resp?.data ?? [];
+		items = items.concat(data);
+		const total_count = Number(resp?.headers?.[TOTAL_COUNT_HEADER]);
+		if (
+			data.length < MAX_PER_PAGE ||
+			(total_count && items.length >= total_count)
+		) {
+			return items;
+		}
+	}
+};
+
 export const data_options = (
 	url: string,
 	method: HttpMethod,